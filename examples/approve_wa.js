@@ -0,0 +1,28 @@
+/**
+ * @NScriptType WorkflowActionScript
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/record',
+], (record) => {
+  /**
+   * Defines the function executed by the workflow action.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.newRecord - New record
+   * @param {Record} scriptContext.oldRecord - Old record
+   * @param {Form} scriptContext.form - Current form
+   * @param {string} scriptContext.type - Event type
+   * @param {number} scriptContext.workflowId - Internal id of the workflow
+   * @returns {*} Value stored in the field set by the action, if any
+   */
+  const onAction = (scriptContext) => {
+    // <suitescript:keep onAction>
+    // TODO: Perform the action
+    // return 'T';
+    // </suitescript:keep>
+  };
+
+  return { onAction };
+});
\ No newline at end of file
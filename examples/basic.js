@@ -4,5 +4,7 @@
 
 define([
 ], () => {
+  // <suitescript:keep>
 
+  // </suitescript:keep>
 });
\ No newline at end of file
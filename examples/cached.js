@@ -0,0 +1,38 @@
+/**
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/cache',
+], (cache) => {
+  // <suitescript:keep cache>
+  /**
+   * Time to live of cached values, in seconds. Must be at least 300.
+   */
+  const CACHE_TTL = 300;
+
+  /**
+   * Loads the value for a key that is missing from the cache.
+   *
+   * @param {Object} context
+   * @param {string} context.key - The key that was not found in the cache
+   * @returns {string} The value to cache, serialized as a string
+   */
+  const loader = (context) => {
+    // TODO: Load the value for context.key
+    return '';
+  };
+
+  /**
+   * Gets a cached value, calling the loader if the key is missing or expired.
+   *
+   * @param {string} key
+   * @returns {string}
+   */
+  const getCachedValue = (key) => {
+    const scriptCache = cache.getCache({ name: 'SCRIPT_CACHE', scope: cache.Scope.PRIVATE });
+    // cache.Cache.get costs 1 governance unit
+    return scriptCache.get({ key, loader, ttl: CACHE_TTL });
+  };
+  // </suitescript:keep>
+});
\ No newline at end of file
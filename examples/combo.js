@@ -12,5 +12,151 @@ define([
   'N/record',
   'N/search',
 ], (record, search) => {
+  /**
+   * Defines the function executed after the page is initialized.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.mode - Access mode of the record: create, copy, or edit
+   */
+  const pageInit = (scriptContext) => {
+    // <suitescript:keep pageInit>
+    // TODO: Initialize the page
+    // </suitescript:keep>
+  };
 
+  /**
+   * Defines the function executed when a field is changed by a user or client call.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   * @param {string} scriptContext.fieldId - Field name
+   * @param {number} scriptContext.line - Line number, if the field is in a sublist
+   * @param {number} scriptContext.column - Column number, if the field is in a matrix
+   */
+  const fieldChanged = (scriptContext) => {
+    // <suitescript:keep fieldChanged>
+    // TODO: Respond to the changed field
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed when an existing field is changed by sourcing.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   * @param {string} scriptContext.fieldId - Field name
+   */
+  const postSourcing = (scriptContext) => {
+    // <suitescript:keep postSourcing>
+    // TODO: Respond to the sourced field
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed after a sublist is inserted, removed, or edited.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   */
+  const sublistChanged = (scriptContext) => {
+    // <suitescript:keep sublistChanged>
+    // TODO: Respond to the changed sublist
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed when an existing line is selected.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   */
+  const lineInit = (scriptContext) => {
+    // <suitescript:keep lineInit>
+    // TODO: Initialize the selected line
+    // </suitescript:keep>
+  };
+
+  /**
+   * Validates a field when it is changed, before the change is committed.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   * @param {string} scriptContext.fieldId - Field name
+   * @param {number} scriptContext.line - Line number, if the field is in a sublist
+   * @param {number} scriptContext.column - Column number, if the field is in a matrix
+   * @returns {boolean} Whether the field is valid
+   */
+  const validateField = (scriptContext) => {
+    // <suitescript:keep validateField>
+    // TODO: Validate the field
+    return true;
+    // </suitescript:keep>
+  };
+
+  /**
+   * Validates a sublist line before it is added.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   * @returns {boolean} Whether the line is valid
+   */
+  const validateLine = (scriptContext) => {
+    // <suitescript:keep validateLine>
+    // TODO: Validate the line
+    return true;
+    // </suitescript:keep>
+  };
+
+  /**
+   * Validates a sublist line before it is inserted.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   * @returns {boolean} Whether the line is valid
+   */
+  const validateInsert = (scriptContext) => {
+    // <suitescript:keep validateInsert>
+    // TODO: Validate the inserted line
+    return true;
+    // </suitescript:keep>
+  };
+
+  /**
+   * Validates a sublist line before it is removed.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   * @returns {boolean} Whether the line is valid
+   */
+  const validateDelete = (scriptContext) => {
+    // <suitescript:keep validateDelete>
+    // TODO: Validate the removed line
+    return true;
+    // </suitescript:keep>
+  };
+
+  /**
+   * Validates the record before it is saved.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @returns {boolean} Whether the record is valid
+   */
+  const saveRecord = (scriptContext) => {
+    // <suitescript:keep saveRecord>
+    // TODO: Validate the record
+    return true;
+    // </suitescript:keep>
+  };
+
+  return { pageInit, fieldChanged, postSourcing, sublistChanged, lineInit, validateField, validateLine, validateInsert, validateDelete, saveRecord };
 });
\ No newline at end of file
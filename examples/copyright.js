@@ -9,5 +9,7 @@
 
 define([
 ], () => {
+  // <suitescript:keep>
 
+  // </suitescript:keep>
 });
\ No newline at end of file
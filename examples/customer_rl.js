@@ -0,0 +1,36 @@
+/**
+ * @NScriptType RESTlet
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/record',
+], (record) => {
+  /**
+   * Defines the function called by a GET request.
+   *
+   * @param {Object} requestParams - Parameters of the request URL
+   * @returns {string | Object} Body of the response, an Object for JSON requests
+   */
+  const doGet = (requestParams) => {
+    // <suitescript:keep doGet>
+    // TODO: Read the requested resource
+    return {};
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function called by a POST request.
+   *
+   * @param {string | Object} requestBody - Body of the request, an Object for JSON requests
+   * @returns {string | Object} Body of the response, an Object for JSON requests
+   */
+  const doPost = (requestBody) => {
+    // <suitescript:keep doPost>
+    // TODO: Create the resource
+    return {};
+    // </suitescript:keep>
+  };
+
+  return { get: doGet, post: doPost };
+});
\ No newline at end of file
@@ -0,0 +1,39 @@
+/**
+ * @NScriptType UserEventScript
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/record',
+], (record) => {
+  /**
+   * Defines the function executed before a record is loaded.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.newRecord - New record
+   * @param {string} scriptContext.type - Trigger type
+   * @param {Form} scriptContext.form - Current form
+   * @param {ServerRequest} scriptContext.request - HTTP request sent from the browser
+   */
+  const beforeLoad = (scriptContext) => {
+    // <suitescript:keep beforeLoad>
+    // TODO: Modify the form or the record before it is shown
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed after a record is submitted.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.newRecord - New record
+   * @param {Record} scriptContext.oldRecord - Old record
+   * @param {string} scriptContext.type - Trigger type
+   */
+  const afterSubmit = (scriptContext) => {
+    // <suitescript:keep afterSubmit>
+    // TODO: Process the saved record
+    // </suitescript:keep>
+  };
+
+  return { beforeLoad, afterSubmit };
+});
\ No newline at end of file
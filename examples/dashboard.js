@@ -0,0 +1,27 @@
+/**
+ * @NScriptType Portlet
+ * @NScriptPortletType list
+ * @NApiVersion 2.1
+ */
+
+define([
+], () => {
+  /**
+   * Defines the function that renders the portlet.
+   *
+   * @param {Object} params
+   * @param {Portlet} params.portlet - Portlet to render
+   * @param {number} params.column - Column of the portlet: 1 left, 2 center, 3 right
+   * @param {string} params.entity - Id of the selected customer
+   */
+  const render = (params) => {
+    // <suitescript:keep render>
+    params.portlet.title = 'TODO: Portlet title';
+    params.portlet.addColumn({ id: 'name', type: 'TEXT', label: 'Name', align: 'LEFT' });
+    // TODO: Add the rows of the list
+    params.portlet.addRows({ rows: [] });
+    // </suitescript:keep>
+  };
+
+  return { render };
+});
\ No newline at end of file
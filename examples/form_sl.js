@@ -0,0 +1,29 @@
+/**
+ * @NScriptType Suitelet
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/ui/serverWidget',
+], (uiserverwidget) => {
+  /**
+   * Defines the function called by a request to the Suitelet.
+   *
+   * @param {Object} context
+   * @param {ServerRequest} context.request - Incoming request
+   * @param {ServerResponse} context.response - Suitelet response
+   */
+  const onRequest = (context) => {
+    // <suitescript:keep onRequest>
+    if (context.request.method === 'GET') {
+      const form = uiserverwidget.createForm({ title: 'TODO: Form title' });
+      form.addSubmitButton({ label: 'Submit' });
+      context.response.writePage(form);
+    } else {
+      // TODO: Handle the submitted form
+    }
+    // </suitescript:keep>
+  };
+
+  return { onRequest };
+});
\ No newline at end of file
@@ -0,0 +1,13 @@
+/**
+ * Customizes the GL impact of a transaction.
+ *
+ * @param {nlobjRecord} transactionRecord - Transaction whose GL impact is customized
+ * @param {StandardLines} standardLines - Standard GL lines of the transaction
+ * @param {CustomLines} customLines - Custom GL lines to add to the transaction
+ * @param {Book} book - Accounting book of the GL impact
+ */
+function customizeGlImpact(transactionRecord, standardLines, customLines, book) {
+  // <suitescript:keep>
+  // TODO: Add lines with customLines.addNewLine()
+  // </suitescript:keep>
+}
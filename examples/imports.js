@@ -6,5 +6,7 @@ define([
   'N/record',
   'N/search',
 ], (record, search) => {
+  // <suitescript:keep>
 
+  // </suitescript:keep>
 });
\ No newline at end of file
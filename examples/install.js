@@ -0,0 +1,71 @@
+/**
+ * @NScriptType BundleInstallationScript
+ * @NApiVersion 2.1
+ */
+
+define([
+], () => {
+  /**
+   * Defines the function executed before the bundle is installed.
+   *
+   * @param {Object} params
+   * @param {number} params.version - Version of the bundle being installed
+   */
+  const beforeInstall = (params) => {
+    // <suitescript:keep beforeInstall>
+    // TODO: Check the account before the bundle is installed
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed after the bundle is installed.
+   *
+   * @param {Object} params
+   * @param {number} params.version - Version of the installed bundle
+   */
+  const afterInstall = (params) => {
+    // <suitescript:keep afterInstall>
+    // TODO: Set up the account after the bundle is installed
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed before the bundle is updated.
+   *
+   * @param {Object} params
+   * @param {number} params.fromVersion - Version of the installed bundle
+   * @param {number} params.toVersion - Version of the bundle being installed
+   */
+  const beforeUpdate = (params) => {
+    // <suitescript:keep beforeUpdate>
+    // TODO: Check the account before the bundle is updated
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed after the bundle is updated.
+   *
+   * @param {Object} params
+   * @param {number} params.fromVersion - Version of the previously installed bundle
+   * @param {number} params.toVersion - Version of the installed bundle
+   */
+  const afterUpdate = (params) => {
+    // <suitescript:keep afterUpdate>
+    // TODO: Migrate the account after the bundle is updated
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed before the bundle is uninstalled.
+   *
+   * @param {Object} params
+   * @param {number} params.version - Version of the bundle being uninstalled
+   */
+  const beforeUninstall = (params) => {
+    // <suitescript:keep beforeUninstall>
+    // TODO: Clean up the account before the bundle is uninstalled
+    // </suitescript:keep>
+  };
+
+  return { beforeInstall, afterInstall, beforeUpdate, afterUpdate, beforeUninstall };
+});
\ No newline at end of file
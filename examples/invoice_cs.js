@@ -0,0 +1,52 @@
+/**
+ * @NScriptType ClientScript
+ * @NApiVersion 2.1
+ */
+
+define([
+], () => {
+  /**
+   * Defines the function executed after the page is initialized.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.mode - Access mode of the record: create, copy, or edit
+   */
+  const pageInit = (scriptContext) => {
+    // <suitescript:keep pageInit>
+    // TODO: Initialize the page
+    // </suitescript:keep>
+  };
+
+  /**
+   * Defines the function executed when a field is changed by a user or client call.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @param {string} scriptContext.sublistId - Sublist name
+   * @param {string} scriptContext.fieldId - Field name
+   * @param {number} scriptContext.line - Line number, if the field is in a sublist
+   * @param {number} scriptContext.column - Column number, if the field is in a matrix
+   */
+  const fieldChanged = (scriptContext) => {
+    // <suitescript:keep fieldChanged>
+    // TODO: Respond to the changed field
+    // </suitescript:keep>
+  };
+
+  /**
+   * Validates the record before it is saved.
+   *
+   * @param {Object} scriptContext
+   * @param {Record} scriptContext.currentRecord - Current form record
+   * @returns {boolean} Whether the record is valid
+   */
+  const saveRecord = (scriptContext) => {
+    // <suitescript:keep saveRecord>
+    // TODO: Validate the record
+    return true;
+    // </suitescript:keep>
+  };
+
+  return { pageInit, fieldChanged, saveRecord };
+});
\ No newline at end of file
@@ -0,0 +1,27 @@
+/**
+ * @NScriptType ScheduledScript
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/runtime',
+  'N/search',
+], (runtime, search) => {
+  /**
+   * Defines the function executed when the script is scheduled or run on demand.
+   *
+   * @param {Object} context
+   * @param {string} context.type - Trigger type, such as SCHEDULED or ON_DEMAND
+   */
+  const execute = (context) => {
+    // <suitescript:keep execute>
+    // TODO: Process the scheduled work, checking the remaining governance units before each
+    // unit of work so the script stops before running out:
+    // if (runtime.getCurrentScript().getRemainingUsage() < 100) {
+    //   return;
+    // }
+    // </suitescript:keep>
+  };
+
+  return { execute };
+});
\ No newline at end of file
@@ -0,0 +1,23 @@
+/**
+ * @NScriptType MassUpdateScript
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/record',
+], (record) => {
+  /**
+   * Defines the function executed for each record of the mass update.
+   *
+   * @param {Object} params
+   * @param {string} params.type - Record type of the record being processed
+   * @param {number} params.id - Id of the record being processed
+   */
+  const each = (params) => {
+    // <suitescript:keep each>
+    // TODO: Update the record
+    // </suitescript:keep>
+  };
+
+  return { each };
+});
\ No newline at end of file
@@ -0,0 +1,22 @@
+/**
+ * @NScriptType SDFInstallationScript
+ * @NApiVersion 2.1
+ */
+
+define([
+], () => {
+  /**
+   * Defines the function executed when the SuiteCloud project is deployed.
+   *
+   * @param {Object} params
+   * @param {string} params.fromVersion - Version of the deployed project, if any
+   * @param {string} params.toVersion - Version of the project being deployed
+   */
+  const run = (params) => {
+    // <suitescript:keep run>
+    // TODO: Set up the account for the deployed version
+    // </suitescript:keep>
+  };
+
+  return { run };
+});
\ No newline at end of file
@@ -0,0 +1,23 @@
+// @ts-check
+/**
+ * @typedef {typeof import('N/record')} NRecord
+ * @typedef {typeof import('N/search')} NSearch
+ */
+
+/**
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/record',
+  'N/search',
+],
+/**
+ * @param {NRecord} record
+ * @param {NSearch} search
+ */
+(record, search) => {
+  // <suitescript:keep>
+
+  // </suitescript:keep>
+});
\ No newline at end of file
@@ -5,5 +5,7 @@
 
 define([
 ], () => {
+  // <suitescript:keep>
 
+  // </suitescript:keep>
 });
\ No newline at end of file
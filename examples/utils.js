@@ -0,0 +1,18 @@
+/**
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/search',
+], (search) => {
+  /**
+   * Example function exported to the scripts importing the library.
+   */
+  const example = () => {
+    // <suitescript:keep example>
+    // TODO: Replace with the functions shared by scripts
+    // </suitescript:keep>
+  };
+
+  return { example };
+});
\ No newline at end of file
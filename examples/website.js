@@ -0,0 +1,22 @@
+/**
+ * @NScriptType ClientScript
+ * @NApiVersion 2.1
+ */
+
+define([
+  'N/search',
+], (search) => {
+  /**
+   * Entry point of the extension, called once when the SuiteCommerce application starts.
+   *
+   * @param {Object} container - The application, used to get extensibility components
+   *   such as `container.getComponent('PDP')`
+   */
+  const mountToApp = (container) => {
+    // <suitescript:keep mountToApp>
+    // TODO: Extend the website with the components of the container
+    // </suitescript:keep>
+  };
+
+  return { mountToApp };
+});
\ No newline at end of file
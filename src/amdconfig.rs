@@ -0,0 +1,52 @@
+use std::path::{Path, PathBuf};
+
+use super::OutputFile;
+
+/// Contents of a new AMD config file, mapping module ids to the paths of shared libraries.
+const STARTER_CONFIG: &str = "{
+  \"paths\": {
+    \"lib\": \"./lib\"
+  }
+}
+";
+
+/// Gets a starter AMD config file for a script, unless it already exists.
+///
+/// The path of the config is resolved relative to the directory of the script, the same way
+/// `NetSuite` resolves the `@NAmdConfig` tag.
+pub fn get_file(script: &Path, config: &Path) -> Option<OutputFile> {
+    let path = get_config_path(script, config);
+    if path.exists() {
+        return None;
+    }
+
+    Some(OutputFile {
+        path,
+        contents: String::from(STARTER_CONFIG),
+    })
+}
+
+/// Gets the path of the AMD config of a script.
+pub fn get_config_path(script: &Path, config: &Path) -> PathBuf {
+    script
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_path() {
+        assert_eq!(
+            get_config_path(Path::new("src/customer_ue.js"), Path::new("./amd.json")),
+            PathBuf::from("src/./amd.json")
+        );
+        assert_eq!(
+            get_config_path(Path::new("customer_ue.js"), Path::new("amd.json")),
+            PathBuf::from("amd.json")
+        );
+    }
+}
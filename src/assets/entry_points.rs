@@ -0,0 +1,597 @@
+/// A function of the module body that `NetSuite` calls, returned from the module.
+pub struct EntryPoint {
+    /// Name of the entry point, used as its key in the returned object.
+    pub name: &'static str,
+    /// Name of the function, if it differs from the name of the entry point.
+    pub function: Option<&'static str>,
+    /// Lines of the `JSDoc` block of the function, without the comment markers.
+    pub doc: &'static [&'static str],
+    /// Name of the parameter of the function.
+    pub param: &'static str,
+    /// Minimal context the placeholder Jest test calls the function with, with the members its
+    /// body uses.
+    pub context: &'static str,
+    /// Statements of the placeholder Jest test setting the return values of the mocked modules
+    /// its body uses, such as the form created by `N/ui/serverWidget`.
+    pub mocks: &'static str,
+    /// Statements of the function.
+    pub body: &'static str,
+}
+
+pub const SUITECOMMERCE: [EntryPoint; 1] = [EntryPoint {
+    name: "mountToApp",
+    function: None,
+    doc: &[
+        "Entry point of the extension, called once when the SuiteCommerce application starts.",
+        "",
+        "@param {Object} container - The application, used to get extensibility components",
+        "  such as `container.getComponent('PDP')`",
+    ],
+    param: "container",
+    context: "{ getComponent: jest.fn() }",
+    mocks: "",
+    body: "    // TODO: Extend the website with the components of the container",
+}];
+
+pub const USER_EVENT: [EntryPoint; 3] = [
+    EntryPoint {
+        name: "beforeLoad",
+        function: None,
+        doc: &[
+            "Defines the function executed before a record is loaded.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.newRecord - New record",
+            "@param {string} scriptContext.type - Trigger type",
+            "@param {Form} scriptContext.form - Current form",
+            "@param {ServerRequest} scriptContext.request - HTTP request sent from the browser",
+        ],
+        param: "scriptContext",
+        context: "{ newRecord: {}, type: 'view', form: {}, request: {} }",
+        mocks: "",
+        body: "    // TODO: Modify the form or the record before it is shown",
+    },
+    EntryPoint {
+        name: "beforeSubmit",
+        function: None,
+        doc: &[
+            "Defines the function executed before a record is submitted.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.newRecord - New record",
+            "@param {Record} scriptContext.oldRecord - Old record",
+            "@param {string} scriptContext.type - Trigger type",
+        ],
+        param: "scriptContext",
+        context: "{ newRecord: {}, oldRecord: {}, type: 'create' }",
+        mocks: "",
+        body: "    // TODO: Validate or modify the record before it is saved",
+    },
+    EntryPoint {
+        name: "afterSubmit",
+        function: None,
+        doc: &[
+            "Defines the function executed after a record is submitted.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.newRecord - New record",
+            "@param {Record} scriptContext.oldRecord - Old record",
+            "@param {string} scriptContext.type - Trigger type",
+        ],
+        param: "scriptContext",
+        context: "{ newRecord: {}, oldRecord: {}, type: 'create' }",
+        mocks: "",
+        body: "    // TODO: Process the saved record",
+    },
+];
+
+pub const CLIENT: [EntryPoint; 10] = [
+    EntryPoint {
+        name: "pageInit",
+        function: None,
+        doc: &[
+            "Defines the function executed after the page is initialized.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.mode - Access mode of the record: create, copy, or edit",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, mode: 'create' }",
+        mocks: "",
+        body: "    // TODO: Initialize the page",
+    },
+    EntryPoint {
+        name: "fieldChanged",
+        function: None,
+        doc: &[
+            "Defines the function executed when a field is changed by a user or client call.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.sublistId - Sublist name",
+            "@param {string} scriptContext.fieldId - Field name",
+            "@param {number} scriptContext.line - Line number, if the field is in a sublist",
+            "@param {number} scriptContext.column - Column number, if the field is in a matrix",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, sublistId: null, fieldId: 'custbody_field' }",
+        mocks: "",
+        body: "    // TODO: Respond to the changed field",
+    },
+    EntryPoint {
+        name: "postSourcing",
+        function: None,
+        doc: &[
+            "Defines the function executed when an existing field is changed by sourcing.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.sublistId - Sublist name",
+            "@param {string} scriptContext.fieldId - Field name",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, sublistId: null, fieldId: 'custbody_field' }",
+        mocks: "",
+        body: "    // TODO: Respond to the sourced field",
+    },
+    EntryPoint {
+        name: "sublistChanged",
+        function: None,
+        doc: &[
+            "Defines the function executed after a sublist is inserted, removed, or edited.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.sublistId - Sublist name",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, sublistId: 'item' }",
+        mocks: "",
+        body: "    // TODO: Respond to the changed sublist",
+    },
+    EntryPoint {
+        name: "lineInit",
+        function: None,
+        doc: &[
+            "Defines the function executed when an existing line is selected.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.sublistId - Sublist name",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, sublistId: 'item' }",
+        mocks: "",
+        body: "    // TODO: Initialize the selected line",
+    },
+    EntryPoint {
+        name: "validateField",
+        function: None,
+        doc: &[
+            "Validates a field when it is changed, before the change is committed.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.sublistId - Sublist name",
+            "@param {string} scriptContext.fieldId - Field name",
+            "@param {number} scriptContext.line - Line number, if the field is in a sublist",
+            "@param {number} scriptContext.column - Column number, if the field is in a matrix",
+            "@returns {boolean} Whether the field is valid",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, sublistId: null, fieldId: 'custbody_field' }",
+        mocks: "",
+        body: "    // TODO: Validate the field\n    return true;",
+    },
+    EntryPoint {
+        name: "validateLine",
+        function: None,
+        doc: &[
+            "Validates a sublist line before it is added.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.sublistId - Sublist name",
+            "@returns {boolean} Whether the line is valid",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, sublistId: 'item' }",
+        mocks: "",
+        body: "    // TODO: Validate the line\n    return true;",
+    },
+    EntryPoint {
+        name: "validateInsert",
+        function: None,
+        doc: &[
+            "Validates a sublist line before it is inserted.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.sublistId - Sublist name",
+            "@returns {boolean} Whether the line is valid",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, sublistId: 'item' }",
+        mocks: "",
+        body: "    // TODO: Validate the inserted line\n    return true;",
+    },
+    EntryPoint {
+        name: "validateDelete",
+        function: None,
+        doc: &[
+            "Validates a sublist line before it is removed.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@param {string} scriptContext.sublistId - Sublist name",
+            "@returns {boolean} Whether the line is valid",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {}, sublistId: 'item' }",
+        mocks: "",
+        body: "    // TODO: Validate the removed line\n    return true;",
+    },
+    EntryPoint {
+        name: "saveRecord",
+        function: None,
+        doc: &[
+            "Validates the record before it is saved.",
+            "",
+            "@param {Object} scriptContext",
+            "@param {Record} scriptContext.currentRecord - Current form record",
+            "@returns {boolean} Whether the record is valid",
+        ],
+        param: "scriptContext",
+        context: "{ currentRecord: {} }",
+        mocks: "",
+        body: "    // TODO: Validate the record\n    return true;",
+    },
+];
+
+pub const RESTLET: [EntryPoint; 4] = [
+    EntryPoint {
+        name: "get",
+        function: Some("doGet"),
+        doc: &[
+            "Defines the function called by a GET request.",
+            "",
+            "@param {Object} requestParams - Parameters of the request URL",
+            "@returns {string | Object} Body of the response, an Object for JSON requests",
+        ],
+        param: "requestParams",
+        context: "{}",
+        mocks: "",
+        body: "    // TODO: Read the requested resource\n    return {};",
+    },
+    EntryPoint {
+        name: "post",
+        function: Some("doPost"),
+        doc: &[
+            "Defines the function called by a POST request.",
+            "",
+            "@param {string | Object} requestBody - Body of the request, an Object for JSON requests",
+            "@returns {string | Object} Body of the response, an Object for JSON requests",
+        ],
+        param: "requestBody",
+        context: "{}",
+        mocks: "",
+        body: "    // TODO: Create the resource\n    return {};",
+    },
+    EntryPoint {
+        name: "put",
+        function: Some("doPut"),
+        doc: &[
+            "Defines the function called by a PUT request.",
+            "",
+            "@param {string | Object} requestBody - Body of the request, an Object for JSON requests",
+            "@returns {string | Object} Body of the response, an Object for JSON requests",
+        ],
+        param: "requestBody",
+        context: "{}",
+        mocks: "",
+        body: "    // TODO: Update the resource\n    return {};",
+    },
+    EntryPoint {
+        name: "delete",
+        function: Some("doDelete"),
+        doc: &[
+            "Defines the function called by a DELETE request.",
+            "",
+            "@param {Object} requestParams - Parameters of the request URL",
+            "@returns {string | Object} Body of the response, an Object for JSON requests",
+        ],
+        param: "requestParams",
+        context: "{}",
+        mocks: "",
+        body: "    // TODO: Delete the resource\n    return {};",
+    },
+];
+
+pub const SUITELET: [EntryPoint; 1] = [EntryPoint {
+    name: "onRequest",
+    function: None,
+    doc: &[
+        "Defines the function called by a request to the Suitelet.",
+        "",
+        "@param {Object} context",
+        "@param {ServerRequest} context.request - Incoming request",
+        "@param {ServerResponse} context.response - Suitelet response",
+    ],
+    param: "context",
+    context: "{ request: { method: 'GET', parameters: {} }, response: { write: jest.fn(), writePage: jest.fn() } }",
+    mocks: "",
+    body: "    if (context.request.method === 'GET') {
+      // TODO: Handle the GET request
+    } else {
+      // TODO: Handle the POST request
+    }",
+}];
+
+pub const SUITELET_FORM: [EntryPoint; 1] = [EntryPoint {
+    name: "onRequest",
+    function: None,
+    doc: SUITELET[0].doc,
+    param: "context",
+    context: "{ request: { method: 'GET', parameters: {} }, response: { write: jest.fn(), writePage: jest.fn() } }",
+    mocks: "uiserverwidget.createForm.mockReturnValue({ addSubmitButton: jest.fn() });",
+    body: "    if (context.request.method === 'GET') {
+      const form = uiserverwidget.createForm({ title: 'TODO: Form title' });
+      form.addSubmitButton({ label: 'Submit' });
+      context.response.writePage(form);
+    } else {
+      // TODO: Handle the submitted form
+    }",
+}];
+
+/// Suitelet form with a client script attached, whose path replaces the `{client}` marker.
+pub const SUITELET_PAIR: [EntryPoint; 1] = [EntryPoint {
+    name: "onRequest",
+    function: None,
+    doc: SUITELET[0].doc,
+    param: "context",
+    context: "{ request: { method: 'GET', parameters: {} }, response: { write: jest.fn(), writePage: jest.fn() } }",
+    mocks: "uiserverwidget.createForm.mockReturnValue({ addSubmitButton: jest.fn() });",
+    body: "    if (context.request.method === 'GET') {
+      const form = uiserverwidget.createForm({ title: 'TODO: Form title' });
+      form.clientScriptModulePath = '{client}';
+      form.addSubmitButton({ label: 'Submit' });
+      context.response.writePage(form);
+    } else {
+      // TODO: Handle the submitted form
+    }",
+}];
+
+pub const SCHEDULED: [EntryPoint; 1] = [EntryPoint {
+    name: "execute",
+    function: None,
+    doc: &[
+        "Defines the function executed when the script is scheduled or run on demand.",
+        "",
+        "@param {Object} context",
+        "@param {string} context.type - Trigger type, such as SCHEDULED or ON_DEMAND",
+    ],
+    param: "context",
+    context: "{ type: 'SCHEDULED' }",
+    mocks: "",
+    body: "    // TODO: Process the scheduled work",
+}];
+
+pub const SCHEDULED_GOVERNANCE: [EntryPoint; 1] = [EntryPoint {
+    name: "execute",
+    function: None,
+    doc: SCHEDULED[0].doc,
+    param: "context",
+    context: "{ type: 'SCHEDULED' }",
+    mocks: "",
+    body: "    // TODO: Process the scheduled work, checking the remaining governance units before each
+    // unit of work so the script stops before running out:
+    // if (runtime.getCurrentScript().getRemainingUsage() < 100) {
+    //   return;
+    // }",
+}];
+
+pub const PORTLET: [(&str, EntryPoint); 4] = [
+    (
+        "form",
+        EntryPoint {
+            name: "render",
+            function: None,
+            doc: PORTLET_DOC,
+            param: "params",
+            context: "{ portlet: { addField: jest.fn(), setSubmitButton: jest.fn() }, column: 2 }",
+            mocks: "",
+            body: "    params.portlet.title = 'TODO: Portlet title';
+    params.portlet.addField({ id: 'custpage_field', type: 'TEXT', label: 'TODO: Field label' });
+    params.portlet.setSubmitButton({ url: 'TODO: Submit URL', label: 'Submit' });",
+        },
+    ),
+    (
+        "list",
+        EntryPoint {
+            name: "render",
+            function: None,
+            doc: PORTLET_DOC,
+            param: "params",
+            context: "{ portlet: { addColumn: jest.fn(), addRows: jest.fn() }, column: 2 }",
+            mocks: "",
+            body: "    params.portlet.title = 'TODO: Portlet title';
+    params.portlet.addColumn({ id: 'name', type: 'TEXT', label: 'Name', align: 'LEFT' });
+    // TODO: Add the rows of the list
+    params.portlet.addRows({ rows: [] });",
+        },
+    ),
+    (
+        "html",
+        EntryPoint {
+            name: "render",
+            function: None,
+            doc: PORTLET_DOC,
+            param: "params",
+            context: "{ portlet: {}, column: 2 }",
+            mocks: "",
+            body: "    params.portlet.title = 'TODO: Portlet title';
+    params.portlet.html = '<div>TODO: Portlet content</div>';",
+        },
+    ),
+    (
+        "links",
+        EntryPoint {
+            name: "render",
+            function: None,
+            doc: PORTLET_DOC,
+            param: "params",
+            context: "{ portlet: { addLine: jest.fn() }, column: 2 }",
+            mocks: "",
+            body: "    params.portlet.title = 'TODO: Portlet title';
+    params.portlet.addLine({ text: 'TODO: Link text', url: 'TODO: Link URL' });",
+        },
+    ),
+];
+
+pub const BUNDLE_INSTALLATION: [EntryPoint; 5] = [
+    EntryPoint {
+        name: "beforeInstall",
+        function: None,
+        doc: &[
+            "Defines the function executed before the bundle is installed.",
+            "",
+            "@param {Object} params",
+            "@param {number} params.version - Version of the bundle being installed",
+        ],
+        param: "params",
+        context: "{ version: 1 }",
+        mocks: "",
+        body: "    // TODO: Check the account before the bundle is installed",
+    },
+    EntryPoint {
+        name: "afterInstall",
+        function: None,
+        doc: &[
+            "Defines the function executed after the bundle is installed.",
+            "",
+            "@param {Object} params",
+            "@param {number} params.version - Version of the installed bundle",
+        ],
+        param: "params",
+        context: "{ version: 1 }",
+        mocks: "",
+        body: "    // TODO: Set up the account after the bundle is installed",
+    },
+    EntryPoint {
+        name: "beforeUpdate",
+        function: None,
+        doc: &[
+            "Defines the function executed before the bundle is updated.",
+            "",
+            "@param {Object} params",
+            "@param {number} params.fromVersion - Version of the installed bundle",
+            "@param {number} params.toVersion - Version of the bundle being installed",
+        ],
+        param: "params",
+        context: "{ fromVersion: 1, toVersion: 2 }",
+        mocks: "",
+        body: "    // TODO: Check the account before the bundle is updated",
+    },
+    EntryPoint {
+        name: "afterUpdate",
+        function: None,
+        doc: &[
+            "Defines the function executed after the bundle is updated.",
+            "",
+            "@param {Object} params",
+            "@param {number} params.fromVersion - Version of the previously installed bundle",
+            "@param {number} params.toVersion - Version of the installed bundle",
+        ],
+        param: "params",
+        context: "{ fromVersion: 1, toVersion: 2 }",
+        mocks: "",
+        body: "    // TODO: Migrate the account after the bundle is updated",
+    },
+    EntryPoint {
+        name: "beforeUninstall",
+        function: None,
+        doc: &[
+            "Defines the function executed before the bundle is uninstalled.",
+            "",
+            "@param {Object} params",
+            "@param {number} params.version - Version of the bundle being uninstalled",
+        ],
+        param: "params",
+        context: "{ version: 1 }",
+        mocks: "",
+        body: "    // TODO: Clean up the account before the bundle is uninstalled",
+    },
+];
+
+pub const MASS_UPDATE: [EntryPoint; 1] = [EntryPoint {
+    name: "each",
+    function: None,
+    doc: &[
+        "Defines the function executed for each record of the mass update.",
+        "",
+        "@param {Object} params",
+        "@param {string} params.type - Record type of the record being processed",
+        "@param {number} params.id - Id of the record being processed",
+    ],
+    param: "params",
+    context: "{ type: 'customer', id: 1 }",
+    mocks: "",
+    body: "    // TODO: Update the record",
+}];
+
+pub const WORKFLOW_ACTION: [EntryPoint; 1] = [EntryPoint {
+    name: "onAction",
+    function: None,
+    doc: &[
+        "Defines the function executed by the workflow action.",
+        "",
+        "@param {Object} scriptContext",
+        "@param {Record} scriptContext.newRecord - New record",
+        "@param {Record} scriptContext.oldRecord - Old record",
+        "@param {Form} scriptContext.form - Current form",
+        "@param {string} scriptContext.type - Event type",
+        "@param {number} scriptContext.workflowId - Internal id of the workflow",
+        "@returns {*} Value stored in the field set by the action, if any",
+    ],
+    param: "scriptContext",
+    context: "{ newRecord: {}, oldRecord: {}, form: {}, type: 'create', workflowId: 1 }",
+    mocks: "",
+    body: "    // TODO: Perform the action
+    // return 'T';",
+}];
+
+pub const SDF_INSTALLATION: [EntryPoint; 1] = [EntryPoint {
+    name: "run",
+    function: None,
+    doc: &[
+        "Defines the function executed when the SuiteCloud project is deployed.",
+        "",
+        "@param {Object} params",
+        "@param {string} params.fromVersion - Version of the deployed project, if any",
+        "@param {string} params.toVersion - Version of the project being deployed",
+    ],
+    param: "params",
+    context: "{ fromVersion: '1.0.0', toVersion: '1.1.0' }",
+    mocks: "",
+    body: "    // TODO: Set up the account for the deployed version",
+}];
+
+pub const LIBRARY: [EntryPoint; 1] = [EntryPoint {
+    name: "example",
+    function: None,
+    doc: &["Example function exported to the scripts importing the library."],
+    param: "",
+    context: "",
+    mocks: "",
+    body: "    // TODO: Replace with the functions shared by scripts",
+}];
+
+const PORTLET_DOC: &[&str] = &[
+    "Defines the function that renders the portlet.",
+    "",
+    "@param {Object} params",
+    "@param {Portlet} params.portlet - Portlet to render",
+    "@param {number} params.column - Column of the portlet: 1 left, 2 center, 3 right",
+    "@param {string} params.entity - Id of the selected customer",
+];
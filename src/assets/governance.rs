@@ -0,0 +1,33 @@
+/// Governance units used by API calls, as the API name and the lowest and highest cost.
+///
+/// Costs that depend on the record type range from custom records to transactions.
+pub const COSTS: [(&str, u32, u32); 28] = [
+    ("cache.Cache.get", 1, 1),
+    ("cache.Cache.put", 1, 1),
+    ("cache.Cache.remove", 1, 1),
+    ("email.send", 20, 20),
+    ("file.File.save", 20, 20),
+    ("file.load", 10, 10),
+    ("http.get", 10, 10),
+    ("http.post", 10, 10),
+    ("https.delete", 10, 10),
+    ("https.get", 10, 10),
+    ("https.post", 10, 10),
+    ("https.put", 10, 10),
+    ("https.request", 10, 10),
+    ("query.runSuiteQL", 10, 10),
+    ("record.Record.save", 4, 20),
+    ("record.delete", 4, 20),
+    ("record.load", 2, 10),
+    ("record.submitFields", 2, 10),
+    ("record.transform", 2, 10),
+    ("search.PagedData.fetch", 5, 5),
+    ("search.ResultSet.each", 10, 10),
+    ("search.ResultSet.getRange", 10, 10),
+    ("search.Search.runPaged", 5, 5),
+    ("search.load", 5, 5),
+    ("search.lookupFields", 1, 1),
+    ("task.ScheduledScriptTask.submit", 20, 20),
+    ("workflow.initiate", 20, 20),
+    ("workflow.trigger", 20, 20),
+];
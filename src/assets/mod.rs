@@ -1 +1,6 @@
+pub mod entry_points;
+pub mod governance;
+pub mod module_info;
 pub mod netsuite_types;
+pub mod plugins;
+pub mod recipes;
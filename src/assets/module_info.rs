@@ -0,0 +1,347 @@
+/// Reference information about a `SuiteScript` module.
+pub struct ModuleInfo {
+    /// Name of the module without the `N/` prefix.
+    pub name: &'static str,
+    /// What the module is used for.
+    pub purpose: &'static str,
+    /// Commonly used members of the module.
+    pub members: &'static [&'static str],
+    /// Script types the module can be used in.
+    pub availability: &'static str,
+}
+
+/// Script types of modules available to every script.
+const ALL_SCRIPTS: &str = "Client and server scripts";
+
+/// Script types of modules available only on the server.
+const SERVER_SCRIPTS: &str = "Server scripts";
+
+/// Script types of modules available only to client scripts.
+const CLIENT_SCRIPTS: &str = "Client scripts";
+
+pub const MODULE_INFO: [ModuleInfo; 48] = [
+    ModuleInfo {
+        name: "action",
+        purpose: "Finds and executes the actions available on a record, such as approving a sales order",
+        members: &["action.find", "action.get", "action.execute", "action.executeBulk"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "auth",
+        purpose: "Changes the email address or password of the current user",
+        members: &["auth.changeEmail", "auth.changePassword"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "cache",
+        purpose: "Stores values in a cache shared by the executions of a script, a bundle, or the account",
+        members: &["cache.getCache", "Cache.get", "Cache.put", "Cache.remove", "cache.Scope"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "certificatecontrol",
+        purpose: "Manages the digital certificates stored in the account",
+        members: &[
+            "certificatecontrol.createCertificate",
+            "certificatecontrol.loadCertificate",
+            "certificatecontrol.findCertificates",
+            "certificatecontrol.deleteCertificate",
+        ],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "commerce",
+        purpose: "Accesses the web store of a SuiteCommerce website from its server-side scripts",
+        members: &["commerce.webstore"],
+        availability: "Server scripts of SuiteCommerce websites",
+    },
+    ModuleInfo {
+        name: "compress",
+        purpose: "Compresses and archives files",
+        members: &["compress.gzip", "compress.gunzip", "compress.createArchiver", "Archiver.add"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "config",
+        purpose: "Loads the configuration records of the account, such as company information and preferences",
+        members: &["config.load", "config.Type"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "crypto",
+        purpose: "Hashes, signs, and encrypts data with secret keys stored in the account",
+        members: &[
+            "crypto.createHash",
+            "crypto.createHmac",
+            "crypto.createSecretKey",
+            "crypto.createCipher",
+            "crypto.createDecipher",
+        ],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "crypto/certificate",
+        purpose: "Signs and verifies XML documents and strings with digital certificates",
+        members: &[
+            "certificate.signXml",
+            "certificate.verifyXmlSignature",
+            "certificate.createSigner",
+            "certificate.createVerifier",
+        ],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "currency",
+        purpose: "Gets the exchange rates between currencies",
+        members: &["currency.exchangeRate"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "currentrecord",
+        purpose: "Accesses the record open in the browser, such as the record of a form",
+        members: &[
+            "currentRecord.get",
+            "CurrentRecord.getValue",
+            "CurrentRecord.setValue",
+            "CurrentRecord.selectLine",
+            "CurrentRecord.commitLine",
+        ],
+        availability: CLIENT_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "dataset",
+        purpose: "Creates, loads, and runs the datasets of SuiteAnalytics Workbook",
+        members: &["dataset.create", "dataset.load", "dataset.list", "Dataset.run"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "email",
+        purpose: "Sends email messages, including bulk and campaign email",
+        members: &["email.send", "email.sendBulk", "email.sendCampaignEvent"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "encode",
+        purpose: "Converts strings between encodings, such as UTF-8, Base64, and hexadecimal",
+        members: &["encode.convert", "encode.Encoding"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "error",
+        purpose: "Creates custom errors to throw from scripts",
+        members: &["error.create", "error.Type"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "file",
+        purpose: "Creates, loads, and deletes files in the File Cabinet",
+        members: &["file.create", "file.load", "file.delete", "File.save", "File.getContents"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "format",
+        purpose: "Parses and formats dates, numbers, and currency amounts with the user preferences",
+        members: &["format.format", "format.parse", "format.Type"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "format/i18n",
+        purpose: "Formats currency amounts, numbers, and phone numbers for a locale",
+        members: &["i18n.getCurrencyFormatter", "i18n.getNumberFormatter", "i18n.getPhoneNumberFormatter"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "http",
+        purpose: "Sends HTTP requests, and defines the request and response types of Suitelets and RESTlets",
+        members: &["http.get", "http.post", "http.put", "http.delete", "http.request", "http.Method"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "https",
+        purpose: "Sends HTTPS requests, with support for secret credentials stored in the account",
+        members: &["https.get", "https.post", "https.request", "https.requestSuitelet", "https.createSecureString"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "https/clientcertificate",
+        purpose: "Sends HTTPS requests authenticated with a client certificate",
+        members: &["clientCertificate.get", "clientCertificate.post", "clientCertificate.request"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "keycontrol",
+        purpose: "Manages the private keys stored in the account",
+        members: &["keyControl.createKey", "keyControl.loadKey", "keyControl.findKeys", "keyControl.deleteKey"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "log",
+        purpose: "Writes entries to the execution log of a script",
+        members: &["log.debug", "log.audit", "log.error", "log.emergency"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "piremoval",
+        purpose: "Removes personal information from records, their system notes, and workflow history",
+        members: &["piremoval.createTask", "piremoval.loadTask", "PiRemovalTask.run"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "plugin",
+        purpose: "Loads the implementations of a custom plug-in",
+        members: &["plugin.loadImplementation", "plugin.findImplementations"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "portlet",
+        purpose: "Resizes and refreshes the portlet a script is running in",
+        members: &["portlet.resize", "portlet.refresh"],
+        availability: "Client scripts of form portlets",
+    },
+    ModuleInfo {
+        name: "query",
+        purpose: "Runs SuiteQL queries and queries built with the SuiteAnalytics Workbook engine",
+        members: &["query.create", "query.load", "query.runSuiteQL", "query.runSuiteQLPaged", "Query.run"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "record",
+        purpose: "Creates, loads, copies, transforms, and deletes records",
+        members: &[
+            "record.create",
+            "record.load",
+            "record.copy",
+            "record.transform",
+            "record.submitFields",
+            "record.delete",
+            "Record.save",
+        ],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "recordcontext",
+        purpose: "Gets the context of a record, such as its localization context",
+        members: &["recordContext.getContext", "recordContext.ContextType"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "redirect",
+        purpose: "Redirects the user to a record, a Suitelet, a saved search, or an external URL",
+        members: &["redirect.toRecord", "redirect.toSuitelet", "redirect.toSearch", "redirect.redirect"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "render",
+        purpose: "Prints transactions and renders Advanced PDF/HTML templates and email merges",
+        members: &["render.transaction", "render.create", "render.mergeEmail", "TemplateRenderer.renderAsPdf"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "runtime",
+        purpose: "Gets information about the current script, user, session, and account",
+        members: &[
+            "runtime.getCurrentScript",
+            "runtime.getCurrentUser",
+            "runtime.getCurrentSession",
+            "runtime.executionContext",
+            "Script.getRemainingUsage",
+        ],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "search",
+        purpose: "Creates, loads, and runs saved searches, and looks up fields of records",
+        members: &["search.create", "search.load", "search.lookupFields", "search.global", "Search.run", "Search.runPaged"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "sftp",
+        purpose: "Uploads and downloads files over SFTP",
+        members: &["sftp.createConnection", "Connection.upload", "Connection.download", "Connection.list"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "sso",
+        purpose: "Generates tokens for outbound single sign-on to external applications",
+        members: &["sso.generateSuiteSignOnToken"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "suiteappinfo",
+        purpose: "Checks which SuiteApps and bundles are installed in the account",
+        members: &["suiteAppInfo.isSuiteAppInstalled", "suiteAppInfo.isBundleInstalled", "suiteAppInfo.listSuiteAppInfo"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "task",
+        purpose: "Submits scheduled, map/reduce, CSV import, and search tasks to run in the background",
+        members: &["task.create", "task.checkStatus", "task.TaskType", "ScheduledScriptTask.submit"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "task/accounting/recognition",
+        purpose: "Merges revenue arrangements and revenue elements in the background",
+        members: &["recognition.create", "recognition.checkStatus"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "transaction",
+        purpose: "Voids transactions",
+        members: &["transaction.void"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "translation",
+        purpose: "Gets the strings of Translation Collections in the language of the user",
+        members: &["translation.get", "translation.load", "translation.selectLocale"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "ui/dialog",
+        purpose: "Shows alert and confirmation dialogs in the browser",
+        members: &["dialog.alert", "dialog.confirm", "dialog.create"],
+        availability: CLIENT_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "ui/message",
+        purpose: "Shows banner messages at the top of a page",
+        members: &["message.create", "message.Type", "Message.show", "Message.hide"],
+        availability: CLIENT_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "ui/serverwidget",
+        purpose: "Builds forms, lists, and assistants for Suitelets, and adds fields to forms before they load",
+        members: &["serverWidget.createForm", "serverWidget.createList", "Form.addField", "Form.addSublist", "Form.addSubmitButton"],
+        availability: "Suitelets, portlets, and user event scripts",
+    },
+    ModuleInfo {
+        name: "url",
+        purpose: "Resolves the URLs of records, scripts, and tasks",
+        members: &["url.resolveRecord", "url.resolveScript", "url.resolveTaskLink", "url.format"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "util",
+        purpose: "Checks the types of values, and iterates over and extends objects",
+        members: &["util.isArray", "util.isObject", "util.isString", "util.each", "util.extend"],
+        availability: ALL_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "workbook",
+        purpose: "Creates and loads SuiteAnalytics workbooks, and runs their pivots and tables",
+        members: &["workbook.create", "workbook.load", "workbook.list", "Workbook.runPivot"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "workflow",
+        purpose: "Initiates workflows on records and triggers their actions and buttons",
+        members: &["workflow.initiate", "workflow.trigger"],
+        availability: SERVER_SCRIPTS,
+    },
+    ModuleInfo {
+        name: "xml",
+        purpose: "Parses, queries, and validates XML documents",
+        members: &["xml.Parser.fromString", "xml.XPath.select", "xml.validate", "xml.escape"],
+        availability: SERVER_SCRIPTS,
+    },
+];
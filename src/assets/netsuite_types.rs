@@ -1,4 +1,4 @@
-pub const TYPES: [&str; 7] = [
+pub const TYPES: [&str; 13] = [
     "mapreduce",
     "userevent",
     "scheduled",
@@ -6,10 +6,28 @@ pub const TYPES: [&str; 7] = [
     "suitelet",
     "portlet",
     "restlet",
+    "bundleinstallation",
+    "massupdate",
+    "workflowaction",
+    "sdfinstallation",
+    "customgl",
+    "library",
+];
+
+pub const SUFFIXES: [(&str, &str); 7] = [
+    ("_ue", "userevent"),
+    ("_mr", "mapreduce"),
+    ("_cs", "client"),
+    ("_sl", "suitelet"),
+    ("_rl", "restlet"),
+    ("_ss", "scheduled"),
+    ("_wa", "workflowaction"),
 ];
 
 pub const API: [&str; 4] = ["2.1", "2", "2.x", "2.0"];
 
+pub const SCOPES: [&str; 3] = ["Public", "SameAccount", "TargetAccount"];
+
 pub const MODULES: [&str; 48] = [
     "action",
     "auth",
@@ -60,3 +78,89 @@ pub const MODULES: [&str; 48] = [
     "workflow",
     "xml",
 ];
+
+pub const SUITECOMMERCE_UNSUPPORTED: [&str; 12] = [
+    "compress",
+    "config",
+    "email",
+    "file",
+    "redirect",
+    "render",
+    "sftp",
+    "task",
+    "ui/dialog",
+    "ui/message",
+    "ui/serverwidget",
+    "workflow",
+];
+
+pub const DEPLOYMENT_STATUSES: [&str; 2] = ["testing", "released"];
+
+pub const LOG_LEVELS: [&str; 4] = ["debug", "audit", "error", "emergency"];
+
+pub const RECORD_TYPES: [&str; 64] = [
+    "assemblybuild",
+    "assemblyitem",
+    "assemblyunbuild",
+    "calendarevent",
+    "campaign",
+    "cashrefund",
+    "cashsale",
+    "check",
+    "classification",
+    "contact",
+    "creditcardcharge",
+    "creditmemo",
+    "customer",
+    "customerdeposit",
+    "customerpayment",
+    "customerrefund",
+    "department",
+    "deposit",
+    "descriptionitem",
+    "discountitem",
+    "employee",
+    "estimate",
+    "expensereport",
+    "giftcertificateitem",
+    "inventoryadjustment",
+    "inventoryitem",
+    "inventorytransfer",
+    "invoice",
+    "itemfulfillment",
+    "itemgroup",
+    "itemreceipt",
+    "job",
+    "journalentry",
+    "kititem",
+    "lead",
+    "location",
+    "lotnumberedinventoryitem",
+    "markupitem",
+    "message",
+    "noninventoryitem",
+    "note",
+    "opportunity",
+    "otherchargeitem",
+    "partner",
+    "paymentitem",
+    "phonecall",
+    "prospect",
+    "purchaseorder",
+    "purchaserequisition",
+    "returnauthorization",
+    "salesorder",
+    "serializedinventoryitem",
+    "serviceitem",
+    "subsidiary",
+    "subtotalitem",
+    "supportcase",
+    "task",
+    "transferorder",
+    "vendor",
+    "vendorbill",
+    "vendorcredit",
+    "vendorpayment",
+    "vendorreturnauthorization",
+    "workorder",
+];
@@ -0,0 +1,92 @@
+/// A snippet of boilerplate for a common `SuiteScript` pattern.
+pub struct Recipe {
+    /// Name used to select the recipe.
+    pub name: &'static str,
+    /// Modules the snippet uses, imported automatically when missing.
+    pub modules: &'static [&'static str],
+    /// Module whose import selects the recipe without naming it, if any.
+    pub trigger: Option<&'static str>,
+    /// Code inserted into the module body.
+    pub snippet: &'static str,
+}
+
+pub const RECIPES: [Recipe; 2] = [
+    Recipe {
+        name: "cache",
+        modules: &["cache"],
+        trigger: Some("cache"),
+        snippet: "  /**
+   * Time to live of cached values, in seconds. Must be at least 300.
+   */
+  const CACHE_TTL = 300;
+
+  /**
+   * Loads the value for a key that is missing from the cache.
+   *
+   * @param {Object} context
+   * @param {string} context.key - The key that was not found in the cache
+   * @returns {string} The value to cache, serialized as a string
+   */
+  const loader = (context) => {
+    // TODO: Load the value for context.key
+    return '';
+  };
+
+  /**
+   * Gets a cached value, calling the loader if the key is missing or expired.
+   *
+   * @param {string} key
+   * @returns {string}
+   */
+  const getCachedValue = (key) => {
+    const scriptCache = cache.getCache({ name: 'SCRIPT_CACHE', scope: cache.Scope.PRIVATE });
+    // {cost:cache.Cache.get}
+    return scriptCache.get({ key, loader, ttl: CACHE_TTL });
+  };",
+    },
+    Recipe {
+        name: "workflow",
+        modules: &["workflow", "runtime"],
+        trigger: None,
+        snippet: "  /**
+   * Script parameters holding the ids of the workflow and of the workflow action to trigger.
+   */
+  const WORKFLOW_ID_PARAM = 'custscript_workflow_id';
+  const WORKFLOW_ACTION_ID_PARAM = 'custscript_workflow_action_id';
+
+  /**
+   * Initiates the workflow on a record.
+   *
+   * @param {string} recordType
+   * @param {number} recordId
+   * @returns {number} The id of the workflow instance
+   */
+  const initiateWorkflow = (recordType, recordId) => {
+    const script = runtime.getCurrentScript();
+    // {cost:workflow.initiate}
+    return workflow.initiate({
+      recordType,
+      recordId,
+      workflowId: script.getParameter({ name: WORKFLOW_ID_PARAM }),
+    });
+  };
+
+  /**
+   * Triggers the workflow action on a record.
+   *
+   * @param {string} recordType
+   * @param {number} recordId
+   * @returns {number} The id of the record
+   */
+  const triggerWorkflowAction = (recordType, recordId) => {
+    const script = runtime.getCurrentScript();
+    // {cost:workflow.trigger}
+    return workflow.trigger({
+      recordType,
+      recordId,
+      workflowId: script.getParameter({ name: WORKFLOW_ID_PARAM }),
+      actionId: script.getParameter({ name: WORKFLOW_ACTION_ID_PARAM }),
+    });
+  };",
+    },
+];
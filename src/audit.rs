@@ -0,0 +1,545 @@
+use ignore::WalkBuilder;
+use regex::Regex;
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::config::load_spec;
+use super::{
+    describe_naming_convention, escape_json, get_api_version, get_copyright_pattern, get_file_name,
+    get_suffixes, map_script_to_name, read_copyright, read_to_string, validate_api_version,
+    ScriptSpec, SuiteScriptError,
+};
+
+/// Name of the file listing the paths that project-scanning commands skip.
+const IGNORE_FILE: &str = ".suitescriptignore";
+
+/// Options for auditing the scripts of a project.
+#[derive(StructOpt, Debug)]
+pub struct AuditOpt {
+    /// Directory containing the scripts to audit
+    #[structopt(parse(from_os_str))]
+    dir: PathBuf,
+
+    /// Version of the `SuiteScript` API every script must use, the `apiversion` of the config, or
+    /// 2.1, if omitted
+    #[structopt(short, long = "apiversion", validator = validate_api_version)]
+    api_version: Option<String>,
+
+    /// Path to a file containing the copyright message every script must carry, the `copyright`,
+    /// `copyright-text`, and `copyrights` table of the config if omitted
+    #[structopt(short, long = "copyright", parse(from_os_str))]
+    copyright: Option<PathBuf>,
+
+    /// Company name substituted for `{{company}}` in the copyright message, the `company` of the
+    /// config if omitted
+    #[structopt(long = "company", env = "SUITESCRIPT_COMPANY")]
+    company: Option<String>,
+
+    /// Format of the findings report
+    #[structopt(long = "format", default_value = "table", possible_values = &["table", "json", "csv"])]
+    format: String,
+}
+
+/// Conventions every script of a project is expected to follow.
+struct Conventions {
+    api_version: String,
+    /// Copyright message, before its placeholders are substituted for each script.
+    copyright: Option<String>,
+    /// Copyright messages of the directories of the `copyrights` table of a config, as pairs of an
+    /// absolute directory and a message, which take precedence over `copyright` for the scripts in
+    /// each directory.
+    copyrights: Vec<(PathBuf, String)>,
+    /// Company name substituted for `{{company}}` in the copyright message.
+    company: String,
+    /// File name suffixes of script types, as pairs of a suffix and a script type.
+    suffixes: Vec<(String, String)>,
+    /// Regular expression file names must match, set by the `filename-pattern` option of a config.
+    file_name_pattern: Option<Regex>,
+    /// Description of the naming convention, set by the `filename-convention` option of a config.
+    file_name_convention: Option<String>,
+}
+
+/// Audits every script in a directory and prints a report of findings.
+///
+/// The API version and copyright messages are those of the configs of the directory, unless they
+/// are given as options.
+///
+/// Returns whether every script follows the conventions, so that the CLI can exit with a non-zero
+/// status and the audit can be used as a CI check.
+///
+/// # Errors
+/// Returns an error if the directory, a script, the copyright file, or a config cannot be read
+pub fn run(opt: &AuditOpt) -> Result<bool, SuiteScriptError> {
+    let spec = load_spec(&opt.dir)?;
+    let (copyright, copyrights) = match &opt.copyright {
+        Some(path) => {
+            let copyright = read_copyright(&ScriptSpec {
+                copyright: path.clone(),
+                ..ScriptSpec::default()
+            })?;
+            (copyright, Vec::new())
+        }
+        None => {
+            let copyright = read_copyright(&ScriptSpec {
+                copyright: spec.copyright.clone(),
+                copyright_text: spec.copyright_text.clone(),
+                ..ScriptSpec::default()
+            })?;
+            let mut copyrights = Vec::new();
+            for (dir, path) in &spec.copyrights {
+                let message = read_copyright(&ScriptSpec {
+                    copyright: path.clone(),
+                    ..ScriptSpec::default()
+                })?;
+                copyrights.push((dir.clone(), message.unwrap_or_default()));
+            }
+            (copyright, copyrights)
+        }
+    };
+    let conventions = Conventions {
+        api_version: get_api_version(opt.api_version.as_deref().unwrap_or(&spec.api_version)),
+        copyright,
+        copyrights,
+        company: opt.company.clone().unwrap_or_else(|| spec.company.clone()),
+        suffixes: get_suffixes(&spec),
+        file_name_pattern: match &spec.file_name_pattern {
+            Some(pattern) => Some(Regex::new(pattern).map_err(|error| {
+                SuiteScriptError::Validation(format!(
+                    "Invalid filename-pattern {}: {}",
+                    pattern, error
+                ))
+            })?),
+            None => None,
+        },
+        file_name_convention: spec.file_name_convention.clone(),
+    };
+
+    let scripts = find_scripts(&opt.dir)?;
+    let mut findings = Vec::new();
+    for script in &scripts {
+        let contents = read_to_string(script)?;
+        for finding in audit_script(script, &contents, &conventions) {
+            findings.push((script.display().to_string(), finding));
+        }
+    }
+
+    match opt.format.as_str() {
+        "json" => print!("{}", format_json(scripts.len(), &findings)),
+        "csv" => print!("{}", format_csv(&findings)),
+        _ if findings.is_empty() => println!("No findings in {} scripts", scripts.len()),
+        _ => print_findings(&findings),
+    }
+
+    Ok(findings.is_empty())
+}
+
+/// Recursively collects the JavaScript and `TypeScript` files in a directory, sorted by path.
+///
+/// `TypeScript` declaration files, such as `index.d.ts`, are not scripts and are skipped. Files matched by a `.suitescriptignore` file, in gitignore syntax, in the directory, one of its
+/// subdirectories, or one of its parents are skipped. A path to a single file is returned as is.
+///
+/// # Errors
+/// Returns an IO error if the directory cannot be read
+pub fn find_scripts(dir: &Path) -> Result<Vec<PathBuf>, SuiteScriptError> {
+    let mut scripts = Vec::new();
+    for entry in WalkBuilder::new(dir)
+        .standard_filters(false)
+        .parents(true)
+        .add_custom_ignore_filename(IGNORE_FILE)
+        .build()
+    {
+        let path = entry
+            .map_err(|error| {
+                let source = io::Error::other(error);
+                SuiteScriptError::io(format!("Failed to read {}", dir.display()), source)
+            })?
+            .into_path();
+        if path.is_file() && is_script(&path) {
+            scripts.push(path);
+        }
+    }
+
+    scripts.sort();
+    Ok(scripts)
+}
+
+/// Checks whether a file is a JavaScript or `TypeScript` script, rather than a declaration file.
+fn is_script(path: &Path) -> bool {
+    match path.extension().and_then(OsStr::to_str) {
+        Some("js") => true,
+        Some("ts") => !path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|name| name.ends_with(".d.ts")),
+        _ => false,
+    }
+}
+
+/// Gets the value of a `JSDoc` tag in a script, if present.
+pub fn get_tag<'a>(contents: &'a str, tag: &str) -> Option<&'a str> {
+    contents.lines().find_map(|line| {
+        let mut words = line.split_whitespace().skip_while(|word| *word != tag);
+        words.next()?;
+        words.next()
+    })
+}
+
+/// Checks a single script against the conventions.
+///
+/// Returns a description of each convention the script does not follow. The copyright message is
+/// matched as it is generated for the script, in any year. Custom GL plugins are not
+/// versioned, so they are not checked for an API version. File names are checked against the
+/// `filename-pattern` of the config if it sets one, and must be lowercase `snake_case` otherwise.
+fn audit_script(path: &Path, contents: &str, conventions: &Conventions) -> Vec<String> {
+    let mut findings = Vec::new();
+
+    match get_tag(contents, "@NApiVersion") {
+        None if contents.contains("function customizeGlImpact(") => {}
+        None => findings.push(String::from("Missing @NApiVersion tag")),
+        Some(version) if get_api_version(version) != conventions.api_version => {
+            findings.push(format!(
+                "API version {} does not match {}",
+                version, conventions.api_version
+            ));
+        }
+        Some(_) => {}
+    }
+
+    if let Some(copyright) = get_script_copyright(path, conventions) {
+        let script = ScriptSpec {
+            file_name: Some(path.to_owned()),
+            company: conventions.company.clone(),
+            ..ScriptSpec::default()
+        };
+        if !get_copyright_pattern(copyright, &script).is_match(contents) {
+            findings.push(String::from("Copyright message does not match"));
+        }
+    }
+
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    match &conventions.file_name_pattern {
+        Some(pattern) => {
+            let name = get_file_name(path);
+            if !pattern.is_match(&name) {
+                findings.push(describe_naming_convention(
+                    &name,
+                    pattern.as_str(),
+                    conventions.file_name_convention.as_deref(),
+                ));
+            }
+        }
+        None => {
+            if stem
+                .chars()
+                .any(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))
+            {
+                findings.push(String::from(
+                    "File name must only contain lowercase letters, digits, and underscores",
+                ));
+            }
+        }
+    }
+
+    if let Some(tag) = get_tag(contents, "@NScriptType") {
+        let script_type = map_script_to_name(tag.trim_end_matches("Script"));
+        let suffix = conventions
+            .suffixes
+            .iter()
+            .find(|(_, name)| !script_type.is_empty() && map_script_to_name(name) == script_type)
+            .map(|(suffix, _)| suffix);
+        if let Some(suffix) = suffix {
+            if !stem.to_lowercase().ends_with(&suffix.to_lowercase()) {
+                findings.push(format!("File name must end with {} for a {}", suffix, tag));
+            }
+        }
+    }
+
+    findings
+}
+
+/// Gets the copyright message a script must carry, that of the deepest directory of the
+/// `copyrights` table containing it, if any.
+fn get_script_copyright<'a>(path: &Path, conventions: &'a Conventions) -> Option<&'a str> {
+    let path = std::env::current_dir().map_or_else(|_| path.to_owned(), |dir| dir.join(path));
+    conventions
+        .copyrights
+        .iter()
+        .filter(|(dir, _)| path.starts_with(dir))
+        .max_by_key(|(dir, _)| dir.components().count())
+        .map(|(_, copyright)| copyright.as_str())
+        .or(conventions.copyright.as_deref())
+        .filter(|copyright| !copyright.is_empty())
+}
+
+/// Prints the findings as a table with one row per finding.
+pub fn print_findings(findings: &[(String, String)]) {
+    let width = findings
+        .iter()
+        .map(|(file, _)| file.len())
+        .chain(std::iter::once("File".len()))
+        .max()
+        .unwrap_or_default();
+
+    println!("{:width$}  Finding", "File", width = width);
+    for (file, finding) in findings {
+        println!("{:width$}  {}", file, finding, width = width);
+    }
+}
+
+/// Formats the findings as a JSON object with the number of scripts audited.
+pub fn format_json(scripts: usize, findings: &[(String, String)]) -> String {
+    let entries: Vec<String> = findings
+        .iter()
+        .map(|(file, finding)| {
+            format!(
+                "    {{ \"file\": \"{}\", \"finding\": \"{}\" }}",
+                escape_json(file),
+                escape_json(finding)
+            )
+        })
+        .collect();
+
+    if entries.is_empty() {
+        return format!("{{\n  \"scripts\": {},\n  \"findings\": []\n}}\n", scripts);
+    }
+    format!(
+        "{{\n  \"scripts\": {},\n  \"findings\": [\n{}\n  ]\n}}\n",
+        scripts,
+        entries.join(",\n")
+    )
+}
+
+/// Formats the findings as CSV with a header row.
+pub fn format_csv(findings: &[(String, String)]) -> String {
+    let mut csv = String::from("file,finding\n");
+    for (file, finding) in findings {
+        csv.push_str(&format!("{},{}\n", escape_csv(file), escape_csv(finding)));
+    }
+    csv
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote, or line break.
+pub fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", value.replace('"', "\"\""));
+    }
+    value.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::generate;
+    use super::*;
+
+    fn conventions() -> Conventions {
+        Conventions {
+            api_version: String::from("2.1"),
+            copyright: Some(String::from("Copyright (c) MyCompany")),
+            copyrights: Vec::new(),
+            company: String::new(),
+            suffixes: get_suffixes(&ScriptSpec::default()),
+            file_name_pattern: None,
+            file_name_convention: None,
+        }
+    }
+
+    #[test]
+    fn test_find_scripts_ignored() {
+        let dir = std::env::temp_dir().join(format!("suitescript-ignore-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("vendor")).unwrap();
+        std::fs::write(dir.join(IGNORE_FILE), "vendor/\n*.min.js\n").unwrap();
+        for file in &[
+            "customer_ue.js",
+            "bundle.min.js",
+            "vendor/lib.js",
+            "notes.txt",
+        ] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+
+        let scripts = find_scripts(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(scripts, vec![dir.join("customer_ue.js")]);
+    }
+
+    #[test]
+    fn test_find_scripts_typescript() {
+        let dir = std::env::temp_dir().join(format!("suitescript-ts-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in &["customer_ue.ts", "order_cs.js", "types.d.ts", "README.md"] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+
+        let scripts = find_scripts(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            scripts,
+            vec![dir.join("customer_ue.ts"), dir.join("order_cs.js")]
+        );
+    }
+
+    #[test]
+    fn test_get_tag() {
+        assert_eq!(
+            get_tag("/**\n * @NApiVersion 2.x\n */", "@NApiVersion"),
+            Some("2.x")
+        );
+        assert_eq!(get_tag("define([], () => {});", "@NApiVersion"), None);
+    }
+
+    #[test]
+    fn test_format_json() {
+        let findings = vec![(
+            String::from("src/\"quoted\".js"),
+            String::from("Missing @NApiVersion tag"),
+        )];
+        assert_eq!(
+            format_json(2, &findings),
+            "{\n  \"scripts\": 2,\n  \"findings\": [\n    { \"file\": \"src/\\\"quoted\\\".js\", \"finding\": \"Missing @NApiVersion tag\" }\n  ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_csv() {
+        let findings = vec![(
+            String::from("a.js"),
+            String::from("API version 2.0 does not match 2.1, see \"docs\""),
+        )];
+        assert_eq!(
+            format_csv(&findings),
+            "file,finding\na.js,\"API version 2.0 does not match 2.1, see \"\"docs\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_audit_conforming_script() {
+        let contents = "/**\n * Copyright (c) MyCompany\n */\n\n/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.1\n */";
+        assert_eq!(
+            audit_script(Path::new("customer_ue.js"), contents, &conventions()),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_audit_custom_gl_plugin() {
+        let contents = "/**\n * Copyright (c) MyCompany\n */\n\nfunction customizeGlImpact(transactionRecord, standardLines, customLines, book) {\n}\n";
+        assert_eq!(
+            audit_script(Path::new("gl_plugin.js"), contents, &conventions()),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_audit_nonconforming_script() {
+        let contents = "/**\n * @NScriptType MapReduceScript\n * @NApiVersion 2.0\n */";
+        assert_eq!(
+            audit_script(Path::new("Customer.js"), contents, &conventions()),
+            vec![
+                String::from("API version 2.0 does not match 2.1"),
+                String::from("Copyright message does not match"),
+                String::from(
+                    "File name must only contain lowercase letters, digits, and underscores"
+                ),
+                String::from("File name must end with _mr for a MapReduceScript"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_configured_suffixes() {
+        let contents = "/**\n * Copyright (c) MyCompany\n */\n\n/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.1\n */";
+        let conventions = Conventions {
+            suffixes: vec![(String::from("_userevent"), String::from("ue"))],
+            ..conventions()
+        };
+        assert_eq!(
+            audit_script(Path::new("customer_userevent.js"), contents, &conventions),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            audit_script(Path::new("customer_ue.js"), contents, &conventions),
+            vec![String::from(
+                "File name must end with _userevent for a UserEventScript"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_audit_filename_pattern() {
+        let contents = "/**\n * Copyright (c) MyCompany\n */\n\n/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.1\n */";
+        let conventions = Conventions {
+            file_name_pattern: Some(Regex::new(r"^[A-Z][A-Za-z]+_ue\.js$").unwrap()),
+            ..conventions()
+        };
+        assert_eq!(
+            audit_script(Path::new("src/Customer_ue.js"), contents, &conventions),
+            Vec::<String>::new()
+        );
+        let conventions = Conventions {
+            file_name_convention: Some(String::from("PascalCase, such as Customer_ue.js")),
+            ..conventions
+        };
+        assert_eq!(
+            audit_script(Path::new("src/customer_ue.js"), contents, &conventions),
+            vec![String::from(
+                r"File name customer_ue.js does not follow the naming convention of the project: PascalCase, such as Customer_ue.js (filename-pattern ^[A-Z][A-Za-z]+_ue\.js$)"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_audit_directory_copyright() {
+        let contents = "/**\n * Copyright (c) Vendor\n */\n\n/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.1\n */";
+        let dir = std::env::current_dir().unwrap().join("vendor");
+        let conventions = Conventions {
+            copyrights: vec![(dir, String::from("Copyright (c) Vendor"))],
+            ..conventions()
+        };
+        assert_eq!(
+            audit_script(Path::new("vendor/lib_ue.js"), contents, &conventions),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            audit_script(Path::new("src/customer_ue.js"), contents, &conventions),
+            vec![String::from("Copyright message does not match")]
+        );
+    }
+
+    #[test]
+    fn test_audit_generated_copyright() {
+        let message = "Copyright (c) {{year}} {{company}}\nFile: {{file}}";
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("src/customer_ue.js")),
+            copyright_text: Some(message.replace('\n', "\\n")),
+            company: String::from("MyCompany"),
+            ..ScriptSpec::default()
+        };
+        let contents = generate(&spec).unwrap();
+        let conventions = Conventions {
+            copyright: Some(String::from(message)),
+            company: String::from("MyCompany"),
+            ..conventions()
+        };
+        assert_eq!(
+            audit_script(Path::new("src/customer_ue.js"), &contents, &conventions),
+            Vec::<String>::new()
+        );
+        let previous = contents.replace(
+            &format!("(c) {} ", super::super::current_year()),
+            "(c) 2019 ",
+        );
+        assert_eq!(
+            audit_script(Path::new("src/customer_ue.js"), &previous, &conventions),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            audit_script(Path::new("src/invoice_ue.js"), &contents, &conventions),
+            vec![String::from("Copyright message does not match")]
+        );
+    }
+}
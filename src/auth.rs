@@ -0,0 +1,267 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::{Command, Stdio};
+use structopt::StructOpt;
+
+use super::keyring::{delete_secret, get_secret, set_secret};
+use super::oauth::Credentials;
+use super::wizard::ask;
+use super::SuiteScriptError;
+
+/// Options for managing the profiles of credentials kept in the keyring.
+#[derive(StructOpt, Debug)]
+pub struct AuthOpt {
+    #[structopt(subcommand)]
+    command: AuthCommand,
+}
+
+#[derive(StructOpt, Debug)]
+enum AuthCommand {
+    /// Prompts for the account ID and the token based authentication credentials of a profile, and
+    /// stores them in the keyring, replacing the profile of the same name
+    Add {
+        #[structopt(validator = validate_profile)]
+        profile: String,
+    },
+    /// Removes a profile from the keyring
+    Remove {
+        #[structopt(validator = validate_profile)]
+        profile: String,
+    },
+}
+
+/// Account and credentials of a profile, used to sign the requests sent to the account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    /// ID of the account, such as `1234567_SB1`
+    pub account: String,
+    /// Token based authentication credentials of the integration and the user
+    pub credentials: Credentials,
+}
+
+/// Fields of a profile in the order they are prompted for and stored, each with its question and
+/// whether its value is hidden as it is typed.
+const FIELDS: [(&str, &str, bool); 5] = [
+    ("account", "Account ID, such as 1234567_SB1", false),
+    ("consumer_key", "Consumer key of the integration", false),
+    (
+        "consumer_secret",
+        "Consumer secret of the integration",
+        true,
+    ),
+    ("token_id", "Token ID", false),
+    ("token_secret", "Token secret", true),
+];
+
+/// Runs the `auth` command.
+///
+/// With `plain`, `auth add` reads the fields of the profile from stdin one per line, in the order
+/// they are prompted for, without prompting for them or turning off the echo of the terminal.
+///
+/// # Errors
+/// Returns an IO error if the terminal or the keyring cannot be read or written, and a validation
+/// error if the profile to remove does not exist
+pub fn run(opt: &AuthOpt, plain: bool) -> Result<(), SuiteScriptError> {
+    match &opt.command {
+        AuthCommand::Add { profile } => {
+            let stdin = io::stdin();
+            let values = if plain {
+                read_fields(&mut stdin.lock())
+            } else {
+                prompt(&mut stdin.lock(), &mut io::stdout())
+            }
+            .map_err(|source| SuiteScriptError::io("Failed to read the credentials", source))?;
+            set_secret(profile, &values.join(" ")).map_err(|source| {
+                SuiteScriptError::io(format!("Failed to store profile {}", profile), source)
+            })?;
+            println!("Stored profile {} in the keyring", profile);
+        }
+        AuthCommand::Remove { profile } => {
+            let removed = delete_secret(profile).map_err(|source| {
+                SuiteScriptError::io(format!("Failed to remove profile {}", profile), source)
+            })?;
+            if !removed {
+                return Err(missing_profile(profile));
+            }
+            println!("Removed profile {} from the keyring", profile);
+        }
+    }
+    Ok(())
+}
+
+/// Loads a profile from the keyring.
+///
+/// # Errors
+/// Returns a validation error if the profile does not exist or is incomplete, and an IO error if
+/// the keyring cannot be read
+pub fn load_profile(profile: &str) -> Result<Profile, SuiteScriptError> {
+    let secret = get_secret(profile)
+        .map_err(|source| {
+            SuiteScriptError::io(format!("Failed to read profile {}", profile), source)
+        })?
+        .ok_or_else(|| missing_profile(profile))?;
+    parse_profile(&secret).ok_or_else(|| {
+        SuiteScriptError::Validation(format!(
+            "Profile {} is incomplete, store it again with `suitescript auth add {}`",
+            profile, profile
+        ))
+    })
+}
+
+/// Gets the error of a profile that is not in the keyring.
+fn missing_profile(profile: &str) -> SuiteScriptError {
+    SuiteScriptError::Validation(format!(
+        "Profile {} does not exist, add it with `suitescript auth add {}`",
+        profile, profile
+    ))
+}
+
+/// Prompts for the fields of a profile, formatted as `name=value` for the keyring.
+///
+/// The secrets are not echoed when they are typed in a terminal.
+fn prompt(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<Vec<String>> {
+    let mut values = Vec::new();
+    for (name, question, hidden) in &FIELDS {
+        let hidden = *hidden && io::stdin().is_terminal();
+        if hidden {
+            set_echo(false);
+        }
+        let answer = ask(input, output, question, "", validate_value);
+        if hidden {
+            set_echo(true);
+            writeln!(output)?;
+        }
+        values.push(format!("{}={}", name, answer?));
+    }
+    Ok(values)
+}
+
+/// Reads the fields of a profile one per line, formatted as `name=value` for the keyring.
+///
+/// Unlike [`prompt`], an invalid value is an error rather than asked for again.
+fn read_fields(input: &mut impl BufRead) -> io::Result<Vec<String>> {
+    let mut values = Vec::new();
+    for (name, question, _) in &FIELDS {
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Input ended before every field was read",
+            ));
+        }
+        let answer = answer.trim().to_owned();
+        validate_value(answer.clone()).map_err(|message| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{}: {}", question, message),
+            )
+        })?;
+        values.push(format!("{}={}", name, answer));
+    }
+    Ok(values)
+}
+
+/// Turns the echo of the terminal on or off with `stty`, leaving it as it is if `stty` fails.
+fn set_echo(enabled: bool) {
+    let _ = Command::new("stty")
+        .arg(if enabled { "echo" } else { "-echo" })
+        .stdin(Stdio::inherit())
+        .status();
+}
+
+/// Validates a field of a profile, which is stored in one line separated by spaces.
+fn validate_value(value: String) -> Result<(), String> {
+    if value.is_empty() {
+        return Err(String::from("A value is required"));
+    }
+    if value.contains(char::is_whitespace) {
+        return Err(String::from("The value cannot contain spaces"));
+    }
+    Ok(())
+}
+
+/// Validates the name of a profile, made of letters, digits, `-`, and `_`.
+///
+/// # Errors
+/// Returns an error message if the name is empty or has other characters
+pub fn validate_profile(profile: String) -> Result<(), String> {
+    if profile.is_empty()
+        || !profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "Invalid profile {}, use letters, digits, `-`, and `_`",
+            profile
+        ));
+    }
+    Ok(())
+}
+
+/// Parses a profile stored in the keyring, such as `account=1234567 consumer_key=...`.
+///
+/// Returns `None` if a field is missing.
+fn parse_profile(secret: &str) -> Option<Profile> {
+    let get = |field: &str| {
+        secret
+            .split_whitespace()
+            .find_map(|pair| pair.strip_prefix(field)?.strip_prefix('='))
+            .map(str::to_owned)
+    };
+    Some(Profile {
+        account: get("account")?,
+        credentials: Credentials {
+            consumer_key: get("consumer_key")?,
+            consumer_secret: get("consumer_secret")?,
+            token_id: get("token_id")?,
+            token_secret: get("token_secret")?,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt() {
+        let mut input = io::Cursor::new("1234567_SB1\nck\ncs\n\nti\nts\n");
+        let mut output = Vec::new();
+        let values = prompt(&mut input, &mut output).unwrap();
+        assert_eq!(
+            parse_profile(&values.join(" ")),
+            Some(Profile {
+                account: String::from("1234567_SB1"),
+                credentials: Credentials {
+                    consumer_key: String::from("ck"),
+                    consumer_secret: String::from("cs"),
+                    token_id: String::from("ti"),
+                    token_secret: String::from("ts"),
+                },
+            })
+        );
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("error: A value is required"));
+        assert_eq!(parse_profile("account=1234567 token_id=ti"), None);
+    }
+
+    #[test]
+    fn test_read_fields() {
+        let mut input = io::Cursor::new("1234567_SB1\nck\ncs\nti\nts\n");
+        assert_eq!(
+            read_fields(&mut input).unwrap(),
+            vec![
+                "account=1234567_SB1",
+                "consumer_key=ck",
+                "consumer_secret=cs",
+                "token_id=ti",
+                "token_secret=ts"
+            ]
+        );
+        let mut input = io::Cursor::new("1234567_SB1\n\n");
+        assert_eq!(
+            read_fields(&mut input).unwrap_err().to_string(),
+            "Consumer key of the integration: A value is required"
+        );
+    }
+}
@@ -0,0 +1,768 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use structopt::StructOpt;
+
+use super::config::Value;
+use super::lock::{get_key, hash_contents, Lock, LockedFile};
+use super::{
+    get_copyright, get_template, read_to_string, remote, write_atomically, Fnv1a, ScriptSpec,
+    SuiteScriptError,
+};
+
+/// Options for generating every script listed in a manifest or a CSV file.
+#[derive(StructOpt, Debug)]
+pub struct BatchOpt {
+    /// Manifest listing the scripts to generate, in YAML or, with a `.json` extension, JSON
+    #[structopt(parse(from_os_str), required_unless = "from-csv")]
+    pub manifest: Option<PathBuf>,
+
+    /// CSV file listing the scripts to generate, one per row, under a header row naming the options
+    #[structopt(long = "from-csv", parse(from_os_str), conflicts_with = "manifest")]
+    pub from_csv: Option<PathBuf>,
+
+    /// Number of scripts to render and write at the same time, the number of CPUs by default
+    #[structopt(short, long = "jobs", validator = validate_jobs)]
+    pub jobs: Option<usize>,
+
+    /// Regenerates every script, including those whose options, template, and files are unchanged
+    /// since the previous batch
+    #[structopt(long = "no-cache")]
+    pub no_cache: bool,
+}
+
+impl BatchOpt {
+    /// Gets the number of threads to render and write scripts on.
+    pub fn get_jobs(&self) -> usize {
+        self.jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+        })
+    }
+}
+
+/// A script listed in a manifest, as the options used to generate it in the order they are
+/// written.
+pub type ManifestEntry = Vec<(String, Value)>;
+
+/// A script to generate, with the label used to report its errors, such as `row 2`, or the reason
+/// it cannot be read as a script.
+pub type LabelledEntry = (String, Result<ManifestEntry, String>);
+
+/// Names of CSV columns that differ from the long names of the options they set.
+const COLUMN_ALIASES: [(&str, &str); 5] = [
+    ("file", "filename"),
+    ("file name", "filename"),
+    ("type", "scripttype"),
+    ("script type", "scripttype"),
+    ("api version", "apiversion"),
+];
+
+/// Options whose CSV cells list several values, separated by commas or whitespace.
+const LIST_COLUMNS: [&str; 5] = ["modules", "recipes", "events", "entries", "methods"];
+
+/// Options whose values are paths, resolved from the directory of the manifest or CSV file.
+const PATH_OPTIONS: [&str; 4] = ["filename", "copyright", "template", "templates-dir"];
+
+/// Path of the cache of the scripts generated by batches, relative to the directory of the lock
+/// manifest of the project.
+pub const CACHE_FILE: &str = ".suitescript/cache.toml";
+
+/// The scripts generated by the previous batches of a project, in `.suitescript/cache.toml` next
+/// to its lock manifest, so that a batch skips the scripts that would be generated the same way.
+///
+/// Each script is recorded with a hash of what it is generated from, and the hashes of the files
+/// written for it. A script is skipped when its hash matches and its files were neither edited nor
+/// removed since.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Cache {
+    /// Directory of the cache, which the paths of the scripts are relative to
+    #[serde(skip)]
+    dir: PathBuf,
+    /// Directory the generator is run in, which the paths given to the generator are relative to
+    #[serde(skip)]
+    current_dir: PathBuf,
+    /// Scripts generated, sorted by path
+    #[serde(default, rename = "script")]
+    scripts: Vec<CachedScript>,
+}
+
+/// A script recorded in the cache of the scripts generated by batches.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CachedScript {
+    /// Path of the script, relative to the directory of the cache, with `/` separators
+    pub path: String,
+    /// Hash of the options, copyright message, and template the script was generated with, and of
+    /// the version of the generator, as returned by [`hash_entry`]
+    pub hash: String,
+    /// Files written for the script, including itself, with the hashes of their contents
+    #[serde(default, rename = "file")]
+    pub files: Vec<LockedFile>,
+}
+
+impl Cache {
+    /// Opens the cache of the project a directory is in.
+    ///
+    /// A cache that does not exist or cannot be read is empty, so that every script is generated.
+    pub fn open(current_dir: &Path) -> Cache {
+        let dir = Lock::find(current_dir)
+            .parent()
+            .unwrap_or(current_dir)
+            .to_path_buf();
+        let cache = std::fs::read_to_string(dir.join(CACHE_FILE))
+            .ok()
+            .and_then(|contents| toml::from_str::<Cache>(&contents).ok())
+            .unwrap_or_default();
+        Cache {
+            dir,
+            current_dir: current_dir.to_path_buf(),
+            ..cache
+        }
+    }
+
+    /// Gets the path of the cache.
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(CACHE_FILE)
+    }
+
+    /// Checks whether a script was generated from the same hash by a previous batch, and its files
+    /// are unchanged since.
+    pub fn is_fresh(&self, script: &Path, hash: &str) -> bool {
+        let key = get_key(&self.dir, &self.current_dir, script);
+        self.scripts
+            .iter()
+            .find(|cached| cached.path == key)
+            .is_some_and(|cached| {
+                cached.hash == hash
+                    && cached.files.iter().all(|file| {
+                        std::fs::read(self.dir.join(&file.path))
+                            .is_ok_and(|contents| hash_contents(&contents) == file.hash)
+                    })
+            })
+    }
+
+    /// Records a script generated from a hash, with the files written for it as they are now.
+    ///
+    /// A file that cannot be read is not recorded, so that the script is generated again.
+    pub fn record(&mut self, script: &Path, hash: String, files: &[PathBuf]) {
+        let key = get_key(&self.dir, &self.current_dir, script);
+        let files = files
+            .iter()
+            .map(|path| LockedFile {
+                path: get_key(&self.dir, &self.current_dir, path),
+                hash: std::fs::read(path)
+                    .map(|contents| hash_contents(&contents))
+                    .unwrap_or_default(),
+            })
+            .collect();
+        self.scripts.retain(|cached| cached.path != key);
+        self.scripts.push(CachedScript {
+            path: key,
+            hash,
+            files,
+        });
+        self.scripts
+            .sort_by(|left, right| left.path.cmp(&right.path));
+    }
+
+    /// Writes the cache.
+    ///
+    /// # Errors
+    /// Returns an IO error if the cache cannot be written
+    pub fn save(&self) -> Result<(), SuiteScriptError> {
+        let path = self.path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|source| {
+                SuiteScriptError::io(
+                    format!("Failed to create directory {}", parent.display()),
+                    source,
+                )
+            })?;
+        }
+        let contents = toml::to_string(self).map_err(|error| {
+            SuiteScriptError::Validation(format!(
+                "Failed to write batch cache {}: {}",
+                path.display(),
+                error
+            ))
+        })?;
+        write_atomically(&path, &contents)
+    }
+}
+
+/// Hashes what a script is generated from: its resolved options, its copyright message, its
+/// template, and the version of the generator, whose built-in templates change with it.
+///
+/// # Errors
+/// Returns an error if the copyright message or the template cannot be read
+pub fn hash_entry(config: &ScriptSpec) -> Result<String, SuiteScriptError> {
+    let hash = Fnv1a::default()
+        .update(env!("CARGO_PKG_VERSION").as_bytes())
+        .update(format!("{:?}", config).as_bytes())
+        .update(get_copyright(config)?.as_bytes())
+        .update(get_template(config)?.as_bytes())
+        .finish();
+    Ok(format!("{:016x}", hash))
+}
+
+/// Reads the scripts listed in the manifest or the CSV file of the options.
+///
+/// Returns the path of the file read, and each script with the label used to report its errors,
+/// such as `script 1` in a manifest or `row 2` in a CSV file. Rows of a CSV file that cannot be read
+/// as a script are returned as errors, so that they are reported with the other invalid scripts.
+/// Relative paths are resolved from the directory of the file read.
+///
+/// # Errors
+/// Returns an IO error if the file cannot be read, and a validation error if it is invalid as a
+/// whole
+pub fn load_entries(opt: &BatchOpt) -> Result<(&Path, Vec<LabelledEntry>), SuiteScriptError> {
+    let (path, entries): (&Path, Vec<LabelledEntry>) = match &opt.from_csv {
+        Some(path) => (path, load_csv(path)?),
+        None => {
+            let path = opt
+                .manifest
+                .as_deref()
+                .expect("Manifest is required unless --from-csv is set");
+            let entries = load_manifest(path)?
+                .into_iter()
+                .enumerate()
+                .map(|(number, entry)| (format!("script {}", number + 1), Ok(entry)))
+                .collect();
+            (path, entries)
+        }
+    };
+
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let entries = entries
+        .into_iter()
+        .map(|(label, entry)| (label, entry.map(|entry| resolve_paths(dir, entry))))
+        .collect();
+    Ok((path, entries))
+}
+
+/// Resolves the relative paths of the options of a script from a directory, leaving HTTPS URLs as
+/// they are.
+fn resolve_paths(dir: &Path, entry: ManifestEntry) -> ManifestEntry {
+    entry
+        .into_iter()
+        .map(|(key, value)| match value {
+            Value::String(path)
+                if PATH_OPTIONS.contains(&key.as_str())
+                    && !path.is_empty()
+                    && Path::new(&path).is_relative()
+                    && !remote::is_url(Path::new(&path)) =>
+            {
+                let path = dir.join(path).to_string_lossy().into_owned();
+                (key, Value::String(path))
+            }
+            value => (key, value),
+        })
+        .collect()
+}
+
+/// Reads and parses a manifest.
+///
+/// # Errors
+/// Returns an IO error if the manifest cannot be read, and a validation error if it is invalid
+pub fn load_manifest(path: &Path) -> Result<Vec<ManifestEntry>, SuiteScriptError> {
+    let contents = read_to_string(path)?;
+    let entries = if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        parse_json_manifest(&contents)
+    } else {
+        parse_yaml_manifest(&contents)
+    };
+    Ok(entries.map_err(|message| format!("Invalid manifest {}: {}", path.display(), message))?)
+}
+
+/// Reads a CSV file of scripts, labelling each row with its number in a spreadsheet.
+///
+/// # Errors
+/// Returns an IO error if the file cannot be read, and a validation error if it has no header row
+/// or a quote is not closed
+pub fn load_csv(path: &Path) -> Result<Vec<LabelledEntry>, SuiteScriptError> {
+    let contents = read_to_string(path)?;
+    let invalid = |message| format!("Invalid CSV file {}: {}", path.display(), message);
+    let mut rows = parse_csv(&contents).map_err(invalid)?.into_iter();
+    let (_, header) = rows
+        .next()
+        .ok_or_else(|| invalid(String::from("missing header row")))?;
+    let columns: Vec<String> = header
+        .iter()
+        .map(|name| {
+            let name = name.trim().to_lowercase();
+            COLUMN_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == name)
+                .map_or(name, |(_, option)| (*option).to_owned())
+        })
+        .collect();
+
+    Ok(rows
+        .map(|(number, cells)| {
+            let label = format!("row {}", number);
+            if cells.len() > columns.len() {
+                let message = format!(
+                    "{} cells, but the header names {} options",
+                    cells.len(),
+                    columns.len()
+                );
+                return (label, Err(message));
+            }
+            (label, Ok(get_row_entry(&columns, &cells)))
+        })
+        .collect())
+}
+
+/// Converts a row of a CSV file to the options of a script, skipping empty cells.
+///
+/// Cells of list options, such as `modules`, are split on commas and whitespace, and `true` or
+/// `false` sets a flag.
+fn get_row_entry(columns: &[String], cells: &[String]) -> ManifestEntry {
+    columns
+        .iter()
+        .zip(cells)
+        .filter(|(_, cell)| !cell.trim().is_empty())
+        .map(|(column, cell)| {
+            let cell = cell.trim();
+            let value = match cell {
+                _ if LIST_COLUMNS.contains(&column.as_str()) => Value::List(
+                    cell.split(|c: char| c == ',' || c.is_whitespace())
+                        .map(|item| item.trim_start_matches("N/"))
+                        .filter(|item| !item.is_empty())
+                        .map(str::to_owned)
+                        .collect(),
+                ),
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                _ => Value::String(cell.to_owned()),
+            };
+            (column.clone(), value)
+        })
+        .collect()
+}
+
+/// Parses the rows of a CSV file, with the line number each row starts on.
+///
+/// Cells are separated by commas, and can be quoted with double quotes to contain commas, line
+/// breaks, or quotes written twice. Blank lines are skipped.
+fn parse_csv(contents: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut rows = Vec::new();
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if cell.is_empty() => quoted = true,
+            '\n' if quoted => {
+                cell.push(c);
+                line += 1;
+            }
+            ',' if !quoted => cells.push(std::mem::take(&mut cell)),
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                cells.push(std::mem::take(&mut cell));
+                let blank = cells.len() == 1 && cells[0].is_empty();
+                let row = std::mem::take(&mut cells);
+                if !blank {
+                    rows.push((start, row));
+                }
+                line += 1;
+                start = line;
+            }
+            c => cell.push(c),
+        }
+    }
+    if quoted {
+        return Err(format!("unclosed quote on line {}", start));
+    }
+    if !cell.is_empty() || !cells.is_empty() {
+        cells.push(cell);
+        rows.push((start, cells));
+    }
+    Ok(rows)
+}
+
+/// Maps items on a number of threads, returning the results in the order of the items.
+///
+/// Each thread takes the next item as soon as it is done with the previous one, so that a slow
+/// file, such as on a network filesystem, does not hold up the others.
+pub fn map_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    map: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, items.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        match items.get(index) {
+                            Some(item) => results.push((index, map(item))),
+                            None => return results,
+                        }
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("Worker thread panicked"))
+            .collect()
+    });
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Validates the number of scripts to generate at the same time.
+fn validate_jobs(jobs: String) -> Result<(), String> {
+    match jobs.parse::<usize>() {
+        Ok(jobs) if jobs > 0 => Ok(()),
+        _ => Err(format!("Invalid number of jobs {}", jobs)),
+    }
+}
+
+/// Converts the options of a manifest entry to the arguments of the `new` command.
+///
+/// Each option is named after its long flag. Strings are passed as the value of the flag, lists
+/// repeat the flag for each item, and `true` passes the flag alone.
+pub fn get_args(entry: &[(String, Value)]) -> Vec<String> {
+    let mut args = vec![String::from("suitescript")];
+    for (key, value) in entry {
+        match value {
+            Value::String(value) => args.push(format!("--{}={}", key, value)),
+            Value::Bool(true) => args.push(format!("--{}", key)),
+            Value::Bool(false) => {}
+            Value::List(values) => {
+                args.extend(values.iter().map(|value| format!("--{}={}", key, value)))
+            }
+        }
+    }
+    args
+}
+
+/// Manifest listing the scripts to generate, each as a map of options, as read by `batch` and
+/// written by `import`.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Manifest {
+    /// Options of each script, named after their long flags, in the order they are written
+    pub scripts: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Parses a YAML manifest, a `scripts` list of maps of options.
+fn parse_yaml_manifest(contents: &str) -> Result<Vec<ManifestEntry>, String> {
+    let manifest: Manifest = serde_yaml::from_str(contents).map_err(|error| error.to_string())?;
+    get_manifest_entries(manifest)
+}
+
+/// Parses a JSON manifest, an object with a `scripts` array of objects.
+fn parse_json_manifest(contents: &str) -> Result<Vec<ManifestEntry>, String> {
+    let manifest: Manifest = serde_json::from_str(contents).map_err(|error| error.to_string())?;
+    get_manifest_entries(manifest)
+}
+
+/// Converts the scripts of a manifest to the options of each script, in the order they are
+/// written.
+///
+/// Options are strings, numbers, booleans, or lists of strings and numbers. Numbers are read as
+/// they are written, such as `2.1` for an API version, and an option without a value, such as
+/// `modules:` in YAML, is an empty list.
+fn get_manifest_entries(manifest: Manifest) -> Result<Vec<ManifestEntry>, String> {
+    let mut entries = Vec::new();
+    for (number, script) in manifest.scripts.into_iter().enumerate() {
+        let mut entry = Vec::new();
+        for (key, value) in script {
+            let value = match value {
+                serde_json::Value::Bool(enabled) => Some(Value::Bool(enabled)),
+                serde_json::Value::Null => Some(Value::List(Vec::new())),
+                serde_json::Value::Array(items) => items
+                    .into_iter()
+                    .map(get_scalar)
+                    .collect::<Option<Vec<String>>>()
+                    .map(Value::List),
+                value => get_scalar(value).map(Value::String),
+            };
+            let value = value.ok_or_else(|| {
+                format!(
+                    "invalid value for {} of script {}, expected a string, true or false, or a list of strings",
+                    key,
+                    number + 1
+                )
+            })?;
+            entry.push((key, value));
+        }
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Gets a string or a number of a manifest as a string, `None` for other values.
+fn get_scalar(value: serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(value) => Some(value),
+        serde_json::Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_yaml_manifest() {
+        let contents = "# Integration\nscripts:\n  - filename: 'src/customer''s_ue.js'\n    scripttype: userevent\n    modules:\n      - 'record'\n      - search\n    provenance: true\n  - filename: src/lib.js\n    modules: [format, \"url\"]\n";
+        assert_eq!(
+            parse_yaml_manifest(contents),
+            Ok(vec![
+                vec![
+                    (
+                        String::from("filename"),
+                        Value::String(String::from("src/customer's_ue.js"))
+                    ),
+                    (
+                        String::from("scripttype"),
+                        Value::String(String::from("userevent"))
+                    ),
+                    (
+                        String::from("modules"),
+                        Value::List(vec![String::from("record"), String::from("search")])
+                    ),
+                    (String::from("provenance"), Value::Bool(true)),
+                ],
+                vec![
+                    (
+                        String::from("filename"),
+                        Value::String(String::from("src/lib.js"))
+                    ),
+                    (
+                        String::from("modules"),
+                        Value::List(vec![String::from("format"), String::from("url")])
+                    ),
+                ],
+            ])
+        );
+        assert_eq!(
+            parse_yaml_manifest(
+                "scripts:\n  - filename: a_ue.js # user event\n    apiversion: 2.0\n    modules:\n"
+            ),
+            Ok(vec![vec![
+                (
+                    String::from("filename"),
+                    Value::String(String::from("a_ue.js"))
+                ),
+                (
+                    String::from("apiversion"),
+                    Value::String(String::from("2.0"))
+                ),
+                (String::from("modules"), Value::List(Vec::new())),
+            ]])
+        );
+        assert!(parse_yaml_manifest("scripts:\n  filename: a.js\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_json_manifest() {
+        let contents = "{\n  \"scripts\": [\n    { \"filename\": \"a_ue.js\", \"modules\": [\"record\"], \"backup\": false },\n    {}\n  ]\n}\n";
+        assert_eq!(
+            parse_json_manifest(contents),
+            Ok(vec![
+                vec![
+                    (
+                        String::from("filename"),
+                        Value::String(String::from("a_ue.js"))
+                    ),
+                    (
+                        String::from("modules"),
+                        Value::List(vec![String::from("record")])
+                    ),
+                    (String::from("backup"), Value::Bool(false)),
+                ],
+                Vec::new(),
+            ])
+        );
+        assert_eq!(
+            parse_json_manifest(
+                "{ \"version\": 1, \"scripts\": [{ \"description\": \"\\ud83d\\ude80\\r\\b\\f\" }] }"
+            ),
+            Ok(vec![vec![(
+                String::from("description"),
+                Value::String(String::from("\u{1f680}\r\u{8}\u{c}"))
+            )]])
+        );
+        assert_eq!(
+            parse_json_manifest("{ \"scripts\": [{ \"filename\": {} }] }"),
+            Err(String::from(
+                "invalid value for filename of script 1, expected a string, true or false, or a list of strings"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_resolve_paths() {
+        let template = std::env::temp_dir().join("a.tpl").display().to_string();
+        let entry = vec![
+            (
+                String::from("filename"),
+                Value::String(String::from("src/a_ue.js")),
+            ),
+            (String::from("template"), Value::String(template.clone())),
+            (
+                String::from("scripttype"),
+                Value::String(String::from("userevent")),
+            ),
+        ];
+        assert_eq!(
+            resolve_paths(Path::new("specs"), entry),
+            vec![
+                (
+                    String::from("filename"),
+                    Value::String(String::from("specs/src/a_ue.js"))
+                ),
+                (String::from("template"), Value::String(template)),
+                (
+                    String::from("scripttype"),
+                    Value::String(String::from("userevent"))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let contents = "filename,modules\r\na_ue.js,\"record, search\"\n\n\"b \"\"quoted\"\".js\",\"line\nbreak\"\nc.js";
+        assert_eq!(
+            parse_csv(contents),
+            Ok(vec![
+                (1, vec![String::from("filename"), String::from("modules")]),
+                (
+                    2,
+                    vec![String::from("a_ue.js"), String::from("record, search")]
+                ),
+                (
+                    4,
+                    vec![String::from("b \"quoted\".js"), String::from("line\nbreak")]
+                ),
+                (6, vec![String::from("c.js")]),
+            ])
+        );
+        assert_eq!(
+            parse_csv("filename\n\"a.js\n"),
+            Err(String::from("unclosed quote on line 2"))
+        );
+    }
+
+    #[test]
+    fn test_get_row_entry() {
+        let columns = vec![
+            String::from("filename"),
+            String::from("modules"),
+            String::from("with-harness"),
+            String::from("scripttype"),
+        ];
+        let cells = vec![
+            String::from("a_rl.js"),
+            String::from("N/record search"),
+            String::from("true"),
+            String::new(),
+        ];
+        assert_eq!(
+            get_row_entry(&columns, &cells),
+            vec![
+                (
+                    String::from("filename"),
+                    Value::String(String::from("a_rl.js"))
+                ),
+                (
+                    String::from("modules"),
+                    Value::List(vec![String::from("record"), String::from("search")])
+                ),
+                (String::from("with-harness"), Value::Bool(true)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = std::env::temp_dir().join(format!("suitescript-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("customer_ue.js");
+        std::fs::write(&script, "define([], () => {});\n").unwrap();
+        let mut cache = Cache::open(&dir);
+        let uncached = cache.is_fresh(&script, "1");
+        cache.record(&script, String::from("1"), std::slice::from_ref(&script));
+        cache.save().unwrap();
+
+        let cache = Cache::open(&dir);
+        let fresh = cache.is_fresh(&script, "1");
+        let changed = cache.is_fresh(&script, "2");
+        std::fs::write(&script, "// edited\n").unwrap();
+        let edited = cache.is_fresh(&script, "1");
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(!uncached);
+        assert!(fresh);
+        assert!(!changed);
+        assert!(!edited);
+        assert_eq!(cache.scripts[0].path, "customer_ue.js");
+    }
+
+    #[test]
+    fn test_hash_entry() {
+        let spec = ScriptSpec::from_iter(&["suitescript", "-f", "customer_ue.js", "-m", "record"]);
+        let other = ScriptSpec::from_iter(&["suitescript", "-f", "customer_ue.js", "-m", "search"]);
+        assert_eq!(hash_entry(&spec).unwrap(), hash_entry(&spec).unwrap());
+        assert_ne!(hash_entry(&spec).unwrap(), hash_entry(&other).unwrap());
+    }
+
+    #[test]
+    fn test_map_parallel() {
+        let items: Vec<usize> = (0..100).collect();
+        let doubled = map_parallel(&items, 8, |item| {
+            std::thread::sleep(std::time::Duration::from_micros((100 - *item as u64) * 10));
+            item * 2
+        });
+        assert_eq!(
+            doubled,
+            (0..100).map(|item| item * 2).collect::<Vec<usize>>()
+        );
+        assert!(map_parallel(&Vec::<usize>::new(), 4, |item| *item).is_empty());
+    }
+
+    #[test]
+    fn test_get_args() {
+        let entry = vec![
+            (
+                String::from("filename"),
+                Value::String(String::from("a_ue.js")),
+            ),
+            (
+                String::from("modules"),
+                Value::List(vec![String::from("record"), String::from("search")]),
+            ),
+            (String::from("provenance"), Value::Bool(true)),
+            (String::from("backup"), Value::Bool(false)),
+        ];
+        assert_eq!(
+            get_args(&entry),
+            vec![
+                "suitescript",
+                "--filename=a_ue.js",
+                "--modules=record",
+                "--modules=search",
+                "--provenance"
+            ]
+        );
+    }
+}
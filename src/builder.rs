@@ -0,0 +1,373 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use super::ScriptSpec;
+
+/// Type of `SuiteScript` to generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptType {
+    MapReduce,
+    UserEvent,
+    Scheduled,
+    Client,
+    Suitelet,
+    Portlet,
+    Restlet,
+    BundleInstallation,
+    MassUpdate,
+    WorkflowAction,
+    SdfInstallation,
+    CustomGl,
+    Library,
+}
+
+impl ScriptType {
+    /// Gets the name of the script type, as accepted by `--scripttype`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ScriptType::MapReduce => "mapreduce",
+            ScriptType::UserEvent => "userevent",
+            ScriptType::Scheduled => "scheduled",
+            ScriptType::Client => "client",
+            ScriptType::Suitelet => "suitelet",
+            ScriptType::Portlet => "portlet",
+            ScriptType::Restlet => "restlet",
+            ScriptType::BundleInstallation => "bundleinstallation",
+            ScriptType::MassUpdate => "massupdate",
+            ScriptType::WorkflowAction => "workflowaction",
+            ScriptType::SdfInstallation => "sdfinstallation",
+            ScriptType::CustomGl => "customgl",
+            ScriptType::Library => "library",
+        }
+    }
+}
+
+impl fmt::Display for ScriptType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// `SuiteScript` module to import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Module {
+    Action,
+    Auth,
+    Cache,
+    CertificateControl,
+    Commerce,
+    Compress,
+    Config,
+    Crypto,
+    CryptoCertificate,
+    Currency,
+    CurrentRecord,
+    Dataset,
+    Email,
+    Encode,
+    Error,
+    File,
+    Format,
+    FormatI18n,
+    Http,
+    Https,
+    HttpsClientCertificate,
+    KeyControl,
+    Log,
+    PiRemoval,
+    Plugin,
+    Portlet,
+    Query,
+    Record,
+    RecordContext,
+    Redirect,
+    Render,
+    Runtime,
+    Search,
+    Sftp,
+    Sso,
+    SuiteAppInfo,
+    Task,
+    TaskAccountingRecognition,
+    Transaction,
+    Translation,
+    UiDialog,
+    UiMessage,
+    UiServerWidget,
+    Url,
+    Util,
+    Workbook,
+    Workflow,
+    Xml,
+}
+
+impl Module {
+    /// Gets the name of the module without the `N/` prefix, as accepted by `--modules`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Module::Action => "action",
+            Module::Auth => "auth",
+            Module::Cache => "cache",
+            Module::CertificateControl => "certificatecontrol",
+            Module::Commerce => "commerce",
+            Module::Compress => "compress",
+            Module::Config => "config",
+            Module::Crypto => "crypto",
+            Module::CryptoCertificate => "crypto/certificate",
+            Module::Currency => "currency",
+            Module::CurrentRecord => "currentrecord",
+            Module::Dataset => "dataset",
+            Module::Email => "email",
+            Module::Encode => "encode",
+            Module::Error => "error",
+            Module::File => "file",
+            Module::Format => "format",
+            Module::FormatI18n => "format/i18n",
+            Module::Http => "http",
+            Module::Https => "https",
+            Module::HttpsClientCertificate => "https/clientcertificate",
+            Module::KeyControl => "keycontrol",
+            Module::Log => "log",
+            Module::PiRemoval => "piremoval",
+            Module::Plugin => "plugin",
+            Module::Portlet => "portlet",
+            Module::Query => "query",
+            Module::Record => "record",
+            Module::RecordContext => "recordcontext",
+            Module::Redirect => "redirect",
+            Module::Render => "render",
+            Module::Runtime => "runtime",
+            Module::Search => "search",
+            Module::Sftp => "sftp",
+            Module::Sso => "sso",
+            Module::SuiteAppInfo => "suiteappinfo",
+            Module::Task => "task",
+            Module::TaskAccountingRecognition => "task/accounting/recognition",
+            Module::Transaction => "transaction",
+            Module::Translation => "translation",
+            Module::UiDialog => "ui/dialog",
+            Module::UiMessage => "ui/message",
+            Module::UiServerWidget => "ui/serverwidget",
+            Module::Url => "url",
+            Module::Util => "util",
+            Module::Workbook => "workbook",
+            Module::Workflow => "workflow",
+            Module::Xml => "xml",
+        }
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Builds the options of a script to generate.
+///
+/// Script types and modules are typed, so that a misspelled name fails to compile instead of
+/// failing validation when the script is generated. Options not set on the builder keep the
+/// defaults of the command line.
+#[derive(Debug, Clone)]
+pub struct ScriptBuilder {
+    spec: ScriptSpec,
+}
+
+impl ScriptBuilder {
+    /// Starts building a script with the given file name.
+    pub fn new<P: Into<PathBuf>>(file_name: P) -> Self {
+        ScriptBuilder {
+            spec: ScriptSpec {
+                file_name: Some(file_name.into()),
+                ..ScriptSpec::default()
+            },
+        }
+    }
+
+    /// Sets the type of the script, inferred from the file name if not set.
+    pub fn script_type(mut self, script_type: ScriptType) -> Self {
+        self.spec.script_type = script_type.name().to_owned();
+        self
+    }
+
+    /// Sets the version of the `SuiteScript` API, `2.1` if not set.
+    pub fn api_version(mut self, api_version: &str) -> Self {
+        self.spec.api_version = api_version.to_owned();
+        self
+    }
+
+    /// Adds a module to import.
+    pub fn module(mut self, module: Module) -> Self {
+        self.spec.modules.push(module.name().to_owned());
+        self
+    }
+
+    /// Adds a recipe of boilerplate to the module body.
+    pub fn recipe(mut self, recipe: &str) -> Self {
+        self.spec.recipes.push(recipe.to_owned());
+        self
+    }
+
+    /// Sets the path of a file containing the copyright message.
+    pub fn copyright<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.spec.copyright = path.into();
+        self
+    }
+
+    /// Sets the copyright message to use when no copyright file is set.
+    pub fn copyright_text(mut self, text: &str) -> Self {
+        self.spec.copyright_text = Some(text.to_owned());
+        self
+    }
+
+    /// Sets the path of a template of the script.
+    pub fn template<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.spec.template = Some(path.into());
+        self
+    }
+
+    /// Sets the directory of templates named after script types, used when no template is set.
+    pub fn templates_dir<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.spec.templates_dir = Some(path.into());
+        self
+    }
+
+    /// Sets the company name substituted for `{{company}}` in the copyright message.
+    pub fn company(mut self, company: &str) -> Self {
+        self.spec.company = company.to_owned();
+        self
+    }
+
+    /// Sets the author written in the `@author` tag.
+    pub fn author(mut self, author: &str) -> Self {
+        self.spec.author = Some(Some(author.to_owned()));
+        self
+    }
+
+    /// Sets the description written in the `@description` tag.
+    pub fn description(mut self, description: &str) -> Self {
+        self.spec.description = Some(description.to_owned());
+        self
+    }
+
+    /// Sets the scope of the module written in the `@NModuleScope` tag.
+    pub fn scope(mut self, scope: &str) -> Self {
+        self.spec.scope = scope.to_owned();
+        self
+    }
+
+    /// Sets the type of content rendered by a portlet.
+    pub fn portlet_type(mut self, portlet_type: &str) -> Self {
+        self.spec.portlet_type = portlet_type.to_owned();
+        self
+    }
+
+    /// Sets the path of the AMD config written in the `@NAmdConfig` tag.
+    pub fn amd_config<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.spec.amd_config = Some(path.into());
+        self
+    }
+
+    /// Adds a user event entry point to generate, all of them if none are added.
+    pub fn event(mut self, name: &str) -> Self {
+        self.spec.events.push(name.to_owned());
+        self
+    }
+
+    /// Adds a client entry point to generate, all of them if none are added.
+    pub fn entry(mut self, name: &str) -> Self {
+        self.spec.entries.push(name.to_owned());
+        self
+    }
+
+    /// Adds a RESTlet method to handle, all of them if none are added.
+    pub fn method(mut self, name: &str) -> Self {
+        self.spec.methods.push(name.to_owned());
+        self
+    }
+
+    /// Skips the copyright and `JSDoc` tags.
+    pub fn no_header(mut self) -> Self {
+        self.spec.no_header = true;
+        self
+    }
+
+    /// Enables `TypeScript` checking with `// @ts-check` and typed module imports.
+    pub fn ts_check(mut self) -> Self {
+        self.spec.ts_check = true;
+        self
+    }
+
+    /// Generates the script as `TypeScript`, which is also inferred from a `.ts` file name.
+    pub fn typescript(mut self) -> Self {
+        self.spec.lang = String::from("ts");
+        self
+    }
+
+    /// Generates a client script for SuiteCommerce websites.
+    pub fn suitecommerce(mut self) -> Self {
+        self.spec.suitecommerce = true;
+        self
+    }
+
+    /// Embeds a comment identifying the generator version, template, and options of the script.
+    pub fn provenance(mut self) -> Self {
+        self.spec.provenance = true;
+        self
+    }
+
+    /// Fixes the order of generated content so the same options always produce identical output.
+    pub fn reproducible(mut self) -> Self {
+        self.spec.reproducible = true;
+        self
+    }
+
+    /// Finishes building the options of the script.
+    pub fn build(self) -> ScriptSpec {
+        self.spec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{generate, validate_modules, validate_script_type};
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let spec = ScriptBuilder::new("file.js")
+            .script_type(ScriptType::MapReduce)
+            .module(Module::Record)
+            .module(Module::UiServerWidget)
+            .build();
+        assert_eq!(spec.file_name, Some(PathBuf::from("file.js")));
+        assert_eq!(spec.script_type, "mapreduce");
+        assert_eq!(spec.modules, vec!["record", "ui/serverwidget"]);
+    }
+
+    #[test]
+    fn test_build_entry_points() {
+        let spec = ScriptBuilder::new("customer_rl.js")
+            .script_type(ScriptType::Restlet)
+            .method("get")
+            .build();
+        assert_eq!(spec.methods, vec!["get"]);
+        let script = generate(&spec).unwrap();
+        assert!(script.contains("define([\n], () => {"));
+        assert!(script.contains("get: doGet"));
+    }
+
+    #[test]
+    fn test_names_are_valid() {
+        for script_type in &[ScriptType::BundleInstallation, ScriptType::CustomGl] {
+            assert_eq!(validate_script_type(script_type.to_string()), Ok(()));
+        }
+        for module in &[
+            Module::CryptoCertificate,
+            Module::TaskAccountingRecognition,
+            Module::Xml,
+        ] {
+            assert_eq!(validate_modules(module.to_string()), Ok(()));
+        }
+    }
+}
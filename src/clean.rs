@@ -0,0 +1,276 @@
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::lock::{hash_contents, Lock, LockedFile, GENERATED_DIR, LOCK_FILE};
+use super::SuiteScriptError;
+
+/// Options for removing the files recorded in the lock manifest of a project.
+#[derive(StructOpt, Debug, Default)]
+pub struct CleanOpt {
+    /// Prints the files that would be removed, without removing them
+    #[structopt(long = "dry-run")]
+    dry_run: bool,
+
+    /// Removes the files without asking for confirmation, as required with --plain
+    #[structopt(short = "y", long = "yes")]
+    yes: bool,
+
+    /// Also removes the files edited since they were generated, which are kept otherwise
+    #[structopt(long = "force")]
+    force: bool,
+}
+
+/// A file recorded in a lock manifest, as found when cleaning.
+struct Recorded {
+    /// The record of the file
+    file: LockedFile,
+    /// Path of the file, relative to the directory the generator is run in if it is in it
+    path: PathBuf,
+    /// Path of the file, joined to the directory of the manifest
+    absolute: PathBuf,
+    /// Whether the file was edited since it was generated
+    edited: bool,
+    /// Whether the file was already removed
+    missing: bool,
+}
+
+/// Removes the files recorded in the lock manifest, `suitescript.lock`, of the project the
+/// current directory is in, after asking for confirmation.
+///
+/// Files edited since they were generated are kept, unless `--force` is given. Directories left
+/// empty are removed, and the manifest itself once every file it records is removed.
+///
+/// # Errors
+/// Returns a validation error if the project has no manifest, or if the confirmation cannot be
+/// asked with `plain` and `--yes` is not given, and an IO error if a file cannot be removed
+pub fn run(opt: &CleanOpt, plain: bool) -> Result<(), SuiteScriptError> {
+    let current_dir = std::env::current_dir()
+        .map_err(|source| SuiteScriptError::io("Failed to read the current directory", source))?;
+    let mut lock = Lock::open(&current_dir, false)?.ok_or_else(|| {
+        SuiteScriptError::Validation(format!(
+            "No {} found. Files are recorded in it when they are generated with --lock",
+            LOCK_FILE
+        ))
+    })?;
+    if !opt.yes && !opt.dry_run && plain {
+        return Err(SuiteScriptError::Validation(String::from(
+            "--plain disables the confirmation prompt. Pass --yes to remove the files",
+        )));
+    }
+    let stdin = io::stdin();
+    clean(opt, &mut lock, &mut stdin.lock(), &mut io::stdout())
+}
+
+/// Removes the files recorded in a lock manifest, asking for confirmation on `input` and
+/// `output` unless `--yes` is given.
+fn clean(
+    opt: &CleanOpt,
+    lock: &mut Lock,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<(), SuiteScriptError> {
+    let write_error = |source| SuiteScriptError::io("Failed to write to stdout", source);
+    let recorded: Vec<Recorded> = lock
+        .files
+        .iter()
+        .map(|file| {
+            let absolute = lock.dir.join(&file.path);
+            let contents = std::fs::read(&absolute).ok();
+            Recorded {
+                file: file.clone(),
+                path: lock.resolve(file),
+                edited: contents
+                    .as_ref()
+                    .is_some_and(|contents| hash_contents(contents) != file.hash),
+                missing: contents.is_none(),
+                absolute,
+            }
+        })
+        .collect();
+    let (kept, removed): (Vec<&Recorded>, Vec<&Recorded>) = recorded
+        .iter()
+        .filter(|recorded| !recorded.missing)
+        .partition(|recorded| recorded.edited && !opt.force);
+
+    for recorded in &kept {
+        writeln!(
+            output,
+            "Kept {}, edited since it was generated. Pass --force to remove it",
+            recorded.path.display()
+        )
+        .map_err(write_error)?;
+    }
+    if opt.dry_run {
+        for recorded in &removed {
+            writeln!(output, "Would remove {}", recorded.path.display()).map_err(write_error)?;
+        }
+        return Ok(());
+    }
+    if removed.is_empty() && recorded.iter().all(|recorded| !recorded.missing) {
+        writeln!(output, "No files to remove").map_err(write_error)?;
+        return Ok(());
+    }
+    if !removed.is_empty() && !opt.yes && !confirm(input, output, removed.len())? {
+        writeln!(output, "No files removed").map_err(write_error)?;
+        return Ok(());
+    }
+
+    for recorded in removed {
+        std::fs::remove_file(&recorded.absolute).map_err(|source| {
+            SuiteScriptError::io(
+                format!("Failed to remove {}", recorded.path.display()),
+                source,
+            )
+        })?;
+        remove_empty_dirs(&recorded.absolute, &lock.dir);
+        lock.forget(&recorded.file)?;
+        writeln!(output, "Removed {}", recorded.path.display()).map_err(write_error)?;
+    }
+    for recorded in recorded.iter().filter(|recorded| recorded.missing) {
+        lock.forget(&recorded.file)?;
+    }
+
+    if lock.files.is_empty() {
+        let path = lock.path();
+        std::fs::remove_file(&path)
+            .or_else(ignore_missing)
+            .map_err(|source| {
+                SuiteScriptError::io(format!("Failed to remove {}", path.display()), source)
+            })?;
+        let generated = lock.dir.join(GENERATED_DIR);
+        if std::fs::remove_dir(&generated).is_ok() {
+            remove_empty_dirs(&generated, &lock.dir);
+        }
+        Ok(())
+    } else {
+        lock.save()
+    }
+}
+
+/// Asks whether to remove the files, which is declined unless the answer starts with `y`.
+fn confirm(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    count: usize,
+) -> Result<bool, SuiteScriptError> {
+    let read_error = |source| SuiteScriptError::io("Failed to read the confirmation", source);
+    let question = match count {
+        1 => String::from("Remove 1 file? [y/N] "),
+        count => format!("Remove {} files? [y/N] ", count),
+    };
+    write!(output, "{}", question)
+        .and_then(|()| output.flush())
+        .map_err(read_error)?;
+    let mut answer = String::new();
+    input.read_line(&mut answer).map_err(read_error)?;
+    Ok(answer.trim().to_lowercase().starts_with('y'))
+}
+
+/// Removes the parent directories of a removed file that are left empty, up to `root`.
+fn remove_empty_dirs(path: &Path, root: &Path) {
+    for dir in path.ancestors().skip(1) {
+        if dir == root || !dir.starts_with(root) || std::fs::remove_dir(dir).is_err() {
+            break;
+        }
+    }
+}
+
+/// Treats a file that does not exist as removed.
+fn ignore_missing(error: io::Error) -> io::Result<()> {
+    match error.kind() {
+        io::ErrorKind::NotFound => Ok(()),
+        _ => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes a project with a lock manifest recording an unedited, an edited, and a removed file.
+    fn write_project(name: &str) -> (PathBuf, Lock) {
+        let dir = std::env::temp_dir().join(format!("suitescript-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(dir.join("src/__tests__")).unwrap();
+        let mut lock = Lock::open(&dir, true).unwrap().unwrap();
+        let mut files = Vec::new();
+        for path in [
+            "src/customer_ue.js",
+            "src/__tests__/customer_ue.test.js",
+            "src/order_cs.js",
+        ] {
+            let path = dir.join(path);
+            std::fs::write(&path, "define([], () => {});\n").unwrap();
+            files.push(
+                lock.write_generated(&path, "define([], () => {});\n")
+                    .unwrap(),
+            );
+        }
+        lock.record(files);
+        lock.save().unwrap();
+        std::fs::write(dir.join("src/customer_ue.js"), "// edited\n").unwrap();
+        std::fs::remove_file(dir.join("src/order_cs.js")).unwrap();
+        (dir, lock)
+    }
+
+    #[test]
+    fn test_clean() {
+        let (dir, mut lock) = write_project("clean");
+        let opt = CleanOpt::default();
+        let mut output = Vec::new();
+        clean(&opt, &mut lock, &mut io::Cursor::new("n\n"), &mut output).unwrap();
+        let declined = dir.join("src/__tests__/customer_ue.test.js").is_file();
+        clean(&opt, &mut lock, &mut io::Cursor::new("y\n"), &mut output).unwrap();
+        let tests_dir = dir.join("src/__tests__").exists();
+        let edited = dir.join("src/customer_ue.js").is_file();
+        let recorded = Lock::open(&dir, false).unwrap().unwrap().files;
+        let force = CleanOpt {
+            force: true,
+            yes: true,
+            ..CleanOpt::default()
+        };
+        clean(&force, &mut lock, &mut io::Cursor::new(""), &mut output).unwrap();
+        let manifest = dir.join(LOCK_FILE).exists();
+        let state = dir.join(".suitescript").exists();
+        let src = dir.join("src").exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(declined);
+        assert!(!tests_dir);
+        assert!(edited);
+        assert_eq!(
+            recorded
+                .iter()
+                .map(|file| &file.path[..])
+                .collect::<Vec<_>>(),
+            vec!["src/customer_ue.js"]
+        );
+        assert!(!manifest);
+        assert!(!state);
+        assert!(!src);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Remove 1 file? [y/N] "));
+        assert!(output.contains("No files removed\n"));
+        assert!(output.contains("Kept "));
+        assert!(output.contains("Removed "));
+    }
+
+    #[test]
+    fn test_clean_dry_run() {
+        let (dir, mut lock) = write_project("clean-dry-run");
+        let opt = CleanOpt {
+            dry_run: true,
+            ..CleanOpt::default()
+        };
+        let mut output = Vec::new();
+        clean(&opt, &mut lock, &mut io::Cursor::new(""), &mut output).unwrap();
+        let removed = !dir.join("src/__tests__/customer_ue.test.js").is_file();
+        let recorded = Lock::open(&dir, false).unwrap().unwrap().files.len();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(!removed);
+        assert_eq!(recorded, 3);
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("Would remove ").count(), 1);
+        assert_eq!(output.matches("Kept ").count(), 1);
+    }
+}
@@ -0,0 +1,180 @@
+use structopt::clap::{App, Shell};
+use structopt::StructOpt;
+
+use super::assets::netsuite_types::{MODULES, RECORD_TYPES, TYPES};
+
+/// Options for generating shell completions.
+#[derive(StructOpt, Debug)]
+pub struct CompletionsOpt {
+    /// Shell to generate completions for
+    #[structopt(possible_values = &["bash", "zsh", "fish", "powershell"])]
+    shell: String,
+}
+
+/// Options of the `new` command whose values are completed from the asset lists, as the long flag,
+/// the short flag if there is one, and the values.
+const VALUES: [(&str, Option<&str>, &[&str]); 4] = [
+    ("modules", Some("m"), &MODULES),
+    ("scripttype", Some("s"), &TYPES),
+    ("record", None, &RECORD_TYPES),
+    ("deployments", None, &RECORD_TYPES),
+];
+
+/// Prints the completion script of a shell for the application.
+pub fn run(opt: &CompletionsOpt, app: App) {
+    print!("{}", generate(app, &opt.shell));
+}
+
+/// Generates the completion script of a shell, with the values of `--modules`, `--scripttype`,
+/// `--record`, and `--deployments` of the `new` command.
+///
+/// The values are added to the script generated by clap, since they are validated rather than
+/// listed as possible values. `PowerShell` scripts complete flags only.
+pub fn generate(mut app: App, shell: &str) -> String {
+    let target = match shell {
+        "bash" => Shell::Bash,
+        "zsh" => Shell::Zsh,
+        "fish" => Shell::Fish,
+        _ => Shell::PowerShell,
+    };
+    let mut script = Vec::new();
+    app.gen_completions_to("suitescript", target, &mut script);
+    let script = String::from_utf8(script).expect("Completions are not UTF-8");
+
+    match target {
+        Shell::Bash => add_bash_values(&script),
+        Shell::Zsh => add_zsh_values(&script),
+        Shell::Fish => add_fish_values(&script),
+        _ => script,
+    }
+}
+
+/// Completes the values of the options in the `suitescript__new` case of a bash script.
+fn add_bash_values(script: &str) -> String {
+    let mut section = "";
+    let mut values: Option<&[&str]> = None;
+    let mut lines = Vec::new();
+    for line in script.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("suitescript__") && trimmed.ends_with(')') {
+            section = trimmed;
+        }
+
+        match values.take() {
+            Some(completions) if trimmed.starts_with("COMPREPLY=($(compgen -f") => {
+                let indent = &line[..line.len() - line.trim_start().len()];
+                lines.push(format!(
+                    "{}COMPREPLY=($(compgen -W \"{}\" -- \"${{cur}}\"))",
+                    indent,
+                    completions.join(" ")
+                ));
+                continue;
+            }
+            _ => {}
+        }
+        if section == "suitescript__new)" {
+            values = VALUES
+                .iter()
+                .find(|(long, short, _)| {
+                    trimmed == format!("--{})", long)
+                        || short.is_some_and(|short| trimmed == format!("-{})", short))
+                })
+                .map(|(_, _, completions)| *completions);
+        }
+        lines.push(line.to_owned());
+    }
+    format!("{}\n", lines.join("\n"))
+}
+
+/// Completes the values of the options in the `new` case of a zsh script.
+fn add_zsh_values(script: &str) -> String {
+    let mut section = "";
+    let mut lines = Vec::new();
+    for line in script.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('(') && trimmed.ends_with(')') && !trimmed.contains(' ') {
+            section = trimmed;
+        }
+
+        let option = VALUES.iter().find(|(long, short, _)| {
+            let spec = trimmed.trim_start_matches(&['\'', '*'][..]);
+            spec.starts_with(&format!("--{}=[", long))
+                || short.is_some_and(|short| spec.starts_with(&format!("-{}+[", short)))
+        });
+        match option {
+            Some((_, _, completions)) if section == "(new)" && line.ends_with("]' \\") => {
+                let spec = &line[..line.len() - "' \\".len()];
+                lines.push(format!("{}: :({})' \\", spec, completions.join(" ")));
+            }
+            _ => lines.push(line.to_owned()),
+        }
+    }
+    format!("{}\n", lines.join("\n"))
+}
+
+/// Completes the values of the options of the `new` command in a fish script.
+fn add_fish_values(script: &str) -> String {
+    let mut lines = Vec::new();
+    for line in script.lines() {
+        let option = VALUES.iter().find(|(long, short, _)| {
+            let flags = match short {
+                Some(short) => format!("-s {} -l {}", short, long),
+                None => format!("-l {}", long),
+            };
+            line.contains(&format!("__fish_seen_subcommand_from new\" {} ", flags))
+        });
+        match option {
+            Some((_, _, completions)) => {
+                lines.push(format!("{} -r -f -a \"{}\"", line, completions.join(" ")))
+            }
+            None => lines.push(line.to_owned()),
+        }
+    }
+    format!("{}\n", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_bash_values() {
+        let script = "        suitescript__new)\n                --modules)\n                    COMPREPLY=($(compgen -f \"${cur}\"))\n        suitescript__audit)\n                --modules)\n                    COMPREPLY=($(compgen -f \"${cur}\"))\n";
+        let completed = add_bash_values(script);
+        assert!(completed.contains("COMPREPLY=($(compgen -W \"action auth cache"));
+        assert_eq!(completed.matches("compgen -f").count(), 1);
+    }
+
+    #[test]
+    fn test_add_zsh_values() {
+        let script = "(new)\n'-s+[Type of script]' \\\n(audit)\n'-s+[Other]' \\\n";
+        assert_eq!(
+            add_zsh_values(script),
+            format!(
+                "(new)\n'-s+[Type of script]: :({})' \\\n(audit)\n'-s+[Other]' \\\n",
+                TYPES.join(" ")
+            )
+        );
+    }
+
+    #[test]
+    fn test_add_fish_values_long_only() {
+        let script = "complete -c suitescript -n \"__fish_seen_subcommand_from new\" -l deployments -d 'Record types'\n";
+        assert!(add_fish_values(script).ends_with(&format!(
+            "-d 'Record types' -r -f -a \"{}\"\n",
+            RECORD_TYPES.join(" ")
+        )));
+    }
+
+    #[test]
+    fn test_add_fish_values() {
+        let script = "complete -c suitescript -n \"__fish_seen_subcommand_from new\" -s m -l modules -d 'Modules'\n";
+        assert_eq!(
+            add_fish_values(script),
+            format!(
+                "complete -c suitescript -n \"__fish_seen_subcommand_from new\" -s m -l modules -d 'Modules' -r -f -a \"{}\"\n",
+                MODULES.join(" ")
+            )
+        );
+    }
+}
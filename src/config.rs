@@ -0,0 +1,915 @@
+use regex::Regex;
+use serde::Deserialize;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::{
+    map_script_to_name, read_to_string, remote, validate_record, write_atomically, ScriptSpec,
+    SuiteScriptError,
+};
+
+/// Names of project config files, in the order they are looked up in each directory.
+pub const PROJECT_FILES: [&str; 2] = ["suitescript.toml", ".suitescriptrc"];
+
+/// Options that can be set in a config file.
+///
+/// Each option is named after its long flag, and is given with the name of its argument and the
+/// environment variable that sets it, if any, so that a flag or variable takes precedence over the
+/// config. Options that can only be set in a config, such as the naming convention of a project,
+/// are given with their own name.
+const KEYS: [(&str, &str, Option<&str>); 24] = [
+    ("apiversion", "api-version", None),
+    ("modules", "modules", None),
+    ("recipes", "recipes", None),
+    ("copyright", "copyright", None),
+    (
+        "copyright-text",
+        "copyright-text",
+        Some("SUITESCRIPT_COPYRIGHT"),
+    ),
+    ("company", "company", Some("SUITESCRIPT_COMPANY")),
+    ("author", "author", Some("SUITESCRIPT_AUTHOR")),
+    (
+        "description",
+        "description",
+        Some("SUITESCRIPT_DESCRIPTION"),
+    ),
+    ("scope", "scope", None),
+    ("template", "template", None),
+    (
+        "templates-dir",
+        "templates-dir",
+        Some("SUITESCRIPT_TEMPLATES_DIR"),
+    ),
+    ("no-header", "no-header", None),
+    ("ts-check", "ts-check", None),
+    ("provenance", "provenance", None),
+    ("reproducible", "reproducible", None),
+    ("backup", "backup", None),
+    ("lock", "lock", None),
+    ("create-dirs", "create-dirs", None),
+    ("filename-pattern", "filename-pattern", None),
+    ("filename-convention", "filename-convention", None),
+    ("record-types", "record-types", None),
+    ("indent", "indent", None),
+    ("quotes", "quotes", None),
+    ("align-tags", "align-tags", None),
+];
+
+/// Settings of an environment that files are uploaded to, set in a config as
+/// `env.<name>.<setting>`, such as `env.sandbox.account`.
+pub const ENV_KEYS: [&str; 3] = ["account", "restlet", "folder"];
+
+/// Options for managing the user config.
+#[derive(StructOpt, Debug)]
+pub struct ConfigOpt {
+    #[structopt(subcommand)]
+    command: ConfigCommand,
+}
+
+#[derive(StructOpt, Debug)]
+enum ConfigCommand {
+    /// Prints the value of an option, exiting with a non-zero status if it is not set
+    Get {
+        #[structopt(validator = validate_key)]
+        key: String,
+    },
+    /// Sets the value of an option, with commas separating modules, recipes, and record types
+    Set {
+        #[structopt(validator = validate_key)]
+        key: String,
+        value: String,
+    },
+    /// Prints every option set in the user config
+    List,
+}
+
+/// Runs a `config` subcommand on the user config.
+///
+/// Returns whether the option printed by `config get` is set, so that the CLI can exit with a
+/// non-zero status if it is not, and `true` for the other subcommands.
+///
+/// # Errors
+/// Returns an error if there is no user config directory, the user config is invalid, or it cannot
+/// be written
+pub fn run(opt: &ConfigOpt) -> Result<bool, SuiteScriptError> {
+    let path = user_config_path().ok_or_else(|| {
+        SuiteScriptError::io(
+            "Failed to find the user config directory",
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "XDG_CONFIG_HOME, HOME, and APPDATA are not set",
+            ),
+        )
+    })?;
+    let contents = std::fs::read_to_string(&path).unwrap_or_default();
+    let config = Config::parse(&path, &contents)?;
+
+    match &opt.command {
+        ConfigCommand::Get { key } => match config.get(key) {
+            Some(Value::String(value)) => println!("{}", value),
+            Some(Value::List(values)) => println!("{}", values.join(",")),
+            Some(value) => println!("{}", value),
+            None => return Ok(false),
+        },
+        ConfigCommand::Set { key, value } => {
+            let value = parse_arg_value(key, value)?;
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(|source| {
+                    SuiteScriptError::io(format!("Failed to create {}", dir.display()), source)
+                })?;
+            }
+            write_atomically(&path, &set_value(&contents, key, &value)?)?;
+        }
+        ConfigCommand::List => {
+            for (key, value) in &config.values {
+                println!("{} = {}", key, value);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Value of an option in a config file.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    String(String),
+    Bool(bool),
+    List(Vec<String>),
+}
+
+impl fmt::Display for Value {
+    /// Formats the value the way it is written in a config file.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_toml())
+    }
+}
+
+impl Value {
+    /// Converts the value to a TOML value, to write it in a config file.
+    fn to_toml(&self) -> toml_edit::Value {
+        match self {
+            Value::String(value) => value.as_str().into(),
+            Value::Bool(value) => (*value).into(),
+            Value::List(values) => values.iter().collect::<toml_edit::Array>().into(),
+        }
+    }
+}
+
+/// Default options read from a config file.
+///
+/// Config files are TOML, where the value of an option is a string, `true` or `false`, or a list of
+/// strings. The settings of environments can be written with dotted keys, such as
+/// `env.sandbox.account`, or in tables, such as `[env.sandbox]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Path of the config file, used to resolve relative paths and in error messages.
+    pub path: PathBuf,
+    /// Options set in the config, in the order they are written.
+    pub values: Vec<(String, Value)>,
+}
+
+impl Config {
+    /// Reads and parses a config file.
+    ///
+    /// # Errors
+    /// Returns an IO error if the config file cannot be read, and a validation error if it is not
+    /// valid TOML or sets an unknown option
+    pub fn load(path: &Path) -> Result<Config, SuiteScriptError> {
+        let contents = read_to_string(path)?;
+        Ok(Config::parse(path, &contents)?)
+    }
+
+    /// Parses the contents of a config file.
+    ///
+    /// Tables are flattened into dotted keys, so that `account` in `[env.sandbox]` is the
+    /// `env.sandbox.account` option.
+    ///
+    /// # Errors
+    /// Returns a description of the TOML syntax error with its line, of an unknown option, or of an
+    /// option whose value is not a string, a boolean, or a list of strings
+    pub fn parse(path: &Path, contents: &str) -> Result<Config, String> {
+        let table: toml::Table = toml::from_str(contents).map_err(|error| {
+            let line = error
+                .span()
+                .map_or(1, |span| contents[..span.start].matches('\n').count() + 1);
+            format!(
+                "Invalid line {} in {}: {}",
+                line,
+                path.display(),
+                error.message().trim()
+            )
+        })?;
+
+        let mut entries = Vec::new();
+        flatten("", table, &mut entries);
+        let mut values = Vec::new();
+        for (key, value) in entries {
+            if !is_key(&key) {
+                return Err(format!("Unknown option {} in {}", key, path.display()));
+            }
+            let value = value.try_into().map_err(|_| {
+                format!(
+                    "Invalid value for {} in {}, expected a string, true or false, or a list of strings",
+                    key,
+                    path.display()
+                )
+            })?;
+            values.push((key, value));
+        }
+
+        Ok(Config {
+            path: path.to_owned(),
+            values,
+        })
+    }
+
+    /// Gets the value of an option, the last one if it is set more than once.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Gets a setting of an environment, such as the `account` of `sandbox`, the last one if it is
+    /// set more than once.
+    pub fn get_env(&self, name: &str, setting: &str) -> Option<&str> {
+        match self.get(&format!("env.{}.{}", name, setting)) {
+            Some(Value::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Applies the options of the config to a script.
+    ///
+    /// Options set by a flag or an environment variable, as reported by `explicit` for the name of
+    /// their argument, are skipped. Relative paths are resolved from the directory of the config.
+    /// Record types are added to those of the configs applied before, rather than replacing them.
+    ///
+    /// # Errors
+    /// Returns a description of the first option whose value has the wrong type
+    pub fn apply(
+        &self,
+        spec: &mut ScriptSpec,
+        explicit: &dyn Fn(&str) -> bool,
+    ) -> Result<(), String> {
+        for (key, value) in &self.values {
+            if parse_env_key(key).is_some() {
+                continue;
+            }
+            if let Some(dir) = parse_copyright_key(key) {
+                match value {
+                    Value::String(path) if !path.is_empty() => {
+                        if explicit("copyright")
+                            || explicit("copyright-text")
+                            || std::env::var_os("SUITESCRIPT_COPYRIGHT").is_some()
+                        {
+                            continue;
+                        }
+                        let dir = self.resolve(dir);
+                        spec.copyrights.retain(|(existing, _)| *existing != dir);
+                        spec.copyrights.push((dir, self.resolve(path)));
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Invalid value for {} in {}, expected the path of a copyright file",
+                            key,
+                            self.path.display()
+                        ))
+                    }
+                }
+                continue;
+            }
+            if let Some(script_type) = parse_suffix_key(key) {
+                match value {
+                    Value::String(suffix) if !suffix.is_empty() => {
+                        spec.suffixes.retain(|(_, name)| {
+                            map_script_to_name(name) != map_script_to_name(script_type)
+                        });
+                        spec.suffixes.push((suffix.clone(), script_type.to_owned()));
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Invalid value for {} in {}, expected a suffix such as _ue",
+                            key,
+                            self.path.display()
+                        ))
+                    }
+                }
+                continue;
+            }
+            let (_, arg, env) = KEYS
+                .iter()
+                .find(|(name, _, _)| name == key)
+                .expect("Config keys are checked when parsed");
+            if explicit(arg) || env.is_some_and(|env| std::env::var_os(env).is_some()) {
+                continue;
+            }
+
+            match (key.as_str(), value) {
+                ("apiversion", Value::String(version)) => spec.api_version = version.clone(),
+                ("modules", Value::List(modules)) => spec.modules = modules.clone(),
+                ("recipes", Value::List(recipes)) => spec.recipes = recipes.clone(),
+                ("copyright", Value::String(path)) => spec.copyright = self.resolve(path),
+                ("copyright-text", Value::String(text)) => spec.copyright_text = Some(text.clone()),
+                ("company", Value::String(company)) => spec.company = company.clone(),
+                ("author", Value::String(author)) => spec.author = Some(Some(author.clone())),
+                ("author", Value::Bool(true)) => spec.author = Some(None),
+                ("author", Value::Bool(false)) => spec.author = None,
+                ("description", Value::String(text)) => spec.description = Some(text.clone()),
+                ("scope", Value::String(scope)) => spec.scope = scope.clone(),
+                ("template", Value::String(path)) => spec.template = Some(self.resolve(path)),
+                ("templates-dir", Value::String(path)) => {
+                    spec.templates_dir = Some(self.resolve(path))
+                }
+                ("no-header", Value::Bool(enabled)) => spec.no_header = *enabled,
+                ("ts-check", Value::Bool(enabled)) => spec.ts_check = *enabled,
+                ("provenance", Value::Bool(enabled)) => spec.provenance = *enabled,
+                ("reproducible", Value::Bool(enabled)) => spec.reproducible = *enabled,
+                ("backup", Value::Bool(enabled)) => spec.backup = *enabled,
+                ("lock", Value::Bool(enabled)) => spec.lock = *enabled,
+                ("create-dirs", Value::Bool(enabled)) => spec.create_dirs = *enabled,
+                ("filename-pattern", Value::String(pattern)) => {
+                    Regex::new(pattern).map_err(|error| {
+                        format!(
+                            "Invalid filename-pattern in {}: {}",
+                            self.path.display(),
+                            error
+                        )
+                    })?;
+                    spec.file_name_pattern = Some(pattern.clone());
+                }
+                ("filename-convention", Value::String(convention)) => {
+                    spec.file_name_convention = Some(convention.clone())
+                }
+                ("record-types", Value::List(records)) => {
+                    if let Some(record) = records
+                        .iter()
+                        .find(|record| validate_record((*record).clone()).is_err())
+                    {
+                        return Err(format!(
+                            "Invalid record type {} in record-types in {}",
+                            record,
+                            self.path.display()
+                        ));
+                    }
+                    for record in records {
+                        if !spec.record_types.contains(record) {
+                            spec.record_types.push(record.clone());
+                        }
+                    }
+                }
+                ("indent", Value::String(indent)) => {
+                    validate_indent(indent.clone()).map_err(|error| {
+                        format!("Invalid indent in {}: {}", self.path.display(), error)
+                    })?;
+                    spec.indent = Some(indent.clone());
+                }
+                ("quotes", Value::String(quotes)) => {
+                    validate_quotes(quotes.clone()).map_err(|error| {
+                        format!("Invalid quotes in {}: {}", self.path.display(), error)
+                    })?;
+                    spec.quotes = Some(quotes.clone());
+                }
+                ("align-tags", Value::Bool(enabled)) => spec.align_tags = *enabled,
+                _ => {
+                    return Err(format!(
+                        "Invalid value for {} in {}",
+                        key,
+                        self.path.display()
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves a path of the config relative to the directory of the config, leaving HTTPS URLs
+    /// as they are.
+    fn resolve(&self, path: &str) -> PathBuf {
+        if remote::is_url(Path::new(path)) {
+            return PathBuf::from(path);
+        }
+        self.path
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(path)
+    }
+}
+
+/// Flattens the keys of nested tables into dotted keys, such as `env.sandbox.account`, in the order
+/// they are written.
+fn flatten(prefix: &str, table: toml::Table, entries: &mut Vec<(String, toml::Value)>) {
+    for (key, value) in table {
+        let key = format!("{}{}", prefix, key);
+        match value {
+            toml::Value::Table(table) => flatten(&format!("{}.", key), table, entries),
+            value => entries.push((key, value)),
+        }
+    }
+}
+
+/// Parses the value of an option given as a command line argument.
+///
+/// Modules, recipes, and record types are separated by commas, and flags are `true` or `false`. The author is the
+/// git user name if `true`, and the indent and quotes of `format` are validated.
+fn parse_arg_value(key: &str, value: &str) -> Result<Value, String> {
+    match key {
+        "modules" | "recipes" | "record-types" => Ok(Value::List(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        )),
+        "no-header" | "ts-check" | "provenance" | "reproducible" | "backup" | "lock"
+        | "create-dirs" | "align-tags" => match value {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err(format!(
+                "Invalid value {} for {}, expected true or false",
+                value, key
+            )),
+        },
+        "author" if value == "true" || value == "false" => Ok(Value::Bool(value == "true")),
+        "indent" => validate_indent(value.to_owned())
+            .map(|()| Value::String(value.to_owned()))
+            .map_err(|error| format!("Invalid indent: {}", error)),
+        "quotes" => validate_quotes(value.to_owned())
+            .map(|()| Value::String(value.to_owned()))
+            .map_err(|error| format!("Invalid quotes: {}", error)),
+        _ => Ok(Value::String(value.to_owned())),
+    }
+}
+
+/// Sets an option in the contents of a config file.
+///
+/// Replaces the value of the option if it is set, in its table if it is in one, and adds the option
+/// otherwise, so that comments, tables, and the order of options are kept. The settings of an
+/// environment are added to its table, such as `[env.sandbox]`.
+///
+/// # Errors
+/// Returns an error if the contents are not valid TOML, or if a key of the option is already set
+/// to a value that is not a table
+fn set_value(contents: &str, key: &str, value: &Value) -> Result<String, String> {
+    let mut document: toml_edit::DocumentMut = contents
+        .parse()
+        .map_err(|error: toml_edit::TomlError| error.message().to_owned())?;
+    let mut segments: Vec<&str> = key.split('.').collect();
+    let name = segments.pop().expect("Keys are not empty");
+    let mut table: &mut dyn toml_edit::TableLike = document.as_table_mut();
+    for segment in segments {
+        let item = table.entry(segment).or_insert_with(|| {
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            toml_edit::Item::Table(table)
+        });
+        table = item
+            .as_table_like_mut()
+            .ok_or_else(|| format!("Cannot set {}, {} is not a table", key, segment))?;
+    }
+    match table.get_mut(name).and_then(toml_edit::Item::as_value_mut) {
+        Some(existing) => {
+            let decor = existing.decor().clone();
+            *existing = value.to_toml();
+            *existing.decor_mut() = decor;
+        }
+        None => {
+            table.insert(name, toml_edit::Item::Value(value.to_toml()));
+        }
+    }
+    Ok(document.to_string())
+}
+
+/// Validates the indentation of the entries of `define` arrays, a number of spaces from 1 to 8 or
+/// `tab`.
+pub fn validate_indent(indent: String) -> Result<(), String> {
+    match indent.parse::<usize>() {
+        Ok(1..=8) => Ok(()),
+        _ if indent == "tab" => Ok(()),
+        _ => Err(format!(
+            "{} is not a number of spaces from 1 to 8 or tab",
+            indent
+        )),
+    }
+}
+
+/// Validates the quotes of the entries of `define` arrays, `single` or `double`.
+pub fn validate_quotes(quotes: String) -> Result<(), String> {
+    match quotes.as_str() {
+        "single" | "double" => Ok(()),
+        _ => Err(format!("{} is not single or double", quotes)),
+    }
+}
+
+/// Validates the name of an option that can be set in a config file.
+fn validate_key(key: String) -> Result<(), String> {
+    if is_key(&key) {
+        return Ok(());
+    }
+
+    Err(format!("Invalid config option {}", key))
+}
+
+/// Checks whether an option can be set in a config file, either an option of scripts, a setting
+/// of an environment, the suffix of a script type, or the copyright file of a directory.
+fn is_key(key: &str) -> bool {
+    KEYS.iter().any(|(name, _, _)| *name == key)
+        || parse_env_key(key).is_some()
+        || parse_suffix_key(key).is_some()
+        || parse_copyright_key(key).is_some()
+}
+
+/// Splits the key of an environment setting, such as `env.sandbox.account`, into the name of the
+/// environment and the setting.
+fn parse_env_key(key: &str) -> Option<(&str, &str)> {
+    let (name, setting) = key.strip_prefix("env.")?.rsplit_once('.')?;
+    Some((name, setting)).filter(|_| !name.is_empty() && ENV_KEYS.contains(&setting))
+}
+
+/// Gets the script type of the key of a file name suffix, such as `userevent` in
+/// `suffixes.userevent`.
+fn parse_suffix_key(key: &str) -> Option<&str> {
+    key.strip_prefix("suffixes.")
+        .filter(|script_type| !map_script_to_name(script_type).is_empty())
+}
+
+/// Gets the directory of the key of a directory copyright file, such as `vendor` in
+/// `copyrights.vendor`.
+fn parse_copyright_key(key: &str) -> Option<&str> {
+    key.strip_prefix("copyrights.")
+        .filter(|dir| !dir.is_empty())
+}
+
+/// Applies the user config and the project config of a path, where the project config takes
+/// precedence, to a script with the default options.
+///
+/// Used by the commands that check existing scripts against the conventions of their project, such
+/// as the suffixes of their file names.
+///
+/// # Errors
+/// Returns an error if the current directory or a config cannot be read, or a config is invalid
+pub fn load_spec(path: &Path) -> Result<ScriptSpec, SuiteScriptError> {
+    let current_dir = std::env::current_dir()
+        .map_err(|source| SuiteScriptError::io("Failed to read the current directory", source))?;
+    let mut spec = ScriptSpec::default();
+    let user_config = user_config_path().filter(|path| path.is_file());
+    for path in user_config
+        .iter()
+        .chain(find_project_config(&current_dir.join(path)).iter())
+    {
+        Config::load(path)?.apply(&mut spec, &|_| false)?;
+    }
+    Ok(spec)
+}
+
+/// Gets the directory of user configuration files.
+///
+/// Uses `XDG_CONFIG_HOME` if set, `~/.config` otherwise, and `%APPDATA%` on systems without a home
+/// directory.
+fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+}
+
+/// Gets the directory of the user config and templates, such as `~/.config/suitescript`.
+pub fn user_dir() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("suitescript"))
+}
+
+/// Gets the path of the user config, such as `~/.config/suitescript/config.toml`.
+pub fn user_config_path() -> Option<PathBuf> {
+    user_dir().map(|dir| dir.join("config.toml"))
+}
+
+/// Finds the project config of a directory, looking up from the directory to the root.
+///
+/// Returns the first `suitescript.toml` or `.suitescriptrc` found, or `None` if there is none.
+pub fn find_project_config(start: &Path) -> Option<PathBuf> {
+    start.ancestors().find_map(|dir| {
+        PROJECT_FILES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structopt::StructOpt;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            Path::new("suitescript.toml"),
+            "# Defaults\napiversion = \"2.x\"\nmodules = [\"record\", \"search\"]\nts-check = true\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.values,
+            vec![
+                (
+                    String::from("apiversion"),
+                    Value::String(String::from("2.x"))
+                ),
+                (
+                    String::from("modules"),
+                    Value::List(vec![String::from("record"), String::from("search")])
+                ),
+                (String::from("ts-check"), Value::Bool(true)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_toml() {
+        let config = Config::parse(
+            Path::new("suitescript.toml"),
+            "apiversion = \"2.0\" # old\nmodules = [\n  \"record\", # records\n  'search',\n]\ncopyright-text = \"Copyright (c) MyCompany, Inc.\"\n\n[env.sandbox]\naccount = \"1234567_SB1\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.values,
+            vec![
+                (
+                    String::from("apiversion"),
+                    Value::String(String::from("2.0"))
+                ),
+                (
+                    String::from("modules"),
+                    Value::List(vec![String::from("record"), String::from("search")])
+                ),
+                (
+                    String::from("copyright-text"),
+                    Value::String(String::from("Copyright (c) MyCompany, Inc."))
+                ),
+                (
+                    String::from("env.sandbox.account"),
+                    Value::String(String::from("1234567_SB1"))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let path = Path::new("suitescript.toml");
+        assert!(Config::parse(path, "# Defaults\napiversion = \"2.1")
+            .unwrap_err()
+            .starts_with("Invalid line 2 in suitescript.toml: "));
+        assert_eq!(
+            Config::parse(path, "apiversion = 2"),
+            Err(String::from(
+                "Invalid value for apiversion in suitescript.toml, expected a string, true or false, or a list of strings"
+            ))
+        );
+        assert_eq!(
+            Config::parse(path, "filename = \"a.js\""),
+            Err(String::from("Unknown option filename in suitescript.toml"))
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let config = Config::parse(
+            Path::new("project/suitescript.toml"),
+            "apiversion = \"2.0\"\ncopyright = \"copyright.txt\"\nscope = \"Public\"\n",
+        )
+        .unwrap();
+        let mut spec =
+            ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "--scope", "SameAccount"]);
+        config.apply(&mut spec, &|arg| arg == "scope").unwrap();
+        assert_eq!(spec.api_version, "2.0");
+        assert_eq!(spec.copyright, PathBuf::from("project/copyright.txt"));
+        assert_eq!(spec.scope, "SameAccount");
+
+        let config = Config::parse(
+            Path::new("suitescript.toml"),
+            "filename-pattern = \"_(ue|cs\"\n",
+        )
+        .unwrap();
+        let error = config.apply(&mut spec, &|_| false).unwrap_err();
+        assert!(error.starts_with("Invalid filename-pattern in suitescript.toml: "));
+        assert!(error.ends_with("error: unclosed group"));
+    }
+
+    #[test]
+    fn test_apply_style() {
+        let config = Config::parse(
+            Path::new("suitescript.toml"),
+            "indent = \"tab\"\nquotes = \"double\"\nalign-tags = true\n",
+        )
+        .unwrap();
+        let mut spec = ScriptSpec::default();
+        config.apply(&mut spec, &|_| false).unwrap();
+        assert_eq!(spec.indent.as_deref(), Some("tab"));
+        assert_eq!(spec.quotes.as_deref(), Some("double"));
+        assert!(spec.align_tags);
+
+        let config = Config::parse(Path::new("suitescript.toml"), "indent = \"12\"").unwrap();
+        assert_eq!(
+            config.apply(&mut spec, &|_| false),
+            Err(String::from(
+                "Invalid indent in suitescript.toml: 12 is not a number of spaces from 1 to 8 or tab"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_apply_copyrights() {
+        let config = Config::parse(
+            Path::new("project/suitescript.toml"),
+            "[copyrights]\nvendor = \"third-party.txt\"\n\"src/lib\" = \"lib.txt\"\n",
+        )
+        .unwrap();
+        let mut spec = ScriptSpec::default();
+        config.apply(&mut spec, &|_| false).unwrap();
+        assert_eq!(
+            spec.copyrights,
+            vec![
+                (
+                    PathBuf::from("project/vendor"),
+                    PathBuf::from("project/third-party.txt")
+                ),
+                (
+                    PathBuf::from("project/src/lib"),
+                    PathBuf::from("project/lib.txt")
+                ),
+            ]
+        );
+
+        let mut spec = ScriptSpec::default();
+        config.apply(&mut spec, &|arg| arg == "copyright").unwrap();
+        assert!(spec.copyrights.is_empty());
+
+        let config =
+            Config::parse(Path::new("suitescript.toml"), "copyrights.vendor = true").unwrap();
+        assert_eq!(
+            config.apply(&mut spec, &|_| false),
+            Err(String::from(
+                "Invalid value for copyrights.vendor in suitescript.toml, expected the path of a copyright file"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_apply_suffixes() {
+        let path = Path::new("suitescript.toml");
+        let mut spec = ScriptSpec::default();
+        for contents in &[
+            "[suffixes]\nuserevent = \"_ue\"\nmapreduce = \"_mapreduce\"\n",
+            "suffixes.ue = \"_userevent\"\n",
+        ] {
+            let config = Config::parse(path, contents).unwrap();
+            config.apply(&mut spec, &|_| false).unwrap();
+        }
+        assert_eq!(
+            spec.suffixes,
+            vec![
+                (String::from("_mapreduce"), String::from("mapreduce")),
+                (String::from("_userevent"), String::from("ue")),
+            ]
+        );
+
+        assert_eq!(
+            Config::parse(path, "suffixes.unknown = \"_x\""),
+            Err(String::from(
+                "Unknown option suffixes.unknown in suitescript.toml"
+            ))
+        );
+        let config = Config::parse(path, "suffixes.client = \"\"").unwrap();
+        assert_eq!(
+            config.apply(&mut spec, &|_| false),
+            Err(String::from(
+                "Invalid value for suffixes.client in suitescript.toml, expected a suffix such as _ue"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_apply_record_types() {
+        let path = Path::new("suitescript.toml");
+        let mut spec = ScriptSpec::default();
+        for contents in &[
+            "record-types = [\"customtransaction_expense\"]",
+            "record-types = [\"customtransaction_expense\", \"customsale_rental\"]",
+        ] {
+            let config = Config::parse(path, contents).unwrap();
+            config.apply(&mut spec, &|_| false).unwrap();
+        }
+        assert_eq!(
+            spec.record_types,
+            vec![
+                String::from("customtransaction_expense"),
+                String::from("customsale_rental")
+            ]
+        );
+        let config = Config::parse(path, "record-types = [\"Sales Order\"]").unwrap();
+        assert_eq!(
+            config.apply(&mut spec, &|_| false),
+            Err(String::from(
+                "Invalid record type Sales Order in record-types in suitescript.toml"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_get_env() {
+        let path = Path::new("suitescript.toml");
+        let config = Config::parse(
+            path,
+            "env.sandbox.account = \"1234567_SB1\"\nenv.sandbox.folder = \"42\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.get_env("sandbox", "account"), Some("1234567_SB1"));
+        assert_eq!(config.get_env("production", "account"), None);
+        assert_eq!(
+            Config::parse(path, "env.sandbox.password = \"secret\""),
+            Err(String::from(
+                "Unknown option env.sandbox.password in suitescript.toml"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_set_value() {
+        let contents = "# Defaults\napiversion = \"2.0\"\n";
+        let value = Value::String(String::from("2.1"));
+        assert_eq!(
+            set_value(contents, "apiversion", &value),
+            Ok(String::from("# Defaults\napiversion = \"2.1\"\n"))
+        );
+        let modules = parse_arg_value("modules", "record, search").unwrap();
+        assert_eq!(
+            set_value(contents, "modules", &modules),
+            Ok(String::from(
+                "# Defaults\napiversion = \"2.0\"\nmodules = [\"record\", \"search\"]\n"
+            ))
+        );
+
+        let contents = "apiversion = \"2.0\" # old\n\n[env.sandbox]\naccount = \"1\"\n";
+        let account = Value::String(String::from("1234567_SB1"));
+        assert_eq!(
+            set_value(contents, "env.sandbox.account", &account),
+            Ok(String::from(
+                "apiversion = \"2.0\" # old\n\n[env.sandbox]\naccount = \"1234567_SB1\"\n"
+            ))
+        );
+        assert_eq!(
+            set_value(contents, "apiversion", &value),
+            Ok(String::from(
+                "apiversion = \"2.1\" # old\n\n[env.sandbox]\naccount = \"1\"\n"
+            ))
+        );
+        assert_eq!(
+            set_value(
+                "",
+                "env.production.folder",
+                &Value::String(String::from("42"))
+            ),
+            Ok(String::from("[env.production]\nfolder = \"42\"\n"))
+        );
+    }
+
+    #[test]
+    fn test_parse_arg_value() {
+        assert_eq!(parse_arg_value("author", "true"), Ok(Value::Bool(true)));
+        assert_eq!(
+            parse_arg_value("company", "Say \"hi\""),
+            Ok(Value::String(String::from("Say \"hi\"")))
+        );
+        assert!(parse_arg_value("ts-check", "yes").is_err());
+    }
+
+    #[test]
+    fn test_quote_round_trip() {
+        let value = Value::String(String::from("Line \"one\"\\\nLine two"));
+        let config = Config::parse(
+            Path::new("suitescript.toml"),
+            &format!("company = {}", value),
+        )
+        .unwrap();
+        assert_eq!(config.get("company"), Some(&value));
+    }
+
+    #[test]
+    fn test_find_project_config() {
+        let dir = std::env::temp_dir().join(format!("suitescript-config-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src/FileCabinet")).unwrap();
+        std::fs::write(dir.join(".suitescriptrc"), "").unwrap();
+
+        let found = find_project_config(&dir.join("src/FileCabinet"));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(found, Some(dir.join(".suitescriptrc")));
+    }
+}
@@ -0,0 +1,142 @@
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::audit::find_scripts;
+use super::{
+    get_copyright, get_copyright_pattern, read_copyright, read_to_string, write_atomically,
+    ScriptSpec, SuiteScriptError,
+};
+
+/// Options for replacing the copyright notice of existing scripts.
+#[derive(StructOpt, Debug)]
+pub struct UpdateCopyrightOpt {
+    /// Path to a file containing the copyright message to replace
+    #[structopt(long = "old", parse(from_os_str))]
+    old: PathBuf,
+
+    /// Path to a file containing the new copyright message
+    #[structopt(long = "new", parse(from_os_str))]
+    new: PathBuf,
+
+    /// Script, or directory of scripts, to update
+    #[structopt(parse(from_os_str))]
+    dir: PathBuf,
+
+    /// Company name substituted for `{{company}}` in the copyright messages
+    #[structopt(long = "company", env = "SUITESCRIPT_COMPANY", default_value = "")]
+    company: String,
+}
+
+/// Replaces the old copyright notice with the new one in every script at a path.
+///
+/// Both messages are formatted and their placeholders substituted the same way as when generating
+/// the script, so a plain text notice matches the comment block written for it, in any year. Prints
+/// each updated script and each script where the old notice was not found.
+///
+/// # Errors
+/// Returns an error if a copyright file or a script cannot be read, or a script cannot be written
+pub fn run(opt: &UpdateCopyrightOpt) -> Result<(), SuiteScriptError> {
+    let old = read_notice(&opt.old)?;
+
+    for script in find_scripts(&opt.dir)? {
+        let contents = read_to_string(&script)?;
+        let spec = ScriptSpec {
+            file_name: Some(script.clone()),
+            copyright: opt.new.clone(),
+            company: opt.company.clone(),
+            ..ScriptSpec::default()
+        };
+        let old = get_copyright_pattern(&old, &spec);
+        let new = get_copyright(&spec)?;
+        match replace_notice(&contents, &old, new.trim_end()) {
+            Some(updated) => {
+                if updated != contents {
+                    write_atomically(&script, &updated)?;
+                    println!("Updated {}", script.display());
+                }
+            }
+            None => println!("Old notice not found in {}", script.display()),
+        }
+    }
+    Ok(())
+}
+
+/// Reads the message of a copyright file, before its placeholders are substituted.
+///
+/// # Errors
+/// Returns an IO error if the file cannot be read, and a validation error if it is empty
+fn read_notice(path: &Path) -> Result<String, SuiteScriptError> {
+    let spec = ScriptSpec {
+        copyright: path.to_owned(),
+        ..ScriptSpec::default()
+    };
+    read_copyright(&spec)?.ok_or_else(|| {
+        SuiteScriptError::Validation(format!("Copyright file {} is empty", path.display()))
+    })
+}
+
+/// Replaces the first occurrence of the old notice in a script with the new one.
+///
+/// Returns `None` if the script does not contain the old notice.
+fn replace_notice(contents: &str, old: &Regex, new: &str) -> Option<String> {
+    let found = old.find(contents)?;
+    Some(format!(
+        "{}{}{}",
+        &contents[..found.start()],
+        new,
+        &contents[found.end()..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{format_copyright, generate};
+    use super::*;
+
+    #[test]
+    fn test_replace_notice() {
+        let spec = ScriptSpec::default();
+        let old = get_copyright_pattern("Copyright (c) 2020 MyCompany", &spec);
+        let new = format_copyright("Copyright (c) 2021 MyCompany");
+        let contents = format!(
+            "{}\n\n/**\n * @NApiVersion 2.1\n */\n",
+            format_copyright("Copyright (c) 2020 MyCompany")
+        );
+        assert_eq!(
+            replace_notice(&contents, &old, &new),
+            Some(format!("{}\n\n/**\n * @NApiVersion 2.1\n */\n", new))
+        );
+    }
+
+    #[test]
+    fn test_replace_missing_notice() {
+        assert_eq!(
+            replace_notice(
+                "/**\n * @NApiVersion 2.1\n */\n",
+                &get_copyright_pattern("/** Old */", &ScriptSpec::default()),
+                "/** New */"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_replace_generated_notice() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_ue.js")),
+            copyright_text: Some(String::from(
+                "Copyright (c) {{year}} {{company}} - {{file}}",
+            )),
+            company: String::from("MyCompany"),
+            ..ScriptSpec::default()
+        };
+        let contents = generate(&spec).unwrap();
+        let old = get_copyright_pattern("Copyright (c) {{year}} {{company}} - {{file}}", &spec);
+        let updated =
+            replace_notice(&contents, &old, "/**\n * Copyright (c) NewCompany\n */").unwrap();
+        assert!(
+            updated.starts_with("/**\n * Copyright (c) NewCompany\n */\n\n/**\n * @NScriptType")
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+use super::amdconfig::get_config_path;
+use super::pair::{get_client_path, get_constants_path};
+use super::sdfproject::find_file_cabinet;
+use super::{map_script_to_name, ScriptSpec, SuiteScriptError};
+
+/// Deploys a generated script with the `suitecloud` CLI.
+///
+/// Scripts with an SDF object are deployed with `project:deploy`, which deploys the objects too,
+/// and other scripts are uploaded with `file:upload`, along with the scripts paired with them and
+/// their AMD config. Tests, harnesses, and other files used only locally are not uploaded. The CLI
+/// is run in the root of the project, with its output shown as it runs. Returns the exit code of
+/// the CLI.
+///
+/// # Errors
+/// Returns a validation error if the script is not in the File Cabinet of a `SuiteCloud` project,
+/// and an IO error if the CLI cannot be run
+pub fn run(config: &ScriptSpec, script: &Path) -> Result<i32, SuiteScriptError> {
+    let current_dir = get_current_dir()?;
+    let file_cabinet = find_script_file_cabinet(script)?;
+    let args = if config.sdf_object {
+        vec![String::from("project:deploy")]
+    } else {
+        let mut args = vec![String::from("file:upload"), String::from("--paths")];
+        args.extend(
+            get_uploaded_files(config, script)
+                .iter()
+                .filter_map(|path| get_cabinet_path(&file_cabinet, &current_dir.join(path))),
+        );
+        args
+    };
+
+    eprintln!("note: running suitecloud {}", args.join(" "));
+    let status = Command::new("suitecloud")
+        .args(&args)
+        .current_dir(get_project_root(&file_cabinet))
+        .status()
+        .map_err(|source| {
+            SuiteScriptError::io(
+                "Failed to run suitecloud, installed with `npm install -g @oracle/suitecloud-cli`",
+                source,
+            )
+        })?;
+    Ok(status.code().unwrap_or(1))
+}
+
+/// Finds the `FileCabinet` directory containing a script, checked before the script is written so
+/// that a script outside a project is not deployed.
+///
+/// # Errors
+/// Returns a validation error if the script is not in the File Cabinet of a `SuiteCloud` project
+pub fn find_script_file_cabinet(script: &Path) -> Result<PathBuf, SuiteScriptError> {
+    let script_path = get_current_dir()?.join(script);
+    script_path
+        .parent()
+        .and_then(find_file_cabinet)
+        .filter(|file_cabinet| script_path.starts_with(file_cabinet))
+        .ok_or_else(|| {
+            SuiteScriptError::Validation(format!(
+                "--deploy requires {} to be in the File Cabinet of a SuiteCloud project",
+                script.display()
+            ))
+        })
+}
+
+/// Gets the current directory, which relative paths are resolved from.
+fn get_current_dir() -> Result<PathBuf, SuiteScriptError> {
+    std::env::current_dir()
+        .map_err(|source| SuiteScriptError::io("Failed to read the current directory", source))
+}
+
+/// Gets the files uploaded with a script: the script, the scripts generated by `--pair`, and its
+/// AMD config.
+fn get_uploaded_files(config: &ScriptSpec, script: &Path) -> Vec<PathBuf> {
+    let mut files = vec![script.to_path_buf()];
+    if config.pair {
+        files.push(get_client_path(script));
+        if map_script_to_name(&config.script_type) == "UserEvent" {
+            files.push(get_constants_path(script));
+        }
+    }
+    if let Some(amd_config) = &config.amd_config {
+        files.push(get_config_path(script, amd_config));
+    }
+    files
+}
+
+/// Gets the root of the project of a `FileCabinet` directory, the parent of its `src` directory.
+fn get_project_root(file_cabinet: &Path) -> PathBuf {
+    let parent = file_cabinet.parent().unwrap_or(file_cabinet);
+    match parent.parent() {
+        Some(root) if parent.file_name().is_some_and(|name| name == "src") => root.to_path_buf(),
+        _ => parent.to_path_buf(),
+    }
+}
+
+/// Gets the path of a file in the File Cabinet, such as `/SuiteScripts/customer_ue.js`.
+///
+/// Returns `None` if the file is not in the `FileCabinet` directory.
+fn get_cabinet_path(file_cabinet: &Path, path: &Path) -> Option<String> {
+    let segments: Vec<String> = path
+        .strip_prefix(file_cabinet)
+        .ok()?
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(segment) => Some(segment.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+    Some(format!("/{}", segments.join("/")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_cabinet_path() {
+        let file_cabinet = Path::new("/project/src/FileCabinet");
+        assert_eq!(
+            get_cabinet_path(
+                file_cabinet,
+                Path::new("/project/src/FileCabinet/SuiteScripts/orders/orders_rl.js")
+            ),
+            Some(String::from("/SuiteScripts/orders/orders_rl.js"))
+        );
+        assert_eq!(
+            get_cabinet_path(
+                file_cabinet,
+                Path::new("/project/__tests__/orders_rl.test.js")
+            ),
+            None
+        );
+        assert_eq!(get_project_root(file_cabinet), PathBuf::from("/project"));
+    }
+}
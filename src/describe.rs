@@ -0,0 +1,92 @@
+use structopt::StructOpt;
+
+use super::assets::governance::COSTS;
+use super::assets::module_info::{ModuleInfo, MODULE_INFO};
+
+/// Options for describing a `SuiteScript` module.
+#[derive(StructOpt, Debug)]
+pub struct DescribeOpt {
+    /// Module to describe, such as `record` or `ui/serverwidget`
+    #[structopt(validator = validate_module)]
+    module: String,
+}
+
+/// Prints what a module is used for, the script types it is available in, and its commonly used
+/// members with their governance costs.
+pub fn run(opt: &DescribeOpt) {
+    let info = find_module(&opt.module).expect("Modules are checked when parsed");
+    print!("{}", format_module(info));
+}
+
+/// Finds the information of a module, ignoring case and an `N/` prefix.
+fn find_module(name: &str) -> Option<&'static ModuleInfo> {
+    let lower_case = name.to_lowercase();
+    let name = lower_case.strip_prefix("n/").unwrap_or(&lower_case);
+    MODULE_INFO.iter().find(|info| info.name == name)
+}
+
+/// Formats the information of a module.
+fn format_module(info: &ModuleInfo) -> String {
+    let members: String = info
+        .members
+        .iter()
+        .map(|member| match get_cost(info, member) {
+            Some(cost) => format!("  {} ({})\n", member, cost),
+            None => format!("  {}\n", member),
+        })
+        .collect();
+    format!(
+        "N/{}\n\n{}.\n\nAvailable in: {}\n\nCommon members:\n{}",
+        info.name, info.purpose, info.availability, members
+    )
+}
+
+/// Gets the governance cost of a member of a module, if it is in the cost table in `assets/`.
+///
+/// Members of objects, such as `Record.save`, are named after the module in the cost table, such as
+/// `record.Record.save`.
+fn get_cost(info: &ModuleInfo, member: &str) -> Option<String> {
+    let api = if member.starts_with(char::is_uppercase) {
+        let module = info.name.rsplit('/').next().unwrap_or(info.name);
+        format!("{}.{}", module, member)
+    } else {
+        member.to_owned()
+    };
+    let (_, min, max) = COSTS.iter().find(|(name, _, _)| *name == api)?;
+    if min == max {
+        let units = if *min == 1 { "unit" } else { "units" };
+        return Some(format!("{} governance {}", min, units));
+    }
+    Some(format!("{} to {} governance units", min, max))
+}
+
+/// Validates the name of a module to describe.
+fn validate_module(name: String) -> Result<(), String> {
+    if find_module(&name).is_some() {
+        return Ok(());
+    }
+
+    Err(format!("Invalid module name {}", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::assets::netsuite_types::MODULES;
+    use super::*;
+
+    #[test]
+    fn test_every_module_described() {
+        for module in MODULES.iter() {
+            assert!(find_module(module).is_some(), "{} is not described", module);
+        }
+    }
+
+    #[test]
+    fn test_format_module() {
+        let info = find_module("N/File").unwrap();
+        assert_eq!(
+            format_module(info),
+            "N/file\n\nCreates, loads, and deletes files in the File Cabinet.\n\nAvailable in: Server scripts\n\nCommon members:\n  file.create\n  file.load (10 governance units)\n  file.delete\n  File.save (20 governance units)\n  File.getContents\n"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use serde::Serialize;
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+use super::to_json;
+
+/// An error generating a script, or running one of the commands on existing scripts.
+#[derive(Debug, Error)]
+pub enum SuiteScriptError {
+    /// An option is invalid, or does not apply to the script type or to the other options.
+    #[error("{0}")]
+    Validation(String),
+
+    /// A file, a directory, or the terminal cannot be read or written.
+    #[error("{message}: {source}")]
+    Io {
+        /// What was being done, such as `Failed to read copyright file copyright.txt`
+        message: String,
+        /// The underlying error
+        #[source]
+        source: io::Error,
+    },
+
+    /// A template file cannot be read or rendered.
+    #[error("Failed to load template file {}: {source}", .path.display())]
+    Template {
+        /// Path of the template file
+        path: PathBuf,
+        /// The underlying error
+        #[source]
+        source: io::Error,
+    },
+}
+
+impl SuiteScriptError {
+    /// Creates an IO error describing what was being done when it failed.
+    pub fn io(message: impl Into<String>, source: io::Error) -> SuiteScriptError {
+        SuiteScriptError::Io {
+            message: message.into(),
+            source,
+        }
+    }
+
+    /// Prefixes the message of the error with what it applies to, such as a row of a CSV file.
+    ///
+    /// Template errors are returned unchanged, since they name the template file.
+    pub fn with_context(self, context: &str) -> SuiteScriptError {
+        match self {
+            SuiteScriptError::Validation(message) => {
+                SuiteScriptError::Validation(format!("{}: {}", context, message))
+            }
+            SuiteScriptError::Io { message, source } => SuiteScriptError::Io {
+                message: format!("{}: {}", context, message),
+                source,
+            },
+            template @ SuiteScriptError::Template { .. } => template,
+        }
+    }
+
+    /// Gets the name of the kind of error, such as `validation`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SuiteScriptError::Validation(_) => "validation",
+            SuiteScriptError::Io { .. } => "io",
+            SuiteScriptError::Template { .. } => "template",
+        }
+    }
+
+    /// Formats the error as a JSON object with its kind and message, for tools that wrap the CLI.
+    pub fn to_json(&self) -> String {
+        to_json(&JsonError {
+            error: JsonErrorDetails {
+                kind: self.kind(),
+                message: self.to_string(),
+            },
+        })
+    }
+}
+
+/// An error formatted as JSON by [`SuiteScriptError::to_json`].
+#[derive(Serialize)]
+struct JsonError {
+    error: JsonErrorDetails,
+}
+
+/// The kind and message of an error formatted as JSON.
+#[derive(Serialize)]
+struct JsonErrorDetails {
+    kind: &'static str,
+    message: String,
+}
+
+impl From<String> for SuiteScriptError {
+    fn from(message: String) -> SuiteScriptError {
+        SuiteScriptError::Validation(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let error = SuiteScriptError::Validation(String::from("Invalid module name \"x\""));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&error.to_json()).unwrap(),
+            serde_json::json!({
+                "error": { "kind": "validation", "message": "Invalid module name \"x\"" },
+            })
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+use super::audit::{find_scripts, get_tag};
+use super::config::load_spec;
+use super::formatter::{get_dependencies, split_define};
+use super::lint::{get_module_name, TAGS};
+use super::{
+    get_api_version, get_copyright, read_to_string, validate_api_version, write_atomically,
+    ScriptSpec, SuiteScriptError,
+};
+
+/// Options for repairing the headers of existing scripts.
+#[derive(StructOpt, Debug)]
+pub struct FixOpt {
+    /// Script, or directory of scripts, to fix
+    #[structopt(parse(from_os_str))]
+    path: PathBuf,
+
+    /// Print the fixes each script needs without changing any file
+    #[structopt(long = "dry-run", conflicts_with = "write", required_unless = "write")]
+    dry_run: bool,
+
+    /// Write the fixes to the scripts
+    #[structopt(long = "write")]
+    write: bool,
+
+    /// Version of the `SuiteScript` API inserted in scripts without an `@NApiVersion` tag, the
+    /// `apiversion` of the config, or 2.1, if omitted
+    #[structopt(short, long = "apiversion", validator = validate_api_version)]
+    api_version: Option<String>,
+
+    /// Path to a file containing the copyright message prepended to scripts without one, the
+    /// `copyright`, `copyright-text`, and `copyrights` table of the config if omitted
+    #[structopt(short, long = "copyright", parse(from_os_str))]
+    copyright: Option<PathBuf>,
+
+    /// Copyright message to use when no copyright file is given, with `\n` for line breaks
+    #[structopt(long = "copyright-text", env = "SUITESCRIPT_COPYRIGHT")]
+    copyright_text: Option<String>,
+
+    /// Company name substituted for `{{company}}` in the copyright message, the `company` of the
+    /// config if omitted
+    #[structopt(long = "company", env = "SUITESCRIPT_COMPANY")]
+    company: Option<String>,
+}
+
+/// Fixes the headers and `define` arrays of every script at a path, printing the fixes made to each
+/// script, or the fixes it needs with `--dry-run`.
+///
+/// Scripts without an `@NApiVersion` tag get one, header tags and modules are written in their
+/// documented casing, and scripts without a copyright notice get the given one. The API version,
+/// copyright notice, and company default to those of the configs of the path, where the copyright
+/// notice of the directory of a script in the `copyrights` table takes precedence.
+///
+/// # Errors
+/// Returns an error if the copyright file or a script cannot be read, or a script cannot be written
+pub fn run(opt: &FixOpt) -> Result<(), SuiteScriptError> {
+    let spec = load_spec(&opt.path)?;
+    let api_version = get_api_version(opt.api_version.as_deref().unwrap_or(&spec.api_version));
+    let scripts = find_scripts(&opt.path)?;
+    let write = opt.write && !opt.dry_run;
+    let (copyright, copyright_text, copyrights) = match (&opt.copyright, &opt.copyright_text) {
+        (None, None) => (spec.copyright, spec.copyright_text, spec.copyrights),
+        _ => (
+            opt.copyright.clone().unwrap_or_default(),
+            opt.copyright_text.clone(),
+            Vec::new(),
+        ),
+    };
+    let company = opt.company.clone().unwrap_or(spec.company);
+    let mut fixed = 0;
+    for script in &scripts {
+        let contents = read_to_string(script)?;
+        let copyright = get_copyright(&ScriptSpec {
+            file_name: Some(script.clone()),
+            copyright: copyright.clone(),
+            copyright_text: copyright_text.clone(),
+            company: company.clone(),
+            copyrights: copyrights.clone(),
+            ..ScriptSpec::default()
+        })?;
+        let (updated, fixes) = fix_script(&contents, &api_version, &copyright);
+        if fixes.is_empty() {
+            continue;
+        }
+
+        fixed += 1;
+        if write {
+            write_atomically(script, &updated)?;
+            println!("Fixed {}: {}", script.display(), fixes.join(", "));
+        } else {
+            println!("Would fix {}: {}", script.display(), fixes.join(", "));
+        }
+    }
+
+    if fixed == 0 {
+        println!("Nothing to fix in {} scripts", scripts.len());
+    }
+    Ok(())
+}
+
+/// Fixes the header and the `define` array of a script.
+///
+/// Returns the fixed contents and a description of each fix. Custom GL plugins are not versioned,
+/// so they do not get an `@NApiVersion` tag, and the copyright notice is only prepended if it is
+/// not empty and the comments before the `define` call do not mention a copyright.
+fn fix_script(contents: &str, api_version: &str, copyright: &str) -> (String, Vec<String>) {
+    let mut fixes = Vec::new();
+    let mut fixed = fix_tag_casing(contents, &mut fixes);
+
+    if get_tag(&fixed, "@NApiVersion").is_none() && !fixed.contains("function customizeGlImpact(") {
+        fixed = insert_api_version(&fixed, api_version);
+        fixes.push(format!("inserted @NApiVersion {}", api_version));
+    }
+
+    if let Some(normalized) = normalize_modules(&fixed, &mut fixes) {
+        fixed = normalized;
+    }
+
+    let header = &fixed[..fixed.find("define(").unwrap_or(fixed.len())];
+    if !copyright.is_empty() && !header.to_lowercase().contains("copyright") {
+        fixed = format!("{}{}", copyright, fixed);
+        fixes.push(String::from("prepended the copyright notice"));
+    }
+
+    (fixed, fixes)
+}
+
+/// Writes the `@NApiVersion` and `@NScriptType` tags of a script in their documented casing.
+fn fix_tag_casing(contents: &str, fixes: &mut Vec<String>) -> String {
+    let lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            let mut line = line.to_owned();
+            for word in line.clone().split_whitespace() {
+                if let Some(tag) = TAGS
+                    .iter()
+                    .find(|tag| tag.eq_ignore_ascii_case(word) && **tag != word)
+                {
+                    line = line.replacen(word, tag, 1);
+                    fixes.push(format!("wrote {} as {}", word, tag));
+                }
+            }
+            line
+        })
+        .collect();
+
+    let mut fixed = lines.join("\n");
+    if contents.ends_with('\n') {
+        fixed.push('\n');
+    }
+    fixed
+}
+
+/// Inserts an `@NApiVersion` tag after the `@NScriptType` tag of a script, or in a new `JSDoc`
+/// block before its `define` call if it has no `@NScriptType` tag.
+///
+/// A `JSDoc` block opened or closed on the line of the `@NScriptType` tag, such as
+/// `/** @NScriptType UserEventScript */`, is first expanded to one line per tag.
+fn insert_api_version(contents: &str, api_version: &str) -> String {
+    let tag = format!(" * @NApiVersion {}", api_version);
+    let mut lines: Vec<String> = contents.lines().map(str::to_owned).collect();
+    if let Some(index) = lines
+        .iter()
+        .position(|line| line.split_whitespace().any(|word| word == "@NScriptType"))
+    {
+        let line = lines.remove(index);
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let rest = line.trim();
+        let opened = rest.starts_with("/**");
+        let rest = rest.trim_start_matches("/**");
+        let closed = rest.ends_with("*/");
+        let rest = rest.trim_end_matches("*/").trim();
+        let mut expanded = Vec::new();
+        if opened || closed {
+            let prefix = if opened {
+                expanded.push(format!("{}/**", indent));
+                format!("{} ", indent)
+            } else {
+                indent.to_owned()
+            };
+            let rest = rest.trim_start_matches('*').trim_start();
+            expanded.push(format!("{}* {}", prefix, rest));
+            expanded.push(format!("{}* @NApiVersion {}", prefix, api_version));
+            if closed {
+                expanded.push(format!("{}*/", prefix));
+            }
+        } else {
+            expanded.push(line.clone());
+            expanded.push(tag);
+        }
+        lines.splice(index..index, expanded);
+        let mut inserted = lines.join("\n");
+        if contents.ends_with('\n') {
+            inserted.push('\n');
+        }
+        return inserted;
+    }
+
+    let at = contents.find("define(").unwrap_or(0);
+    format!("{}/**\n{}\n */\n{}", &contents[..at], tag, &contents[at..])
+}
+
+/// Writes the `N/` modules of a script's `define` array in their documented casing, such as
+/// `N/record` for `N/Record`.
+///
+/// Returns `None` if no module needs to be fixed.
+fn normalize_modules(contents: &str, fixes: &mut Vec<String>) -> Option<String> {
+    let (_, array, after) = split_define(contents)?;
+    let start = contents.len() - after.len() - "]".len() - array.len();
+    let mut fixed_array = array.to_owned();
+    for dependency in get_dependencies(contents)? {
+        let expected = match get_module_name(&dependency) {
+            Some(expected) if expected != dependency => expected,
+            _ => continue,
+        };
+        for quote in &['\'', '"'] {
+            fixed_array = fixed_array.replace(
+                &format!("{}{}{}", quote, dependency, quote),
+                &format!("{}{}{}", quote, expected, quote),
+            );
+        }
+        fixes.push(format!("wrote {} as {}", dependency, expected));
+    }
+
+    if fixed_array == array {
+        return None;
+    }
+    Some(format!(
+        "{}{}{}",
+        &contents[..start],
+        fixed_array,
+        &contents[start + array.len()..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fix_script() {
+        let contents = "/**\n * @nscripttype UserEventScript\n */\n\ndefine(['N/Record', 'N/ui/serverwidget', './constants'], (record, serverWidget, constants) => {\n  return {};\n});\n";
+        let copyright = "/**\n * Copyright (c) MyCompany\n */\n\n";
+        let (fixed, fixes) = fix_script(contents, "2.1", copyright);
+        assert_eq!(
+            fixed,
+            "/**\n * Copyright (c) MyCompany\n */\n\n/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.1\n */\n\ndefine(['N/record', 'N/ui/serverWidget', './constants'], (record, serverWidget, constants) => {\n  return {};\n});\n"
+        );
+        assert_eq!(
+            fixes,
+            vec![
+                "wrote @nscripttype as @NScriptType",
+                "inserted @NApiVersion 2.1",
+                "wrote N/Record as N/record",
+                "wrote N/ui/serverwidget as N/ui/serverWidget",
+                "prepended the copyright notice",
+            ]
+        );
+        assert_eq!(fix_script(&fixed, "2.1", copyright), (fixed, Vec::new()));
+    }
+
+    #[test]
+    fn test_insert_api_version() {
+        assert_eq!(
+            insert_api_version("/**\n * Copyright\n */\ndefine([], () => {});", "2.0"),
+            "/**\n * Copyright\n */\n/**\n * @NApiVersion 2.0\n */\ndefine([], () => {});"
+        );
+    }
+
+    #[test]
+    fn test_insert_api_version_single_line() {
+        assert_eq!(
+            insert_api_version(
+                "/** @NScriptType UserEventScript */\ndefine([], () => {});\n",
+                "2.1"
+            ),
+            "/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.1\n */\ndefine([], () => {});\n"
+        );
+        assert_eq!(
+            insert_api_version(
+                "/**\n * Copyright\n * @NScriptType ClientScript */\ndefine([], () => {});",
+                "2.0"
+            ),
+            "/**\n * Copyright\n * @NScriptType ClientScript\n * @NApiVersion 2.0\n */\ndefine([], () => {});"
+        );
+    }
+}
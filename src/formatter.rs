@@ -0,0 +1,334 @@
+use regex::Regex;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+use super::audit::find_scripts;
+use super::config::{load_spec, validate_indent};
+use super::{read_to_string, write_atomically, ScriptSpec, SuiteScriptError};
+
+/// Options for formatting existing scripts.
+#[derive(StructOpt, Debug)]
+pub struct FormatOpt {
+    /// Script, or directory of scripts, to format
+    #[structopt(parse(from_os_str))]
+    path: PathBuf,
+
+    /// Indentation of the entries of `define` arrays, a number of spaces or `tab`, the `indent` of
+    /// the config, or 2, if omitted
+    #[structopt(long = "indent", validator = validate_indent)]
+    indent: Option<String>,
+
+    /// Quotes of the entries of `define` arrays, the `quotes` of the config, or single, if omitted
+    #[structopt(long = "quotes", possible_values = &["single", "double"])]
+    quotes: Option<String>,
+
+    /// Aligns the values of the `@N` tags of header blocks in a column, as `align-tags` does in the
+    /// config
+    #[structopt(long = "align-tags")]
+    align_tags: bool,
+}
+
+/// The style scripts are formatted in, set by the `indent`, `quotes`, and `align-tags` options of a
+/// config.
+#[derive(Debug, PartialEq)]
+struct Style {
+    /// Indentation of the entries of `define` arrays
+    indent: String,
+    /// Quote of the entries of `define` arrays
+    quote: char,
+    /// Whether the values of `@N` tags are aligned in a column
+    align_tags: bool,
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style {
+            indent: String::from("  "),
+            quote: '\'',
+            align_tags: false,
+        }
+    }
+}
+
+impl Style {
+    /// Gets the style of the options of a script, the default style for the options it does not
+    /// set.
+    fn from_spec(spec: &ScriptSpec) -> Style {
+        let indent = match spec.indent.as_deref() {
+            Some("tab") => String::from("\t"),
+            Some(spaces) => " ".repeat(spaces.parse().unwrap_or(2)),
+            None => String::from("  "),
+        };
+        Style {
+            indent,
+            quote: if spec.quotes.as_deref() == Some("double") {
+                '"'
+            } else {
+                '\''
+            },
+            align_tags: spec.align_tags,
+        }
+    }
+}
+
+/// Formats every script at a path in place, printing the name of each file that changed.
+///
+/// Scripts are formatted in the style of the configs of the path, with the style given as options
+/// taking precedence.
+///
+/// # Errors
+/// Returns an error if a config or a script cannot be read, or a script cannot be written
+pub fn run(opt: &FormatOpt) -> Result<(), SuiteScriptError> {
+    let mut spec = load_spec(&opt.path)?;
+    if opt.indent.is_some() {
+        spec.indent = opt.indent.clone();
+    }
+    if opt.quotes.is_some() {
+        spec.quotes = opt.quotes.clone();
+    }
+    spec.align_tags |= opt.align_tags;
+    let style = Style::from_spec(&spec);
+    for script in find_scripts(&opt.path)? {
+        let contents = read_to_string(&script)?;
+        let formatted = format_script(&contents, &style);
+        if formatted != contents {
+            write_atomically(&script, &formatted)?;
+            println!("Formatted {}", script.display());
+        }
+    }
+    Ok(())
+}
+
+/// Formats the header tags and the `define` dependency array of a script in a style.
+fn format_script(contents: &str, style: &Style) -> String {
+    let aligned = align_header_tags(contents, style.align_tags);
+    format_define(&aligned, style).unwrap_or(aligned)
+}
+
+/// Gets the `@N` tag of a line of a `JSDoc` block, such as `@NApiVersion 2.1`.
+fn get_header_tag(line: &str) -> Option<&str> {
+    line.trim_start()
+        .strip_prefix('*')
+        .map(str::trim)
+        .filter(|tag| tag.starts_with("@N"))
+}
+
+/// Aligns the `@N` tags of a script's `JSDoc` blocks.
+///
+/// Each tag line is written as ` * ` followed by the tag and its value, separated by one space, or
+/// with `column`, by the spaces that align the values after the longest tag of the script.
+fn align_header_tags(contents: &str, column: bool) -> String {
+    let width = contents
+        .lines()
+        .filter_map(get_header_tag)
+        .filter_map(|tag| tag.split_whitespace().next())
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<String> = contents
+        .lines()
+        .map(|line| match get_header_tag(line) {
+            Some(tag) => {
+                let mut words = tag.split_whitespace();
+                let name = words.next().unwrap_or_default();
+                let value: Vec<&str> = words.collect();
+                match (value.is_empty(), column) {
+                    (true, _) => format!(" * {}", name),
+                    (false, true) => {
+                        format!(" * {:width$} {}", name, value.join(" "), width = width)
+                    }
+                    (false, false) => format!(" * {} {}", name, value.join(" ")),
+                }
+            }
+            None => line.to_owned(),
+        })
+        .collect();
+
+    let mut aligned = lines.join("\n");
+    if contents.ends_with('\n') {
+        aligned.push('\n');
+    }
+    aligned
+}
+
+/// Parses the entries of a `define` dependency array.
+///
+/// Returns `None` if an entry is not a quoted string, such as a variable or a comment.
+fn parse_dependencies(array: &str) -> Option<Vec<String>> {
+    array
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let quote = entry.chars().next()?;
+            if (quote == '\'' || quote == '"') && entry.len() > 1 && entry.ends_with(quote) {
+                Some(entry[1..entry.len() - 1].to_owned())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Splits a script around the dependency array of its `define` call.
+///
+/// Returns the contents before the `define` call, the contents of the array, and the contents
+/// after the array. Returns `None` if the script has no `define` call with a dependency array.
+pub fn split_define(contents: &str) -> Option<(&str, &str, &str)> {
+    let start = contents.find("define(")?;
+    let after = &contents[start + "define(".len()..];
+    let array = after.trim_start().strip_prefix('[')?;
+    let close = array.find(']')?;
+    Some((&contents[..start], &array[..close], &array[close + 1..]))
+}
+
+/// Gets the dependencies of a script's `define` call, or of its `import` declarations if it has no
+/// `define` call, as `TypeScript` scripts do.
+///
+/// Returns `None` if the script has no `define` call with a dependency array of strings and no
+/// `import` declaration.
+pub fn get_dependencies(contents: &str) -> Option<Vec<String>> {
+    match split_define(contents) {
+        Some((_, array, _)) => parse_dependencies(array),
+        None => get_imports(contents),
+    }
+}
+
+/// Gets the modules of the `import` declarations of a script, such as `N/record` in
+/// `import * as record from 'N/record';`, skipping the type-only imports, such as `N/types`.
+///
+/// Returns `None` if the script has no `import` declaration.
+fn get_imports(contents: &str) -> Option<Vec<String>> {
+    let pattern = Regex::new(r#"(?m)^\s*import\s+(type\s)?(?:[^'";]*?\sfrom\s*)?['"]([^'"]+)['"]"#)
+        .expect("The expression is valid");
+    let declarations: Vec<regex::Captures> = pattern.captures_iter(contents).collect();
+    if declarations.is_empty() {
+        return None;
+    }
+    Some(
+        declarations
+            .iter()
+            .filter(|captures| captures.get(1).is_none())
+            .map(|captures| captures[2].to_owned())
+            .collect(),
+    )
+}
+
+/// Formats the dependency array of a script's `define` call.
+///
+/// Dependencies are quoted, one per line with a trailing comma, and indented in the style, with
+/// single quotes and 2 spaces by default. Returns `None` if the script has no `define` call with a
+/// dependency array of strings.
+fn format_define(contents: &str, style: &Style) -> Option<String> {
+    let (before, array, after) = split_define(contents)?;
+    let dependencies = parse_dependencies(array)?;
+    let rest = after.trim_start();
+    let rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    let separator = if rest.starts_with(')') {
+        ""
+    } else if rest.starts_with("/*") {
+        ",\n"
+    } else {
+        ", "
+    };
+
+    let entries: String = dependencies
+        .iter()
+        .map(|dependency| {
+            format!(
+                "{}{}{}{},\n",
+                style.indent, style.quote, dependency, style.quote
+            )
+        })
+        .collect();
+    Some(format!(
+        "{}define([\n{}]{}{}",
+        before, entries, separator, rest
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_header_tags() {
+        assert_eq!(
+            align_header_tags(
+                "/**\n *   @NApiVersion    2.1\n*@NScriptType ClientScript\n */\n",
+                false
+            ),
+            "/**\n * @NApiVersion 2.1\n * @NScriptType ClientScript\n */\n"
+        );
+    }
+
+    #[test]
+    fn test_align_header_tags_column() {
+        assert_eq!(
+            align_header_tags(
+                "/**\n * @NApiVersion 2.1\n * @NScriptType ClientScript\n * @NModuleScope   SameAccount\n */\n",
+                true
+            ),
+            "/**\n * @NApiVersion  2.1\n * @NScriptType  ClientScript\n * @NModuleScope SameAccount\n */\n"
+        );
+    }
+
+    #[test]
+    fn test_format_define() {
+        assert_eq!(
+            format_define(
+                "define([\"N/record\",'N/search'],function (record, search) {\n});",
+                &Style::default()
+            ),
+            Some(String::from(
+                "define([\n  'N/record',\n  'N/search',\n], function (record, search) {\n});"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_format_define_style() {
+        let spec = ScriptSpec {
+            indent: Some(String::from("4")),
+            quotes: Some(String::from("double")),
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            format_define(
+                "define(['N/record'], (record) => {\n});",
+                &Style::from_spec(&spec)
+            ),
+            Some(String::from(
+                "define([\n    \"N/record\",\n], (record) => {\n});"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_format_define_unchanged() {
+        let contents = "define([\n  'N/record',\n], (record) => {\n});";
+        assert_eq!(format_script(contents, &Style::default()), contents);
+    }
+
+    #[test]
+    fn test_get_dependencies_imports() {
+        let contents = "/**\n * @NScriptType UserEventScript\n */\n\nimport type { EntryPoints } from 'N/types';\nimport * as record from 'N/record';\nimport {\n  create,\n} from \"N/search\";\nimport './polyfill';\n";
+        assert_eq!(
+            get_dependencies(contents),
+            Some(vec![
+                String::from("N/record"),
+                String::from("N/search"),
+                String::from("./polyfill"),
+            ])
+        );
+        assert_eq!(get_dependencies("const x = 1;\n"), None);
+    }
+
+    #[test]
+    fn test_format_define_non_string_dependency() {
+        assert_eq!(
+            format_define("define([deps], () => {});", &Style::default()),
+            None
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+
+use super::{get_module_names, get_module_path, OutputFile};
+
+/// HTTP methods with a fixture file generated alongside the harness.
+const FIXTURE_METHODS: [&str; 2] = ["get", "post"];
+
+/// Gets the files of a local test harness for a RESTlet next to the script.
+///
+/// The harness is a Node script named `<script>.harness.js`. Fixture files for the payloads of
+/// each method are placed in `fixtures/<script>/`, unless they already exist.
+pub fn get_files(script: &Path, modules: &[String]) -> Vec<OutputFile> {
+    let mut files = vec![OutputFile {
+        path: get_harness_path(script),
+        contents: get_harness(script, modules),
+    }];
+
+    let fixtures = get_fixtures_dir(script);
+    for method in &FIXTURE_METHODS {
+        let fixture = fixtures.join(format!("{}.json", method));
+        if !fixture.exists() {
+            files.push(OutputFile {
+                path: fixture,
+                contents: String::from("{}\n"),
+            });
+        }
+    }
+    files
+}
+
+/// Gets the path of the harness for a script.
+fn get_harness_path(script: &Path) -> PathBuf {
+    script.with_extension("harness.js")
+}
+
+/// Gets the directory holding the fixture files for a script.
+fn get_fixtures_dir(script: &Path) -> PathBuf {
+    let stem = script.file_stem().unwrap_or_default();
+    script
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join("fixtures")
+        .join(stem)
+}
+
+/// Writes the contents of the harness for a script.
+///
+/// The harness defines an AMD `define` shim that passes a stub for each imported module to the
+/// script, with `N/log` printing to the console. It then calls the entry point for the requested
+/// method with the payload read from a fixture file and prints the result.
+fn get_harness(script: &Path, modules: &[String]) -> String {
+    let file_name = script.file_name().unwrap_or_default().to_string_lossy();
+    let stem = script.file_stem().unwrap_or_default().to_string_lossy();
+
+    let mut stubs = vec![String::from(
+        "  'N/log': { debug: console.log, audit: console.log, error: console.error, emergency: console.error },",
+    )];
+    if modules != [String::from("")] {
+        stubs.extend(
+            get_module_names(modules)
+                .iter()
+                .filter(|name| *name != "log")
+                .map(|name| format!("  'N/{}': {{}},", get_module_path(name))),
+        );
+    }
+
+    format!(
+        "/**
+ * Local test harness for {file}.
+ *
+ * Usage: node {stem}.harness.js [get|post|put|delete] [fixture.json]
+ *
+ * Loads the RESTlet with stubbed N/ modules and calls the entry point for the method with the
+ * payload from the fixture file, which defaults to fixtures/{stem}/<method>.json.
+ */
+const fs = require('fs');
+const path = require('path');
+
+const stubs = {{
+{stubs}
+}};
+
+let entryPoints = {{}};
+global.define = (dependencies, factory) => {{
+  entryPoints = factory(...dependencies.map((name) => stubs[name] || {{}})) || {{}};
+}};
+require(path.join(__dirname, '{file}'));
+
+const method = (process.argv[2] || 'get').toLowerCase();
+const fixture = process.argv[3] || path.join(__dirname, 'fixtures', '{stem}', `${{method}}.json`);
+const payload = fs.existsSync(fixture) ? JSON.parse(fs.readFileSync(fixture, 'utf8')) : {{}};
+
+if (typeof entryPoints[method] !== 'function') {{
+  console.error(`{file} does not export a ${{method}} entry point`);
+  process.exit(1);
+}}
+
+console.log(JSON.stringify(entryPoints[method](payload), null, 2));
+",
+        file = file_name,
+        stem = stem,
+        stubs = stubs.join("\n"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_harness_paths() {
+        let script = Path::new("src/customer_rl.js");
+        assert_eq!(
+            get_harness_path(script),
+            PathBuf::from("src/customer_rl.harness.js")
+        );
+        assert_eq!(
+            get_fixtures_dir(script),
+            PathBuf::from("src/fixtures/customer_rl")
+        );
+    }
+
+    #[test]
+    fn test_harness_stubs_modules() {
+        let harness = get_harness(
+            Path::new("customer_rl.js"),
+            &[String::from("record"), String::from("log")],
+        );
+        assert!(harness.contains("  'N/record': {},\n}"));
+        assert!(harness.contains("require(path.join(__dirname, 'customer_rl.js'));"));
+        assert_eq!(harness.matches("'N/log'").count(), 1);
+    }
+}
@@ -0,0 +1,186 @@
+use serde_json::{Map, Value};
+use std::path::{Component, Path, PathBuf};
+use structopt::StructOpt;
+
+use super::audit::{find_scripts, get_tag};
+use super::batch::Manifest;
+use super::formatter::get_dependencies;
+use super::lock::normalize;
+use super::{get_api_version, read_to_string, write_atomically, SuiteScriptError};
+
+/// Options for describing the scripts of an existing project as a spec.
+#[derive(StructOpt, Debug)]
+pub struct ImportOpt {
+    /// Directory containing the scripts to describe
+    #[structopt(parse(from_os_str))]
+    dir: PathBuf,
+
+    /// Writes the spec to a file instead of printing it, with the file names of the scripts
+    /// relative to the directory of the file, as `batch` reads them
+    #[structopt(short, long = "output", parse(from_os_str))]
+    output: Option<PathBuf>,
+}
+
+/// Prints a YAML spec describing every script in a directory, or writes it to the `--output` file.
+///
+/// The spec is a manifest of `batch`, whose file names are relative to the directory of the
+/// manifest, or to the current directory when the spec is printed. Dependencies on custom modules
+/// are not supported by specs and are reported as warnings.
+///
+/// # Errors
+/// Returns an error if the current directory, the directory, or a script cannot be read, or the
+/// spec cannot be written
+pub fn run(opt: &ImportOpt) -> Result<(), SuiteScriptError> {
+    let current_dir = std::env::current_dir()
+        .map_err(|source| SuiteScriptError::io("Failed to read the current directory", source))?;
+    let base = opt
+        .output
+        .as_deref()
+        .and_then(Path::parent)
+        .map_or_else(|| current_dir.clone(), |dir| current_dir.join(dir));
+    let mut scripts = Vec::new();
+    for script in find_scripts(&opt.dir)? {
+        let contents = read_to_string(&script)?;
+        let file_name = get_relative_path(&current_dir.join(&script), &base);
+        let (entry, skipped) = describe_script(&file_name, &contents);
+        for dependency in skipped {
+            eprintln!(
+                "warning: skipped custom module `{}` in {}",
+                dependency,
+                script.display()
+            );
+        }
+        scripts.push(entry);
+    }
+
+    let spec = format_spec(&Manifest { scripts })?;
+    match &opt.output {
+        Some(output) => {
+            write_atomically(output, &spec)?;
+            println!("Wrote the spec to {}", output.display());
+        }
+        None => print!("{}", spec),
+    }
+    Ok(())
+}
+
+/// Describes a single script, given the file name it is listed with in the spec.
+///
+/// Returns the options of the script, named after the long flags used to generate it, and the
+/// custom modules it depends on, which are left out of the options.
+fn describe_script(file_name: &str, contents: &str) -> (Map<String, Value>, Vec<String>) {
+    let (modules, skipped) = get_dependencies(contents)
+        .unwrap_or_default()
+        .into_iter()
+        .partition::<Vec<String>, _>(|dependency| dependency.starts_with("N/"));
+
+    let mut entry = Map::new();
+    entry.insert(String::from("filename"), Value::from(file_name));
+    if let Some(tag) = get_tag(contents, "@NScriptType") {
+        let script_type = tag.trim_end_matches("Script").to_lowercase();
+        entry.insert(String::from("scripttype"), Value::from(script_type));
+    }
+    if let Some(version) = get_tag(contents, "@NApiVersion") {
+        entry.insert(
+            String::from("apiversion"),
+            Value::from(get_api_version(version)),
+        );
+    }
+    let modules = modules
+        .iter()
+        .map(|module| Value::from(module.trim_start_matches("N/")))
+        .collect();
+    entry.insert(String::from("modules"), Value::Array(modules));
+    (entry, skipped)
+}
+
+/// Gets a path relative to a directory, with `..` components for the parts of the directory it is
+/// not in, and `/` separators. Both paths are absolute.
+///
+/// Returns the path as it is if it is on another drive or root than the directory.
+fn get_relative_path(path: &Path, dir: &Path) -> String {
+    let path = normalize(path);
+    let dir = normalize(dir);
+    let common = path
+        .components()
+        .zip(dir.components())
+        .take_while(|(left, right)| left == right)
+        .count();
+    if common == 0 {
+        return path.to_string_lossy().replace('\\', "/");
+    }
+
+    let mut relative = PathBuf::new();
+    for _ in dir.components().skip(common) {
+        relative.push(Component::ParentDir);
+    }
+    for component in path.components().skip(common) {
+        relative.push(component);
+    }
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+/// Formats a manifest as a YAML spec.
+///
+/// # Errors
+/// Returns a validation error if the manifest cannot be serialized
+fn format_spec(manifest: &Manifest) -> Result<String, SuiteScriptError> {
+    serde_yaml::to_string(manifest).map_err(|error| {
+        SuiteScriptError::Validation(format!("Failed to write the spec: {}", error))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_describe_script() {
+        let contents = "/**\n * @NScriptType UserEventScript\n * @NApiVersion 2\n */\ndefine(['N/record', './lib/utils'], (record, utils) => {});";
+        let (entry, skipped) = describe_script("src/customer_ue.js", contents);
+        assert_eq!(
+            Value::Object(entry),
+            json!({
+                "filename": "src/customer_ue.js",
+                "scripttype": "userevent",
+                "apiversion": "2.0",
+                "modules": ["record"],
+            })
+        );
+        assert_eq!(skipped, vec![String::from("./lib/utils")]);
+    }
+
+    #[test]
+    fn test_get_relative_path() {
+        assert_eq!(
+            get_relative_path(
+                Path::new("/project/src/customer_ue.js"),
+                Path::new("/project")
+            ),
+            "src/customer_ue.js"
+        );
+        assert_eq!(
+            get_relative_path(
+                Path::new("/project/src/./customer_ue.js"),
+                Path::new("/project/specs")
+            ),
+            "../src/customer_ue.js"
+        );
+    }
+
+    #[test]
+    fn test_format_spec_round_trip() {
+        let (entry, _) = describe_script(
+            "src/customer's_ue.js",
+            "/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.1\n */\ndefine(['N/record', 'N/search'], (record, search) => {});",
+        );
+        let (library, _) = describe_script("src/lib.js", "");
+        let manifest = Manifest {
+            scripts: vec![entry, library],
+        };
+        let spec = format_spec(&manifest).unwrap();
+        assert_eq!(serde_yaml::from_str::<Manifest>(&spec).unwrap(), manifest);
+        assert_eq!(format_spec(&Manifest::default()).unwrap(), "scripts: []\n");
+    }
+}
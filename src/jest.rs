@@ -0,0 +1,143 @@
+use std::path::{Path, PathBuf};
+
+use super::{get_entry_points, get_module_names, get_module_path, OutputFile, ScriptSpec};
+
+/// Gets the Jest test file of a script, unless it already exists.
+///
+/// The test is named `__tests__/<script>.test.js`, in the directory of the script, so that tests
+/// written in it are never overwritten.
+pub fn get_file(script: &Path, config: &ScriptSpec) -> Option<OutputFile> {
+    let path = get_test_path(script);
+    if path.exists() {
+        return None;
+    }
+
+    Some(OutputFile {
+        path,
+        contents: get_test(script, config),
+    })
+}
+
+/// Gets the path of the test file of a script.
+fn get_test_path(script: &Path) -> PathBuf {
+    let stem = script.file_stem().unwrap_or_default().to_string_lossy();
+    script
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join("__tests__")
+        .join(format!("{}.test.js", stem))
+}
+
+/// Writes the contents of the test file of a script.
+///
+/// The test imports the script and each of its modules, which are replaced with the mocks of
+/// `@oracle/suitecloud-unit-testing` by `jest.mock`. Each entry point has a placeholder test that
+/// calls it with a minimal context, such as a `GET` request for a Suitelet, after setting the
+/// return values of the mocked modules its body uses.
+fn get_test(script: &Path, config: &ScriptSpec) -> String {
+    let file_name = script.file_name().unwrap_or_default().to_string_lossy();
+    let stem = script.file_stem().unwrap_or_default().to_string_lossy();
+    let modules: Vec<String> = get_module_names(&config.modules)
+        .into_iter()
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    let mut imports: Vec<String> = modules
+        .iter()
+        .map(|name| {
+            format!(
+                "import {} from 'N/{}';",
+                name.replace('/', ""),
+                get_module_path(name)
+            )
+        })
+        .collect();
+    imports.push(format!("import script from '../{}';", stem));
+    let mocks: String = modules
+        .iter()
+        .map(|name| format!("jest.mock('N/{}');\n", get_module_path(name)))
+        .collect();
+
+    let tests: Vec<String> = get_entry_points(config)
+        .iter()
+        .map(|entry_point| {
+            let mut setup = String::new();
+            if !entry_point.mocks.is_empty() {
+                setup.push_str(&format!("    {}\n", entry_point.mocks));
+            }
+            if !entry_point.param.is_empty() {
+                setup.push_str(&format!(
+                    "    const {} = {};\n",
+                    entry_point.param, entry_point.context
+                ));
+            }
+            if !setup.is_empty() {
+                setup.push('\n');
+            }
+            format!(
+                "  it('{}', () => {{\n{}    script.{}({});\n\n    // TODO: Assert the calls made to the mocked modules\n  }});",
+                entry_point.name, setup, entry_point.name, entry_point.param
+            )
+        })
+        .collect();
+
+    format!(
+        "/**\n * Tests of {}, run by Jest with the mocks of the N modules of\n * @oracle/suitecloud-unit-testing.\n */\n{}\n\n{}{}beforeEach(() => {{\n  jest.clearAllMocks();\n}});\n\ndescribe('{}', () => {{\n{}\n}});\n",
+        file_name,
+        imports.join("\n"),
+        mocks,
+        if mocks.is_empty() { "" } else { "\n" },
+        file_name,
+        tests.join("\n\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_test_path() {
+        assert_eq!(
+            get_test_path(Path::new("src/customer_ue.js")),
+            PathBuf::from("src/__tests__/customer_ue.test.js")
+        );
+    }
+
+    #[test]
+    fn test_get_test() {
+        let config = ScriptSpec {
+            script_type: String::from("userevent"),
+            modules: vec![String::from("record"), String::from("ui/message")],
+            events: vec![String::from("afterSubmit")],
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            get_test(Path::new("customer_ue.js"), &config),
+            "/**\n * Tests of customer_ue.js, run by Jest with the mocks of the N modules of\n * @oracle/suitecloud-unit-testing.\n */\nimport record from 'N/record';\nimport uimessage from 'N/ui/message';\nimport script from '../customer_ue';\n\njest.mock('N/record');\njest.mock('N/ui/message');\n\nbeforeEach(() => {\n  jest.clearAllMocks();\n});\n\ndescribe('customer_ue.js', () => {\n  it('afterSubmit', () => {\n    const scriptContext = { newRecord: {}, oldRecord: {}, type: 'create' };\n\n    script.afterSubmit(scriptContext);\n\n    // TODO: Assert the calls made to the mocked modules\n  });\n});\n"
+        );
+    }
+
+    #[test]
+    fn test_get_test_contexts() {
+        let suitelet = get_test(
+            Path::new("form_sl.js"),
+            &ScriptSpec {
+                script_type: String::from("suitelet"),
+                modules: vec![String::from("ui/serverwidget")],
+                ..ScriptSpec::default()
+            },
+        );
+        assert!(suitelet.contains("import uiserverwidget from 'N/ui/serverWidget';\n"));
+        assert!(suitelet.contains("  it('onRequest', () => {\n    uiserverwidget.createForm.mockReturnValue({ addSubmitButton: jest.fn() });\n    const context = { request: { method: 'GET', parameters: {} }, response: { write: jest.fn(), writePage: jest.fn() } };\n\n    script.onRequest(context);\n"));
+
+        let portlet = get_test(
+            Path::new("dashboard.js"),
+            &ScriptSpec {
+                script_type: String::from("portlet"),
+                ..ScriptSpec::default()
+            },
+        );
+        assert!(portlet.contains("    const params = { portlet: {}, column: 2 };\n"));
+    }
+}
@@ -0,0 +1,61 @@
+use keyring::{Entry, Error};
+use std::io;
+
+/// Service the secrets of the CLI are stored under in the keyring.
+const SERVICE: &str = "suitescript";
+
+/// Gets the entry of a profile in the keyring of the operating system.
+///
+/// Secrets are stored in the Secret Service on Linux, the login keychain on macOS, and the
+/// Credential Manager on Windows, under the `suitescript` service and the name of the profile.
+fn get_entry(profile: &str) -> io::Result<Entry> {
+    if let Err(error) = Entry::store_status() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("the keyring is unavailable: {}", error),
+        ));
+    }
+    Entry::new(SERVICE, profile).map_err(into_io_error)
+}
+
+/// Stores the secret of a profile in the keyring of the operating system, replacing its previous
+/// secret.
+///
+/// # Errors
+/// Returns an IO error if the keyring is unavailable or cannot be written
+pub fn set_secret(profile: &str, secret: &str) -> io::Result<()> {
+    get_entry(profile)?
+        .set_password(secret)
+        .map_err(into_io_error)
+}
+
+/// Gets the secret of a profile from the keyring of the operating system.
+///
+/// Returns `None` if the keyring has no secret for the profile.
+///
+/// # Errors
+/// Returns an IO error if the keyring is unavailable or cannot be read
+pub fn get_secret(profile: &str) -> io::Result<Option<String>> {
+    match get_entry(profile)?.get_password() {
+        Ok(secret) => Ok(Some(secret).filter(|secret| !secret.is_empty())),
+        Err(Error::NoEntry) => Ok(None),
+        Err(error) => Err(into_io_error(error)),
+    }
+}
+
+/// Deletes the secret of a profile from the keyring of the operating system, returning whether it existed.
+///
+/// # Errors
+/// Returns an IO error if the keyring is unavailable or cannot be written
+pub fn delete_secret(profile: &str) -> io::Result<bool> {
+    match get_entry(profile)?.delete_credential() {
+        Ok(()) => Ok(true),
+        Err(Error::NoEntry) => Ok(false),
+        Err(error) => Err(into_io_error(error)),
+    }
+}
+
+/// Converts an error of the keyring into an IO error.
+fn into_io_error(error: Error) -> io::Error {
+    io::Error::other(format!("the keyring failed: {}", error))
+}
@@ -0,0 +1,4278 @@
+//! Generation of `NetSuite` `SuiteScript` files and their boilerplate.
+//!
+//! The options of a script are described by a [`ScriptSpec`], which [`generate`] turns into the
+//! contents of the script. A [`ScriptBuilder`] builds a spec with typed script types and modules.
+//! The `suitescript` binary is a command line interface to this library.
+
+use handlebars::Handlebars;
+use regex::Regex;
+use serde::Serialize;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+pub mod amdconfig;
+mod assets;
+pub mod audit;
+pub mod auth;
+pub mod batch;
+pub mod builder;
+pub mod clean;
+pub mod completions;
+pub mod config;
+pub mod copyright;
+pub mod deploy;
+pub mod describe;
+pub mod error;
+pub mod fix;
+pub mod formatter;
+pub mod harness;
+pub mod importer;
+pub mod jest;
+pub mod keyring;
+pub mod lint;
+pub mod list;
+pub mod lock;
+pub mod logs;
+pub mod merge;
+pub mod mocks;
+pub mod oauth;
+pub mod pair;
+pub mod postman;
+pub mod remote;
+pub mod sdfobject;
+pub mod sdfproject;
+pub mod stats;
+pub mod templates;
+pub mod tsproject;
+pub mod typescript;
+pub mod upload;
+pub mod wizard;
+use assets::entry_points::{
+    EntryPoint, BUNDLE_INSTALLATION, CLIENT, LIBRARY, MASS_UPDATE, PORTLET, RESTLET, SCHEDULED,
+    SCHEDULED_GOVERNANCE, SDF_INSTALLATION, SUITECOMMERCE, SUITELET, SUITELET_FORM, SUITELET_PAIR,
+    USER_EVENT, WORKFLOW_ACTION,
+};
+use assets::governance::COSTS;
+use assets::netsuite_types::{
+    API, DEPLOYMENT_STATUSES, LOG_LEVELS, MODULES, RECORD_TYPES, SCOPES, SUFFIXES,
+    SUITECOMMERCE_UNSUPPORTED, TYPES,
+};
+use assets::plugins::CUSTOM_GL;
+use assets::recipes::{Recipe, RECIPES};
+pub use builder::{Module, ScriptBuilder, ScriptType};
+pub use error::SuiteScriptError;
+
+/// Opening marker of a region whose contents are preserved when a script is regenerated.
+const KEEP_START: &str = "// <suitescript:keep";
+
+/// Closing marker of a region whose contents are preserved when a script is regenerated.
+const KEEP_END: &str = "// </suitescript:keep>";
+
+/// Marker of the comment recording how a script was generated.
+const PROVENANCE: &str = "suitescript:provenance";
+
+/// Identifier of the built-in template scripts are generated from.
+const TEMPLATE: &str = "default";
+
+/// Built-in template scripts are generated from, unless a template file is given.
+///
+/// The template is rendered by [`render_template`].
+const DEFAULT_TEMPLATE: &str = "{{tscheck}}{{header}}{{provenance}}{{define}}{{body}}});";
+
+/// Variables of templates, in the order they are described by [`render_template`].
+const TEMPLATE_VARIABLES: [&str; 9] = [
+    "tscheck",
+    "header",
+    "provenance",
+    "define",
+    "body",
+    "file",
+    "scripttype",
+    "apiversion",
+    "modules",
+];
+
+/// File extensions accepted for copyright files, in addition to files without an extension.
+const COPYRIGHT_EXTENSIONS: [&str; 3] = ["txt", "md", "js"];
+
+/// Options of a script to generate.
+///
+/// The options can be parsed from command line arguments, built with a [`ScriptBuilder`], or built
+/// with [`Default`] and struct update syntax.
+#[derive(StructOpt, Debug, Clone)]
+pub struct ScriptSpec {
+    /// Name of the file to be generated
+    #[structopt(short, long = "filename", parse(from_os_str), validator = validate_file_name, required_unless_one = &["header-only", "interactive"])]
+    pub file_name: Option<PathBuf>,
+
+    /// Type of `SuiteScript` to be generated, inferred from a file name suffix such as `_ue` if omitted
+    #[structopt(short, long = "scripttype", default_value = "", validator = validate_script_type)]
+    pub script_type: String,
+
+    /// Version of the `SuiteScript` API to use
+    #[structopt(short, long = "apiversion", default_value = "2.1", validator = validate_api_version)]
+    pub api_version: String,
+
+    /// `SuiteScript` modules to import, or `-` to read them from stdin
+    #[structopt(short, long = "modules", default_value = "", validator = validate_modules)]
+    pub modules: Vec<String>,
+
+    /// Recipes of boilerplate to add to the module body, importing the modules they need
+    #[structopt(short, long = "recipes", validator = validate_recipe)]
+    pub recipes: Vec<String>,
+
+    /// Path to a file containing your company's copyright message
+    #[structopt(short, long = "copyright", parse(from_os_str), default_value = "", validator = validate_copyright_file)]
+    pub copyright: PathBuf,
+
+    /// Copyright message to use when no copyright file is given, with `\n` for line breaks
+    #[structopt(long = "copyright-text", env = "SUITESCRIPT_COPYRIGHT")]
+    pub copyright_text: Option<String>,
+
+    /// Path to a Handlebars template of the script, with variables such as `{{header}}` and `{{body}}`
+    #[structopt(long = "template", parse(from_os_str), validator = validate_template)]
+    pub template: Option<PathBuf>,
+
+    /// Directory of templates named after script types, such as `userevent.hbs`, used when no
+    /// template is given, `~/.config/suitescript/templates` if omitted
+    #[structopt(
+        long = "templates-dir",
+        parse(from_os_str),
+        env = "SUITESCRIPT_TEMPLATES_DIR"
+    )]
+    pub templates_dir: Option<PathBuf>,
+
+    /// Prompt for the file name, script type, API version, and modules, validating each answer
+    #[structopt(short, long = "interactive", conflicts_with_all = &["header-only", "stdout"])]
+    pub interactive: bool,
+
+    /// Skip the copyright and `JSDoc` tags, generating only the module definition
+    #[structopt(long = "no-header")]
+    pub no_header: bool,
+
+    /// Print only the copyright and `JSDoc` tags to stdout instead of generating a file
+    #[structopt(long = "header-only", conflicts_with = "no-header")]
+    pub header_only: bool,
+
+    /// Print the generated script to stdout instead of creating a file
+    #[structopt(long = "stdout", conflicts_with = "header-only")]
+    pub stdout: bool,
+
+    /// Print the files that would be written and their contents, without writing them
+    #[structopt(long = "dry-run", conflicts_with_all = &["header-only", "stdout"])]
+    pub dry_run: bool,
+
+    /// Print the files created, the script type, modules, and warnings as JSON, and errors as JSON
+    /// on stderr, for tools that wrap the CLI
+    #[structopt(long = "json", conflicts_with_all = &["header-only", "stdout", "dry-run", "interactive"])]
+    pub json: bool,
+
+    /// Print the files that would be created as JSON, with the template, header tags, and modules
+    /// of the script, without writing them
+    #[structopt(long = "print-plan", conflicts_with_all = &["header-only", "stdout", "dry-run", "json", "interactive"])]
+    pub print_plan: bool,
+
+    /// Copy existing files to a timestamped backup, such as `customer_ue.js.bak-20240101T1200`,
+    /// before they are overwritten
+    #[structopt(long = "backup")]
+    pub backup: bool,
+
+    /// Drop the keep regions of an existing script that are no longer generated, such as the body
+    /// of an entry point removed from --events, instead of refusing to regenerate it
+    #[structopt(long = "force")]
+    pub force: bool,
+
+    /// Record the files written in the lock manifest of the project, `suitescript.lock`, so that
+    /// edits to them are merged when they are regenerated. Projects with a lock manifest always
+    /// record them
+    #[structopt(long = "lock")]
+    pub lock: bool,
+
+    /// Create the parent directories of the script if they are missing
+    #[structopt(long = "create-dirs")]
+    pub create_dirs: bool,
+
+    /// Enable `TypeScript` checking of the script with `// @ts-check` and typed module imports
+    #[structopt(long = "ts-check")]
+    pub ts_check: bool,
+
+    /// Language of the script, `js` or `ts`, inferred from the extension of the file name if omitted
+    #[structopt(long = "lang", default_value = "", validator = validate_lang)]
+    pub lang: String,
+
+    /// Generate a Node test harness and payload fixtures alongside a RESTlet
+    #[structopt(long = "with-harness", conflicts_with_all = &["header-only", "stdout"])]
+    pub with_harness: bool,
+
+    /// Generate a Jest test of the entry points of the script in `__tests__`, with its modules mocked
+    #[structopt(long = "with-tests", conflicts_with_all = &["header-only", "stdout"])]
+    pub with_tests: bool,
+
+    /// Generate a Postman collection describing the methods of a RESTlet alongside it
+    #[structopt(long = "postman", conflicts_with_all = &["header-only", "stdout"])]
+    pub postman: bool,
+
+    /// Generate a client script for the form of a Suitelet, or for the record of a user event with a
+    /// constants module of field IDs shared by both scripts, named after it with a `_cs` suffix
+    #[structopt(long = "pair", conflicts_with_all = &["header-only", "stdout"])]
+    pub pair: bool,
+
+    /// Record type customized by a user event generated with `--pair`, written in the constants
+    /// module, inferred from the file name if omitted
+    #[structopt(long = "record", requires = "pair", validator = validate_record)]
+    pub record: Option<String>,
+
+    /// Generate the SDF object of the script record in the `Objects` directory of the project, named
+    /// after the script with a `customscript_` prefix
+    #[structopt(long = "sdf-object", conflicts_with_all = &["header-only", "stdout"])]
+    pub sdf_object: bool,
+
+    /// ID of the script record in the SDF object, such as `customscript_customer_ue`, derived from
+    /// the file name if omitted
+    #[structopt(long = "script-id", requires = "sdf-object", validator = validate_script_id)]
+    pub script_id: Option<String>,
+
+    /// Deploy the script with the `suitecloud` CLI once it is generated, with `project:deploy` if it
+    /// has an SDF object and `file:upload` otherwise
+    #[structopt(long = "deploy", conflicts_with_all = &["header-only", "stdout", "dry-run", "json", "print-plan"])]
+    pub deploy: bool,
+
+    /// Record types a user event or client script is deployed to, with a deployment in the SDF
+    /// object for each
+    #[structopt(long = "deployments", use_delimiter = true, requires = "sdf-object", validator = validate_record)]
+    pub deployments: Vec<String>,
+
+    /// Status of the deployments, `testing` or `released`, `testing` if omitted
+    #[structopt(long = "deploy-status", default_value = "", validator = validate_deploy_status)]
+    pub deploy_status: String,
+
+    /// Log level of the deployments, `debug`, `audit`, `error`, or `emergency`, `debug` if omitted
+    #[structopt(long = "log-level", default_value = "", validator = validate_log_level)]
+    pub log_level: String,
+
+    /// Roles the deployments are available to, such as `administrator` or `customrole_sales`, all
+    /// roles if omitted
+    #[structopt(long = "audience", use_delimiter = true, validator = validate_role)]
+    pub audience: Vec<String>,
+
+    /// Internal IDs of the departments the deployments are available to, such as `3`, all
+    /// departments if omitted
+    #[structopt(long = "departments", use_delimiter = true, validator = validate_department)]
+    pub departments: Vec<String>,
+
+    /// User event entry points to generate, all of them if omitted
+    #[structopt(long = "events", use_delimiter = true, validator = validate_event)]
+    pub events: Vec<String>,
+
+    /// Client entry points to generate, all of them if omitted
+    #[structopt(long = "entries", use_delimiter = true, validator = validate_entry, conflicts_with = "suitecommerce")]
+    pub entries: Vec<String>,
+
+    /// RESTlet methods to handle, all of them if omitted
+    #[structopt(long = "methods", use_delimiter = true, validator = validate_method)]
+    pub methods: Vec<String>,
+
+    /// Type of content rendered by a portlet, `html` if omitted
+    #[structopt(long = "portlet-type", default_value = "", validator = validate_portlet_type)]
+    pub portlet_type: String,
+
+    /// Scope of the module, written in the `@NModuleScope` tag
+    #[structopt(long = "scope", default_value = "", validator = validate_scope)]
+    pub scope: String,
+
+    /// Path of the AMD config of the script, written in the `@NAmdConfig` tag and created if missing
+    #[structopt(long = "amdconfig", parse(from_os_str), validator = validate_amd_config)]
+    pub amd_config: Option<PathBuf>,
+
+    /// Author of the script, written in the `@author` tag, or the git user name if no value is given
+    #[structopt(long = "author", env = "SUITESCRIPT_AUTHOR")]
+    pub author: Option<Option<String>>,
+
+    /// Description of the script, written in the `@description` tag
+    #[structopt(long = "description", env = "SUITESCRIPT_DESCRIPTION")]
+    pub description: Option<String>,
+
+    /// Related scripts, written in `@see` tags, such as the client script paired with a Suitelet
+    #[structopt(skip)]
+    pub see: Vec<String>,
+
+    /// Local modules to import, as paths relative to the script without their extension, such as
+    /// the constants module shared by a user event and its client script
+    #[structopt(skip)]
+    pub dependencies: Vec<String>,
+
+    /// Regular expression the file name must match, set by the `filename-pattern` option of a
+    /// config to enforce the naming convention of a project
+    #[structopt(skip)]
+    pub file_name_pattern: Option<String>,
+
+    /// Description of the naming convention, set by the `filename-convention` option of a config
+    /// and shown when a file name does not match the pattern
+    #[structopt(skip)]
+    pub file_name_convention: Option<String>,
+
+    /// File name suffixes of script types, as pairs of a suffix and a script type, set by the
+    /// `suffixes` table of a config in place of the default suffix of each script type
+    #[structopt(skip)]
+    pub suffixes: Vec<(String, String)>,
+
+    /// Copyright files of directories, as pairs of a directory and a copyright file, set by the
+    /// `copyrights` table of a config for the scripts written in each directory
+    #[structopt(skip)]
+    pub copyrights: Vec<(PathBuf, PathBuf)>,
+
+    /// Record types accepted by `--record` and `--deployments` in addition to the standard and
+    /// custom record types, set by `record-types` in a config
+    #[structopt(skip)]
+    pub record_types: Vec<String>,
+
+    /// Indentation of the entries of `define` dependency arrays written by `format`, a number of
+    /// spaces or `tab`, set by `indent` in a config, 2 spaces if `None`
+    #[structopt(skip)]
+    pub indent: Option<String>,
+
+    /// Quotes of the entries of `define` dependency arrays written by `format`, `single` or
+    /// `double`, set by `quotes` in a config, single quotes if `None`
+    #[structopt(skip)]
+    pub quotes: Option<String>,
+
+    /// Whether `format` aligns the values of the `@N` tags of header blocks in a column, set by
+    /// `align-tags` in a config
+    #[structopt(skip)]
+    pub align_tags: bool,
+
+    /// Company name substituted for `{{company}}` in the copyright message
+    #[structopt(long = "company", env = "SUITESCRIPT_COMPANY", default_value = "")]
+    pub company: String,
+
+    /// Generate a client script for SuiteCommerce websites, with an extension entry point
+    #[structopt(long = "suitecommerce")]
+    pub suitecommerce: bool,
+
+    /// Embed a comment identifying the generator version, template, and options of the script
+    #[structopt(long = "provenance")]
+    pub provenance: bool,
+
+    /// Fix the order of generated content so the same options always produce identical output
+    #[structopt(long = "reproducible")]
+    pub reproducible: bool,
+}
+
+impl Default for ScriptSpec {
+    fn default() -> Self {
+        ScriptSpec {
+            file_name: None,
+            script_type: String::new(),
+            api_version: String::from("2.1"),
+            modules: Vec::new(),
+            recipes: Vec::new(),
+            copyright: PathBuf::new(),
+            copyright_text: None,
+            template: None,
+            templates_dir: None,
+            interactive: false,
+            no_header: false,
+            header_only: false,
+            stdout: false,
+            dry_run: false,
+            json: false,
+            print_plan: false,
+            backup: false,
+            force: false,
+            lock: false,
+            create_dirs: false,
+            ts_check: false,
+            lang: String::new(),
+            with_harness: false,
+            with_tests: false,
+            postman: false,
+            pair: false,
+            record: None,
+            sdf_object: false,
+            script_id: None,
+            deploy: false,
+            deployments: Vec::new(),
+            deploy_status: String::new(),
+            log_level: String::new(),
+            audience: Vec::new(),
+            departments: Vec::new(),
+            events: Vec::new(),
+            entries: Vec::new(),
+            methods: Vec::new(),
+            portlet_type: String::new(),
+            scope: String::new(),
+            amd_config: None,
+            author: None,
+            description: None,
+            see: Vec::new(),
+            dependencies: Vec::new(),
+            file_name_pattern: None,
+            file_name_convention: None,
+            suffixes: Vec::new(),
+            copyrights: Vec::new(),
+            record_types: Vec::new(),
+            indent: None,
+            quotes: None,
+            align_tags: false,
+            company: String::new(),
+            suitecommerce: false,
+            provenance: false,
+            reproducible: false,
+        }
+    }
+}
+
+/// A message about a change made to the options of a script, or a possible problem with them.
+#[derive(Debug, PartialEq)]
+pub enum Notice {
+    /// A change made to the options, such as a module added for a recipe.
+    Note(String),
+    /// A possible problem with the options, such as a script type inferred from the file name.
+    Warning(String),
+}
+
+impl fmt::Display for Notice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Notice::Note(message) => write!(f, "note: {}", message),
+            Notice::Warning(message) => write!(f, "warning: {}", message),
+        }
+    }
+}
+
+/// A file written by the `new` command, such as a script or a test harness generated alongside it.
+#[derive(Debug, PartialEq)]
+pub struct OutputFile {
+    /// Path to write the file to
+    pub path: PathBuf,
+    /// Contents of the file
+    pub contents: String,
+}
+
+/// Generates the contents of a script.
+///
+/// Resolves the options with [`prepare`], discarding the notices, and renders the script.
+///
+/// # Errors
+/// Returns a validation error for the first invalid option, and an IO or template error if the
+/// copyright or template file cannot be read
+pub fn generate(spec: &ScriptSpec) -> Result<String, SuiteScriptError> {
+    let (config, _) = prepare(spec)?;
+    render(&config)
+}
+
+/// Resolves the options of a script before it is rendered.
+///
+/// Adds the modules needed by the recipes, sorts the modules of reproducible scripts, infers the
+/// script type from the file name, and looks up the git user name for an `--author` without a
+/// value. Returns the resolved options, with a notice for each change made.
+///
+/// # Errors
+/// Returns a validation error for the first invalid option, or for options that conflict
+pub fn prepare(spec: &ScriptSpec) -> Result<(ScriptSpec, Vec<Notice>), SuiteScriptError> {
+    validate_spec(spec)?;
+    let mut config = spec.clone();
+    let mut notices = Vec::new();
+
+    let recipes = get_recipes(&config);
+    let (modules, added) = add_recipe_modules(config.modules, &recipes);
+    config.modules = modules;
+    notices.extend(added);
+    if config.reproducible {
+        config.modules = sort_modules(config.modules);
+    }
+    if config.script_type.is_empty() {
+        let suffixes = get_suffixes(&config);
+        let inferred = config
+            .file_name
+            .as_deref()
+            .and_then(|file_name| infer_script_type(file_name, &suffixes));
+        if let Some(script_type) = inferred {
+            notices.push(Notice::Warning(format!(
+                "inferred script type `{}` from the file name",
+                script_type
+            )));
+            config.script_type = script_type;
+        }
+    }
+
+    if config.pair && map_script_to_name(&config.script_type) == "Suitelet" {
+        if let Some(script) = &config.file_name {
+            config.see.push(format!(
+                "./{} Client script attached to the form",
+                get_file_name(&pair::get_client_path(script))
+            ));
+        }
+        if !config
+            .modules
+            .iter()
+            .any(|module| module.to_lowercase() == "ui/serverwidget")
+        {
+            notices.push(Notice::Note(String::from(
+                "added module `ui/serverwidget` needed by the form of --pair",
+            )));
+            config.modules.retain(|name| !name.is_empty());
+            config.modules.push(String::from("ui/serverwidget"));
+        }
+    }
+    if config.pair && map_script_to_name(&config.script_type) == "UserEvent" {
+        if let Some(script) = &config.file_name {
+            let constants = pair::get_constants_path(script);
+            config.see.push(format!(
+                "./{} Client script of the record",
+                get_file_name(&pair::get_client_path(script))
+            ));
+            config.see.push(format!(
+                "./{} Field IDs shared with the client script",
+                get_file_name(&constants)
+            ));
+            config.dependencies.push(pair::get_dependency(&constants));
+            if config.record.is_none() {
+                let record = pair::get_base_name(script).to_lowercase();
+                notices.push(Notice::Warning(format!(
+                    "inferred record type `{}` from the file name",
+                    record
+                )));
+                config.record = Some(record);
+            }
+        }
+    }
+
+    if config.suitecommerce {
+        if config.script_type.is_empty() {
+            config.script_type = String::from("client");
+        }
+        for module in get_suitecommerce_unsupported(&config.modules) {
+            notices.push(Notice::Warning(format!(
+                "module `{}` is not supported in SuiteCommerce website contexts",
+                module
+            )));
+        }
+    }
+
+    if config.template.is_none() && map_script_to_name(&config.script_type) != "CustomGL" {
+        let dir = config
+            .templates_dir
+            .clone()
+            .or_else(|| config::user_dir().map(|dir| dir.join("templates")));
+        let typescript = typescript::is_typescript(&config);
+        if let Some(template) =
+            dir.and_then(|dir| find_type_template(&dir, &config.script_type, typescript))
+        {
+            notices.push(Notice::Note(format!(
+                "using template {}",
+                template.display()
+            )));
+            config.template = Some(template);
+        }
+    }
+
+    if let Some(None) = config.author {
+        let name = get_git_user_name();
+        if name.is_none() {
+            notices.push(Notice::Warning(String::from(
+                "no user.name in git config, skipped the @author tag",
+            )));
+        }
+        config.author = Some(name);
+    }
+
+    if config.modules.is_empty() {
+        config.modules = vec![String::new()];
+    }
+
+    check_conflicts(&config)?;
+    Ok((config, notices))
+}
+
+/// Validates the options of a script the same way as when they are parsed from arguments.
+fn validate_spec(config: &ScriptSpec) -> Result<(), String> {
+    if !config.script_type.is_empty() {
+        validate_script_type(config.script_type.clone())?;
+    }
+    validate_api_version(config.api_version.clone())?;
+    validate_portlet_type(config.portlet_type.clone())?;
+    validate_scope(config.scope.clone())?;
+    validate_lang(config.lang.clone())?;
+    validate_deploy_status(config.deploy_status.clone())?;
+    validate_log_level(config.log_level.clone())?;
+    for module in &config.modules {
+        validate_modules(module.clone())?;
+    }
+    for (names, validate) in &[
+        (
+            &config.recipes,
+            validate_recipe as fn(String) -> Result<(), String>,
+        ),
+        (&config.events, validate_event),
+        (&config.entries, validate_entry),
+        (&config.methods, validate_method),
+        (&config.deployments, validate_record),
+        (&config.audience, validate_role),
+        (&config.departments, validate_department),
+    ] {
+        for name in names.iter() {
+            validate(name.clone())?;
+        }
+    }
+    if let Some(path) = &config.amd_config {
+        validate_amd_config(path.display().to_string())?;
+    }
+    if let Some(path) = &config.template {
+        validate_template(path.display().to_string())?;
+    }
+    if let Some(record) = &config.record {
+        validate_record(record.clone())?;
+    }
+    for record in config.record.iter().chain(&config.deployments) {
+        validate_record_type(config, record)?;
+    }
+    if let Some(script_id) = &config.script_id {
+        validate_script_id(script_id.clone())?;
+    }
+    if let Some(file_name) = &config.file_name {
+        validate_naming_convention(config, file_name)?;
+    }
+    Ok(())
+}
+
+/// Checks that the options used apply to the script type, and to each other.
+fn check_conflicts(config: &ScriptSpec) -> Result<(), String> {
+    let script_type = map_script_to_name(&config.script_type);
+    if config.suitecommerce && script_type != "Client" {
+        return Err(String::from(
+            "--suitecommerce requires the client script type",
+        ));
+    }
+
+    if !config.portlet_type.is_empty() && script_type != "Portlet" {
+        return Err(String::from(
+            "--portlet-type requires the portlet script type",
+        ));
+    }
+
+    if script_type == "CustomGL"
+        && (config.modules.iter().any(|module| !module.is_empty())
+            || !config.recipes.is_empty()
+            || config.ts_check)
+    {
+        return Err(String::from(
+            "Custom GL plugins cannot import modules, use recipes, or use --ts-check",
+        ));
+    }
+
+    if script_type == "CustomGL" && config.template.is_some() {
+        return Err(String::from("Custom GL plugins cannot use --template"));
+    }
+
+    let extension = config
+        .file_name
+        .as_deref()
+        .and_then(Path::extension)
+        .and_then(|extension| extension.to_str());
+    if let Some(extension) =
+        extension.filter(|extension| !config.lang.is_empty() && *extension != config.lang)
+    {
+        return Err(format!(
+            "--lang {} does not match the .{} extension of the file name",
+            config.lang, extension
+        ));
+    }
+
+    if typescript::is_typescript(config) {
+        for (invalid, message) in &[
+            (
+                script_type == "CustomGL",
+                "Custom GL plugins cannot be TypeScript",
+            ),
+            (
+                config.ts_check,
+                "--ts-check does not apply to TypeScript scripts",
+            ),
+            (
+                config.with_harness,
+                "--with-harness does not support TypeScript scripts",
+            ),
+            (
+                config.with_tests,
+                "--with-tests does not support TypeScript scripts",
+            ),
+            (
+                config.deploy,
+                "--deploy does not support TypeScript scripts, which are deployed once transpiled",
+            ),
+        ] {
+            if *invalid {
+                return Err(String::from(*message));
+            }
+        }
+    }
+
+    for (selected, flag, required) in &[
+        (&config.events, "--events", "UserEvent"),
+        (&config.entries, "--entries", "Client"),
+        (&config.methods, "--methods", "RESTlet"),
+    ] {
+        if !selected.is_empty() && script_type != *required {
+            return Err(format!(
+                "{} requires the {} script type",
+                flag,
+                required.to_lowercase()
+            ));
+        }
+    }
+
+    if config.with_tests && get_entry_points(config).is_empty() {
+        return Err(String::from(
+            "--with-tests requires a script type with entry points",
+        ));
+    }
+
+    for (enabled, flag) in &[
+        (config.with_harness, "--with-harness"),
+        (config.postman, "--postman"),
+    ] {
+        if *enabled && script_type != "RESTlet" {
+            return Err(format!("{} requires the restlet script type", flag));
+        }
+    }
+
+    if config.pair && script_type != "Suitelet" && script_type != "UserEvent" {
+        return Err(String::from(
+            "--pair requires the suitelet or userevent script type",
+        ));
+    }
+
+    if config.sdf_object && sdfobject::get_record_type(&config.script_type).is_none() {
+        return Err(String::from(
+            "--sdf-object requires a script type with a script record",
+        ));
+    }
+
+    if config.script_id.is_some() && !config.sdf_object {
+        return Err(String::from("--script-id requires --sdf-object"));
+    }
+
+    if !config.deployments.is_empty() && !config.sdf_object {
+        return Err(String::from("--deployments requires --sdf-object"));
+    }
+
+    if !config.deployments.is_empty() && script_type != "UserEvent" && script_type != "Client" {
+        return Err(String::from(
+            "--deployments requires the userevent or client script type",
+        ));
+    }
+
+    if let Some(deployment_id) = config
+        .deployments
+        .iter()
+        .map(|record| sdfobject::get_deployment_id(&sdfobject::get_script_id(config), record))
+        .find(|deployment_id| deployment_id.len() > sdfobject::MAX_ID_LENGTH)
+    {
+        return Err(format!(
+            "Deployment ID {} is longer than {} characters, shorten the script ID with --script-id",
+            deployment_id,
+            sdfobject::MAX_ID_LENGTH
+        ));
+    }
+
+    for (set, flag) in &[
+        (!config.deploy_status.is_empty(), "--deploy-status"),
+        (!config.log_level.is_empty(), "--log-level"),
+        (!config.audience.is_empty(), "--audience"),
+        (!config.departments.is_empty(), "--departments"),
+    ] {
+        if *set && config.deployments.is_empty() {
+            return Err(format!("{} requires --deployments", flag));
+        }
+    }
+
+    if config.record.is_some() && (!config.pair || script_type != "UserEvent") {
+        return Err(String::from(
+            "--record requires --pair and the userevent script type",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Renders the contents of a script whose options were resolved with [`prepare`].
+///
+/// # Errors
+/// Returns an IO error if the copyright file cannot be read, and a template error if the template
+/// file cannot be read
+pub fn render(config: &ScriptSpec) -> Result<String, SuiteScriptError> {
+    let mut contents = Vec::new();
+    write_script(&mut contents, config)?;
+    Ok(String::from_utf8_lossy(&contents).into_owned())
+}
+
+/// Renders the header of a script whose options were resolved with [`prepare`].
+///
+/// # Errors
+/// Returns an IO error if the copyright file cannot be read
+pub fn render_header(config: &ScriptSpec) -> Result<String, SuiteScriptError> {
+    let mut header = Vec::new();
+    write_header(&mut header, config)?;
+    Ok(String::from_utf8_lossy(&header).into_owned())
+}
+
+/// Writes the full contents of the script into a writer.
+///
+/// Writes the `TypeScript` check directives, if enabled, the header, unless it is disabled, and the
+/// provenance comment, if enabled, followed by the AMD module definition. Custom GL plugins are
+/// written as the plugin function instead, since plugins are not AMD modules.
+fn write_script(out: &mut impl Write, config: &ScriptSpec) -> Result<(), SuiteScriptError> {
+    if map_script_to_name(&config.script_type) == "CustomGL" {
+        if !config.no_header {
+            write_header(out, config)?;
+        }
+        if config.provenance {
+            write!(out, "{}", get_provenance(config)?).map_err(write_error)?;
+        }
+        return write!(out, "{}", CUSTOM_GL).map_err(write_error);
+    }
+    render_template(out, &get_template(config)?, config)
+}
+
+/// Renders a script whose options were resolved with [`prepare`] into a writer, carrying the keep
+/// regions of `previous`, the existing contents of the script, over to it.
+///
+/// Returns the opening markers of the keep regions of `previous` that are not in the rendered
+/// script, which are dropped.
+///
+/// # Errors
+/// Returns an IO error if the copyright file cannot be read or the script cannot be written, and a
+/// template error if the template file cannot be read
+pub fn render_to(
+    out: &mut impl Write,
+    config: &ScriptSpec,
+    previous: Option<&str>,
+) -> Result<Vec<String>, SuiteScriptError> {
+    match previous {
+        Some(previous) => {
+            let mut merged = KeepRegionWriter::new(out, previous);
+            write_script(&mut merged, config)?;
+            merged.finish().map_err(write_error)
+        }
+        None => write_script(out, config).map(|()| Vec::new()),
+    }
+}
+
+/// Checks the keep regions of an existing script that a regenerated script drops, as returned by
+/// [`render_to`].
+///
+/// Returns a warning for each dropped region if `force` is set.
+///
+/// # Errors
+/// Returns a validation error listing the dropped regions if `force` is not set, so that the code
+/// in them is not lost
+pub fn check_dropped_keep_regions(
+    path: &Path,
+    dropped: &[String],
+    force: bool,
+) -> Result<Vec<Notice>, SuiteScriptError> {
+    if !force && !dropped.is_empty() {
+        return Err(SuiteScriptError::Validation(format!(
+            "{} has keep regions that are no longer generated: {}. Pass --force to drop them",
+            path.display(),
+            dropped.join(", ")
+        )));
+    }
+    Ok(dropped
+        .iter()
+        .map(|marker| {
+            Notice::Warning(format!(
+                "dropped the keep region `{}` of {}, which is no longer generated",
+                marker,
+                path.display()
+            ))
+        })
+        .collect())
+}
+
+/// Reads the existing contents of a script if it has keep regions to carry over to its regenerated
+/// contents.
+pub fn read_kept_script(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .filter(|previous| !get_keep_regions(previous).is_empty())
+}
+
+/// Renders a script whose options were resolved with [`prepare`] straight into the temporary file
+/// of an atomic write, creating its parent directories if they are missing and keeping the regions
+/// marked to be kept in the existing file, if any. The existing file is backed up if `--backup` is
+/// set, once the script is rendered.
+///
+/// With a lock manifest, the rendered script is also written as the generated contents of the
+/// file, and the edits made to the file since it was last generated are merged with the rendered
+/// script, with conflict markers where they cannot be, as described by [`lock::Lock::merge`].
+///
+/// Returns notes about the backup and the merge, warnings about the keep regions dropped with
+/// `--force`, as described by [`check_dropped_keep_regions`], and the record of the file to add to
+/// the lock manifest, if any.
+///
+/// # Errors
+/// Returns an IO error if the copyright file cannot be read or the file cannot be written, a
+/// template error if the template file cannot be read, and a validation error if keep regions
+/// would be dropped without `--force`, in which case the file is left untouched
+pub fn write_script_file(
+    config: &ScriptSpec,
+    path: &Path,
+    lock: Option<&lock::Lock>,
+) -> Result<(Vec<Notice>, Option<lock::LockedFile>), SuiteScriptError> {
+    create_parent_dirs(path)?;
+    let previous = read_kept_script(path);
+    let check = |dropped: &[String]| -> Result<Vec<Notice>, SuiteScriptError> {
+        let mut notices = check_dropped_keep_regions(path, dropped, config.force)?;
+        if config.backup && path.is_file() {
+            let backup = backup_file(path)?;
+            notices.insert(0, describe_backup(path, &backup));
+        }
+        Ok(notices)
+    };
+
+    if let Some(lock) = lock.filter(|lock| lock.is_edited(path)) {
+        let mut regenerated = Vec::new();
+        let dropped = render_to(&mut regenerated, config, previous.as_deref())?;
+        let regenerated = String::from_utf8_lossy(&regenerated).into_owned();
+        let (merged, notice) = lock
+            .merge(path, &regenerated, config.force)?
+            .expect("Edited files are merged");
+        let mut notices = check(&dropped)?;
+        write_atomically(path, &merged)?;
+        notices.push(notice);
+        return Ok((notices, Some(lock.write_generated(path, &regenerated)?)));
+    }
+
+    let mut notices = Vec::new();
+    let mut locked = None;
+    write_atomically_with(path, |out| match lock {
+        Some(lock) => {
+            locked = Some(lock.write_generated_with(path, |generated| {
+                let mut both = lock::TeeWriter {
+                    first: &mut *out,
+                    second: generated,
+                };
+                let dropped = render_to(&mut both, config, previous.as_deref())?;
+                notices = check(&dropped)?;
+                Ok(())
+            })?);
+            Ok(())
+        }
+        None => {
+            let dropped = render_to(out, config, previous.as_deref())?;
+            notices = check(&dropped)?;
+            Ok(())
+        }
+    })?;
+    Ok((notices, locked))
+}
+
+/// Writes a file generated alongside a script, recording it in the lock manifest, if any, as
+/// [`write_script_file`] does for the script.
+///
+/// # Errors
+/// Returns an IO error if the file cannot be written, and a validation error if the file has
+/// conflict markers of a previous regeneration, unless `force` is set
+pub fn write_companion_file(
+    file: &OutputFile,
+    lock: Option<&lock::Lock>,
+    force: bool,
+) -> Result<(Vec<Notice>, Option<lock::LockedFile>), SuiteScriptError> {
+    let lock = match lock {
+        Some(lock) => lock,
+        None => return write_output_file(file).map(|()| (Vec::new(), None)),
+    };
+    let merged = lock.merge(&file.path, &file.contents, force)?;
+    let notices = match merged {
+        Some((contents, notice)) => {
+            write_output_file(&OutputFile {
+                path: file.path.clone(),
+                contents,
+            })?;
+            vec![notice]
+        }
+        None => {
+            write_output_file(file)?;
+            Vec::new()
+        }
+    };
+    Ok((
+        notices,
+        Some(lock.write_generated(&file.path, &file.contents)?),
+    ))
+}
+
+/// Describes a failure to write the contents of a script.
+fn write_error(source: std::io::Error) -> SuiteScriptError {
+    SuiteScriptError::io("Failed to write script", source)
+}
+
+/// Gets the template of the script, read from the template file if one is given.
+///
+/// `TypeScript` scripts use a built-in template without the closing of the module definition.
+/// Template files are checked by [`templates::lint_template`] when they are read, and templates
+/// given as HTTPS URLs are fetched by [`remote::fetch`].
+///
+/// # Errors
+/// Returns a template error if the template file cannot be read or has problems, and an error if
+/// a template URL cannot be fetched
+fn get_template(config: &ScriptSpec) -> Result<String, SuiteScriptError> {
+    match &config.template {
+        Some(path) => {
+            let template = std::fs::read_to_string(get_local_path(path)?)
+                .map(|template| template.replace("\r\n", "\n"))
+                .map_err(|source| SuiteScriptError::Template {
+                    path: path.clone(),
+                    source,
+                })?;
+            templates::check_template(path, &template)?;
+            Ok(template)
+        }
+        None if typescript::is_typescript(config) => Ok(String::from(typescript::TEMPLATE)),
+        None => Ok(String::from(DEFAULT_TEMPLATE)),
+    }
+}
+
+/// Finds the template of a script type in a templates directory.
+///
+/// The template is named after the script type in lowercase, such as `userevent.hbs` for user event
+/// scripts, or `userevent.ts.hbs` for `TypeScript` scripts. Templates with the `.tpl` extension,
+/// such as `userevent.tpl`, are found too, after the `.hbs` ones. Returns `None` if the script type
+/// is not set or has no template in the directory.
+fn find_type_template(dir: &Path, script_type: &str, typescript: bool) -> Option<PathBuf> {
+    let name = map_script_to_name(script_type);
+    if name.is_empty() {
+        return None;
+    }
+
+    let extensions = if typescript {
+        ["ts.hbs", "ts.tpl"]
+    } else {
+        ["hbs", "tpl"]
+    };
+    extensions
+        .iter()
+        .map(|extension| dir.join(format!("{}.{}", name.to_lowercase(), extension)))
+        .find(|path| path.is_file())
+}
+
+/// Renders a template with the parts of the script, as a Handlebars template.
+///
+/// The parts are `{{tscheck}}`, `{{header}}`, `{{provenance}}`, `{{define}}` (the opening of the
+/// module definition, with its dependencies and arguments), and `{{body}}`, each empty when the
+/// options skip it, so that a template can test them with `{{#if provenance}}`. The `{{file}}`,
+/// `{{scripttype}}`, and `{{apiversion}}` variables are the file name, script type, and API
+/// version of the script, and `{{modules}}` lists the `N/` modules it imports. In `TypeScript`
+/// scripts, `{{define}}` is the `import` statements and `{{body}}` exports the entry points.
+/// Values are not escaped, since scripts are not HTML, and unknown variables render empty.
+///
+/// # Errors
+/// Returns an IO error if the copyright file cannot be read, and a template error if the template
+/// is not a valid Handlebars template
+fn render_template(
+    out: &mut impl Write,
+    template: &str,
+    config: &ScriptSpec,
+) -> Result<(), SuiteScriptError> {
+    let data = get_template_data(config)?;
+    let path = config.template.as_deref().unwrap_or(Path::new(TEMPLATE));
+    render_handlebars_to(out, template, &data, path)
+}
+
+/// Gets the variables of the template of a script, as described by [`render_template`].
+///
+/// # Errors
+/// Returns an IO error if the copyright file cannot be read
+fn get_template_data(config: &ScriptSpec) -> Result<serde_json::Value, SuiteScriptError> {
+    let ts_check = if config.ts_check {
+        get_ts_check(&config.modules)
+    } else {
+        String::new()
+    };
+    let header = if config.no_header {
+        String::new()
+    } else {
+        render_header(config)?
+    };
+    let provenance = if config.provenance {
+        get_provenance(config)?
+    } else {
+        String::new()
+    };
+    let file = config
+        .file_name
+        .as_deref()
+        .map(get_file_name)
+        .unwrap_or_default();
+    let recipes = get_recipes(config);
+    let entry_points = get_entry_points(config);
+    let (define, body) = if typescript::is_typescript(config) {
+        (
+            typescript::get_imports(config, &entry_points),
+            typescript::get_body(config, &recipes, &entry_points),
+        )
+    } else {
+        (
+            format!(
+                "define([\n{}",
+                get_modules(&config.modules, &config.dependencies, config.ts_check)
+            ),
+            get_body(&recipes, &entry_points),
+        )
+    };
+    // The form loads the client script as JavaScript, so a `TypeScript` client is referred to by
+    // the path of its transpiled file
+    let body = match (config.pair, config.file_name.as_deref()) {
+        (true, Some(script)) => body.replace(
+            "{client}",
+            &format!(
+                "./{}",
+                get_file_name(&pair::get_client_path(script).with_extension("js"))
+            ),
+        ),
+        _ => body,
+    };
+
+    let modules: Vec<String> = get_module_names(&config.modules)
+        .iter()
+        .filter(|name| !name.is_empty())
+        .map(|name| format!("N/{}", get_module_path(name)))
+        .collect();
+    Ok(serde_json::json!({
+        "tscheck": ts_check,
+        "header": header,
+        "provenance": provenance,
+        "define": define,
+        "body": body,
+        "file": file,
+        "scripttype": map_script_to_name(&config.script_type),
+        "apiversion": get_api_version(&config.api_version),
+        "modules": modules,
+    }))
+}
+
+/// Renders a Handlebars template with variables, which are not escaped.
+///
+/// # Errors
+/// Returns a template error naming the path of the template if it is not a valid Handlebars
+/// template
+fn render_handlebars(
+    template: &str,
+    data: &serde_json::Value,
+    path: &Path,
+) -> Result<String, SuiteScriptError> {
+    let mut contents = Vec::new();
+    render_handlebars_to(&mut contents, template, data, path)?;
+    Ok(String::from_utf8_lossy(&contents).into_owned())
+}
+
+/// Renders a Handlebars template with variables, which are not escaped, into a writer.
+///
+/// # Errors
+/// Returns a template error naming the path of the template if it is not a valid Handlebars
+/// template, and an IO error if the writer fails
+fn render_handlebars_to(
+    out: &mut impl Write,
+    template: &str,
+    data: &serde_json::Value,
+    path: &Path,
+) -> Result<(), SuiteScriptError> {
+    let mut engine = Handlebars::new();
+    engine.register_escape_fn(handlebars::no_escape);
+    engine
+        .render_template_to_write(template, data, out)
+        .map_err(|error| match error.reason() {
+            handlebars::RenderErrorReason::IOError(source) => {
+                write_error(std::io::Error::new(source.kind(), source.to_string()))
+            }
+            _ => SuiteScriptError::Template {
+                path: path.to_owned(),
+                source: std::io::Error::new(std::io::ErrorKind::InvalidData, error),
+            },
+        })
+}
+
+/// Writes the header of the script into a writer.
+///
+/// The header is the copyright message, if any, followed by the `JSDoc` block with the script type,
+/// portlet type, API version, module scope, AMD config, author, description, and see tags. Custom
+/// GL plugins have no tags, so their header is only the copyright message.
+fn write_header(out: &mut impl Write, config: &ScriptSpec) -> Result<(), SuiteScriptError> {
+    if map_script_to_name(&config.script_type) == "CustomGL" {
+        return write!(out, "{}", get_copyright(config)?).map_err(write_error);
+    }
+    write!(
+        out,
+        "{}/**\n{}{}{}{}{}{}{}{} */\n\n",
+        get_copyright(config)?,
+        get_script_type(config.script_type.as_ref()),
+        format_tag("NScriptPortletType", get_portlet_type(config)),
+        format_tag(
+            "NApiVersion",
+            Some(get_api_version(config.api_version.as_ref()))
+        ),
+        format_tag("NModuleScope", get_scope(&config.scope)),
+        format_tag(
+            "NAmdConfig",
+            config
+                .amd_config
+                .as_ref()
+                .map(|path| path.display().to_string())
+        ),
+        format_tag("author", get_author(config)),
+        format_tag("description", config.description.clone()),
+        config
+            .see
+            .iter()
+            .map(|see| format_tag("see", Some(see.clone())))
+            .collect::<String>(),
+    )
+    .map_err(write_error)
+}
+
+/// Gets the author of the script.
+fn get_author(config: &ScriptSpec) -> Option<String> {
+    config.author.clone().flatten()
+}
+
+/// Gets the `user.name` from git config, if git is installed and has a user name.
+fn get_git_user_name() -> Option<String> {
+    std::process::Command::new("git")
+        .args(["config", "user.name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+        .filter(|name| !name.is_empty())
+}
+
+/// Formats a line of the `JSDoc` block with a tag and its value.
+///
+/// Returns an empty string if there is no value.
+fn format_tag(tag: &str, value: Option<String>) -> String {
+    value.map_or_else(String::new, |value| format!(" * @{} {}\n", tag, value))
+}
+
+/// Gets the supported `NetSuite` casing of a module scope.
+///
+/// Returns `None` if no scope is given.
+fn get_scope(scope: &str) -> Option<String> {
+    SCOPES
+        .iter()
+        .find(|name| name.to_lowercase() == scope.to_lowercase())
+        .map(|name| (*name).to_owned())
+}
+
+/// Gets the portlet type of a portlet script, defaulting to `html`.
+///
+/// Returns `None` for other script types.
+fn get_portlet_type(config: &ScriptSpec) -> Option<String> {
+    if map_script_to_name(&config.script_type) != "Portlet" {
+        return None;
+    }
+
+    match config.portlet_type.to_lowercase().as_str() {
+        "" => Some(String::from("html")),
+        portlet_type => Some(portlet_type.to_owned()),
+    }
+}
+
+/// Writes the provenance comment of the script.
+///
+/// The comment records the generator version, the template, and a hash of the options the script
+/// was generated with, so that the files owned by the generator can be identified.
+fn get_provenance(config: &ScriptSpec) -> Result<String, SuiteScriptError> {
+    Ok(format!(
+        "// {} version={} template={} options={:016x}\n\n",
+        PROVENANCE,
+        env!("CARGO_PKG_VERSION"),
+        config
+            .template
+            .as_ref()
+            .map_or_else(|| String::from(TEMPLATE), |path| path.display().to_string()),
+        hash_options(config)?
+    ))
+}
+
+/// Hashes the options that affect the contents of a script.
+///
+/// Uses 64-bit FNV-1a over the resolved options, so that the hash is stable across platforms and
+/// Rust versions. The copyright message and the contents of a template file are hashed rather than
+/// their paths.
+fn hash_options(config: &ScriptSpec) -> Result<u64, SuiteScriptError> {
+    let mut options = format!(
+        "scripttype={}\napiversion={}\nmodules={}\nrecipes={}\ncopyright={}\nno-header={}\nts-check={}\nsuitecommerce={}\nportlettype={}\nscope={}\namdconfig={}\nauthor={}\ndescription={}\nentrypoints={}",
+        map_script_to_name(&config.script_type),
+        get_api_version(&config.api_version),
+        get_module_names(&config.modules).join(","),
+        get_recipes(config)
+            .iter()
+            .map(|recipe| recipe.name)
+            .collect::<Vec<&str>>()
+            .join(","),
+        get_copyright(config)?,
+        config.no_header,
+        config.ts_check,
+        config.suitecommerce,
+        get_portlet_type(config).unwrap_or_default(),
+        get_scope(&config.scope).unwrap_or_default(),
+        config
+            .amd_config
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default(),
+        get_author(config).unwrap_or_default(),
+        config.description.clone().unwrap_or_default(),
+        get_entry_points(config)
+            .iter()
+            .map(|entry_point| entry_point.name)
+            .collect::<Vec<&str>>()
+            .join(","),
+    );
+    if config.template.is_some() {
+        options.push_str(&format!("\ntemplate={}", get_template(config)?));
+    }
+    if typescript::is_typescript(config) {
+        options.push_str("\nlang=ts");
+    }
+
+    Ok(Fnv1a::default().update(options.as_bytes()).finish())
+}
+
+/// A 64-bit FNV-1a hash, which is stable across platforms and Rust versions.
+#[derive(Clone, Copy, Debug)]
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    /// Adds bytes to the hash.
+    fn update(self, bytes: &[u8]) -> Self {
+        Fnv1a(bytes.iter().fold(self.0, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        }))
+    }
+
+    /// Gets the hash of the bytes added.
+    fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+/// Gets the `SuiteScript` API version to be used.
+fn get_api_version(version: &str) -> String {
+    match version {
+        "2" => String::from("2.0"),
+        _ => version.to_owned(),
+    }
+}
+
+/// Retrieves the copyright message of the script.
+///
+/// Placeholders of the message read by [`read_copyright`] are substituted, and the message is then
+/// returned as a comment block, formatted with one blank line after the final content line of the
+/// copyright message. Returns an empty string if there is no copyright message.
+///
+/// # Errors
+/// Returns an IO error naming the copyright file if it cannot be read
+fn get_copyright(config: &ScriptSpec) -> Result<String, SuiteScriptError> {
+    let message = match read_copyright(config)? {
+        Some(message) => message,
+        None => return Ok(String::new()),
+    };
+    let message = interpolate_copyright(&message, config, &current_year().to_string());
+    Ok(format!("{}\n\n", format_copyright(&message)))
+}
+
+/// Reads the copyright message of the script, before its placeholders are substituted.
+///
+/// Reads the copyright file of the directory of the script, if any, or the specified copyright
+/// file into memory, or uses the copyright text if no file is specified, with each `\n` escape
+/// replaced by a line break. Line endings are normalized to `\n` and the contents are trimmed to
+/// remove any mistaken whitespaces or newlines in the file. Returns `None` if there is no
+/// copyright message. Copyright files given as HTTPS URLs are fetched by [`remote::fetch`].
+///
+/// # Errors
+/// Returns an IO error naming the copyright file if it cannot be read, and an error if a copyright
+/// URL cannot be fetched
+fn read_copyright(config: &ScriptSpec) -> Result<Option<String>, SuiteScriptError> {
+    let path = find_directory_copyright(config).unwrap_or(&config.copyright);
+    let contents = if !path.as_os_str().is_empty() {
+        std::fs::read_to_string(get_local_path(path)?).map_err(|source| {
+            SuiteScriptError::io(
+                format!("Failed to read copyright file {}", path.display()),
+                source,
+            )
+        })?
+    } else {
+        match &config.copyright_text {
+            Some(text) if !text.trim().is_empty() => text.replace("\\n", "\n"),
+            _ => return Ok(None),
+        }
+    };
+    Ok(Some(contents.replace("\r\n", "\n").trim().to_owned()))
+}
+
+/// Gets the local path of a template or a copyright file, fetching it into the cache if it is an
+/// HTTPS URL.
+///
+/// # Errors
+/// Returns an error if the URL has no checksum or the file cannot be fetched or does not match it
+fn get_local_path(path: &Path) -> Result<PathBuf, SuiteScriptError> {
+    match path.to_str() {
+        Some(url) if remote::is_url(path) => remote::fetch(url),
+        _ => Ok(path.to_owned()),
+    }
+}
+
+/// Finds the copyright file of the directory a script is written in, from the `copyrights` table of
+/// a config.
+///
+/// Relative file names are resolved from the current directory, and the deepest directory
+/// containing the script is used. Returns `None` if the script is in none of the directories.
+fn find_directory_copyright(config: &ScriptSpec) -> Option<&Path> {
+    let file_name = config.file_name.as_deref()?;
+    let path = std::env::current_dir().ok()?.join(file_name);
+    config
+        .copyrights
+        .iter()
+        .filter(|(dir, _)| path.starts_with(dir))
+        .max_by_key(|(dir, _)| dir.components().count())
+        .map(|(_, copyright)| copyright.as_path())
+}
+
+/// Substitutes the placeholders of a copyright message with the year, the company, and the file
+/// name of the script.
+fn interpolate_copyright(message: &str, config: &ScriptSpec, year: &str) -> String {
+    let file = config
+        .file_name
+        .as_deref()
+        .map(get_file_name)
+        .unwrap_or_default();
+    interpolate(
+        message,
+        &[
+            ("year", year),
+            ("company", &config.company),
+            ("file", &file),
+        ],
+    )
+}
+
+/// Builds a pattern matching the copyright notice of the script as [`get_copyright`] writes it.
+///
+/// Scripts keep the year they were generated in, so `{{year}}` matches any four-digit year.
+fn get_copyright_pattern(message: &str, config: &ScriptSpec) -> Regex {
+    const YEAR: &str = "\u{0}year\u{0}";
+    let notice = format_copyright(&interpolate_copyright(message, config, YEAR));
+    let pattern = regex::escape(&notice).replace(YEAR, r"\d{4}");
+    Regex::new(&pattern).expect("Escaped notices are valid patterns")
+}
+
+/// Substitutes the `{{name}}` placeholders of a copyright message or template with their values.
+///
+/// The text is substituted in a single pass, so placeholders inside substituted values are kept
+/// verbatim. Unknown placeholders are left as is.
+fn interpolate(text: &str, values: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let value = after.find("}}").and_then(|end| {
+            values
+                .iter()
+                .find(|(name, _)| *name == &after[..end])
+                .map(|(_, value)| (end, value))
+        });
+        match value {
+            Some((end, value)) => {
+                result.push_str(value);
+                rest = &after[end + 2..];
+            }
+            None => {
+                result.push_str("{{");
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Gets the current year in UTC.
+///
+/// Converts the days since the Unix epoch to a civil date with the proleptic Gregorian calendar,
+/// so that no date library is needed.
+fn current_year() -> i64 {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    year_from_days((seconds / 86_400) as i64)
+}
+
+/// Gets the year of a date given as days since the Unix epoch.
+fn year_from_days(days: i64) -> i64 {
+    date_from_days(days).0
+}
+
+/// Gets the year, month, and day of a date given as days since the Unix epoch.
+fn date_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let year = year_of_era + era * 400;
+    if month >= 10 {
+        (year + 1, month - 9, day)
+    } else {
+        (year, month + 3, day)
+    }
+}
+
+/// Formats a copyright message as a `JSDoc` comment block.
+///
+/// Messages that are already a block comment, or consist only of line comments, are returned as is.
+/// Otherwise, each line is prefixed with ` * ` and the message is wrapped in `/**` and ` */`.
+fn format_copyright(message: &str) -> String {
+    let line_comments = message
+        .lines()
+        .all(|line| line.trim().is_empty() || line.trim_start().starts_with("//"));
+    if (message.starts_with("/*") && message.ends_with("*/")) || line_comments {
+        return message.to_owned();
+    }
+
+    let lines: Vec<String> = message
+        .lines()
+        .map(|line| format!(" * {}", line.trim_end()).trim_end().to_owned())
+        .collect();
+    format!("/**\n{}\n */", lines.join("\n"))
+}
+
+/// Converts a given script type name to its supported `NetSuite` name.
+///
+/// Converts the name to lowercase to support mangled inputs. Matches the name, or its common
+/// abbreviation, to the casing supported by `NetSuite`. If no match, an empty string is returned.
+fn map_script_to_name(stype: &str) -> &str {
+    match stype.to_lowercase().as_ref() {
+        "mapreduce" | "mr" => "MapReduce",
+        "userevent" | "ue" => "UserEvent",
+        "scheduled" | "ss" => "Scheduled",
+        "client" | "cs" => "Client",
+        "suitelet" | "sl" => "Suitelet",
+        "restlet" | "rl" => "RESTlet",
+        "portlet" => "Portlet",
+        "bundleinstallation" => "BundleInstallation",
+        "massupdate" => "MassUpdate",
+        "workflowaction" | "wa" => "WorkflowAction",
+        "sdfinstallation" => "SDFInstallation",
+        "customgl" => "CustomGL",
+        "library" | "module" => "Library",
+        _ => "",
+    }
+}
+
+/// Gets the file name suffixes of script types, as pairs of a suffix and a script type.
+///
+/// The suffixes set in a config come first, and replace the suffix conventions in `assets/` of
+/// their script types, such as `_ue` for user event scripts.
+pub fn get_suffixes(config: &ScriptSpec) -> Vec<(String, String)> {
+    let mut suffixes = config.suffixes.clone();
+    for (suffix, script_type) in SUFFIXES.iter() {
+        let configured = config
+            .suffixes
+            .iter()
+            .any(|(_, name)| map_script_to_name(name) == map_script_to_name(script_type));
+        if !configured {
+            suffixes.push(((*suffix).to_owned(), (*script_type).to_owned()));
+        }
+    }
+    suffixes
+}
+
+/// Infers the script type of a file from the suffix of its file name.
+///
+/// Matches the file stem against the suffixes of script types, as given by [`get_suffixes`],
+/// preferring the longest suffix that matches. Returns `None` if no suffix applies.
+fn infer_script_type(file_name: &Path, suffixes: &[(String, String)]) -> Option<String> {
+    let stem = file_name.file_stem()?.to_str()?.to_lowercase();
+    suffixes
+        .iter()
+        .filter(|(suffix, _)| stem.ends_with(&suffix.to_lowercase()))
+        .max_by_key(|(suffix, _)| suffix.len())
+        .map(|(_, script_type)| script_type.clone())
+}
+
+/// Converts a given script type to its supported `NetSuite` name.
+///
+/// If the script name is valid, returns a string with the NScriptType tag and the script name.
+/// Otherwise, returns an empty string.
+fn get_script_type(script_type: &str) -> String {
+    let script_name = map_script_to_name(script_type);
+    match script_name {
+        "MapReduce" | "UserEvent" | "Scheduled" | "Client" | "BundleInstallation"
+        | "MassUpdate" | "WorkflowAction" | "SDFInstallation" => {
+            format!(" * @NScriptType {}Script\n", script_name)
+        }
+        "" | "CustomGL" | "Library" => String::from(""),
+        _ => format!(" * @NScriptType {}\n", script_name),
+    }
+}
+
+/// Maps a given module name to the valid `NetSuite` name.
+///
+/// Converts the module name to lowercase to support mangled inputs. Matches the name to a list of
+/// special cases, or returns the lowercase name if no case applies.
+fn map_module_to_name(module: &str) -> String {
+    let lower_case = module.to_lowercase();
+    match lower_case.as_str() {
+        "certificatecontrol" => "certificateControl".into(),
+        "clientcertificate" => "clientCertificate".into(),
+        "currentrecord" => "currentRecord".into(),
+        "keycontrol" => "keyControl".into(),
+        "recordcontext" => "recordContext".into(),
+        "suiteappinfo" => "suiteAppInfo".into(),
+        "serverwidget" => "serverWidget".into(),
+        _ => lower_case,
+    }
+}
+
+/// Gets the path of a module as `NetSuite` and the type definitions write it, with each segment of
+/// its name in camel case, such as `ui/serverWidget` for `ui/serverwidget`.
+///
+/// Argument names are derived from the name of the module instead, so that they do not change.
+pub fn get_module_path(name: &str) -> String {
+    name.split('/')
+        .map(map_module_to_name)
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+/// Converts a given module name to its supported `NetSuite` name.
+///
+/// Maps over a vector of module names, applying `map_module_to_name` to each name.
+fn get_module_names(modules: &[String]) -> Vec<String> {
+    modules
+        .iter()
+        .map(|name| map_module_to_name(name))
+        .collect()
+}
+
+/// Sorts modules by their `NetSuite` name and removes duplicates, so that the order in which the
+/// modules were given does not affect the generated script.
+fn sort_modules(mut modules: Vec<String>) -> Vec<String> {
+    modules.sort_by_key(|name| map_module_to_name(name));
+    modules.dedup_by_key(|name| map_module_to_name(name));
+    modules
+}
+
+/// Parses a newline-separated list of module names.
+///
+/// Each line is stripped of surrounding whitespace, quotes, and commas, and of an `N/` prefix, so
+/// that lines copied from an existing `define` block are accepted. Blank lines and duplicates are
+/// skipped.
+pub fn parse_module_list(input: &str) -> Vec<String> {
+    let mut modules: Vec<String> = Vec::new();
+    for line in input.lines() {
+        let name = line
+            .trim()
+            .trim_matches(|c| c == '\'' || c == '"' || c == ',');
+        let name = name.strip_prefix("N/").unwrap_or(name);
+        if !name.is_empty() && !modules.iter().any(|module| module == name) {
+            modules.push(name.to_owned());
+        }
+    }
+    modules
+}
+
+/// Formats a list of `NetSuite` module names into the correct import string.
+///
+/// Joins the paths of the modules with a comma, newline, and prefix of `N/`. Indentation is 2
+/// spaces.
+fn format_imports(modules: &[String]) -> String {
+    let paths: Vec<String> = modules.iter().map(|name| get_module_path(name)).collect();
+    paths.join("',\n  'N/")
+}
+
+/// Formats a list of `NetSuite` module names into an argument list.
+///
+/// Removes any `/` in module names. Joins modules with a comma and space.
+fn format_args(modules: &[String]) -> String {
+    let cleaned: Vec<String> = modules.iter().map(|name| name.replace('/', "")).collect();
+    cleaned.join(", ")
+}
+
+/// Formats a `NetSuite` module name into the name of its imported type.
+///
+/// Capitalizes each segment of the module path, removes any `/`, and adds an `N` prefix so the
+/// name cannot clash with built-in types, so `ui/message` becomes `NUiMessage`.
+fn format_type_name(module: &str) -> String {
+    let name: String = module
+        .split('/')
+        .map(|segment| {
+            let mut chars = segment.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect::<String>()
+            })
+        })
+        .collect();
+    format!("N{}", name)
+}
+
+/// Writes the `TypeScript` check directive and the typed imports of the given modules.
+///
+/// Each module is imported as a `JSDoc` type definition, which `get_modules` uses to type the
+/// arguments of the module definition.
+fn get_ts_check(modules: &[String]) -> String {
+    if modules == vec![String::from("")] {
+        return String::from("// @ts-check\n\n");
+    }
+
+    let typedefs: Vec<String> = get_module_names(modules)
+        .iter()
+        .map(|name| {
+            format!(
+                " * @typedef {{typeof import('N/{}')}} {}",
+                get_module_path(name),
+                format_type_name(name)
+            )
+        })
+        .collect();
+    format!("// @ts-check\n/**\n{}\n */\n\n", typedefs.join("\n"))
+}
+
+/// Writes the given `SuiteScript` import modules and local dependencies to the file.
+///
+/// Returns a string with the formatted imports and args and the symbols around them if modules
+/// were passed in. Otherwise, returns a string with the symbols for an AMD module with no imports.
+/// Local dependencies are imported after the modules, by their relative paths. When `typed` is
+/// set, the args are annotated with the types written by `get_ts_check`.
+fn get_modules(modules: &[String], dependencies: &[String], typed: bool) -> String {
+    let mods = if modules == vec![String::from("")] {
+        Vec::new()
+    } else {
+        get_module_names(modules)
+    };
+    if mods.is_empty() && dependencies.is_empty() {
+        return String::from("], () => {\n");
+    }
+
+    let mut imports = String::new();
+    let mut args = Vec::new();
+    if !mods.is_empty() {
+        imports.push_str(&format!("  'N/{}',\n", format_imports(&mods)));
+        args.push(format_args(&mods));
+    }
+    for dependency in dependencies {
+        imports.push_str(&format!("  '{}',\n", dependency));
+        args.push(format_dependency_arg(dependency));
+    }
+
+    if typed {
+        let params: Vec<String> = mods
+            .iter()
+            .map(|name| {
+                format!(
+                    " * @param {{{}}} {}",
+                    format_type_name(name),
+                    name.replace('/', "")
+                )
+            })
+            .chain(dependencies.iter().map(|dependency| {
+                format!(
+                    " * @param {{typeof import('{}')}} {}",
+                    dependency,
+                    format_dependency_arg(dependency)
+                )
+            }))
+            .collect();
+        return format!(
+            "{}],\n/**\n{}\n */\n({}) => {{\n",
+            imports,
+            params.join("\n"),
+            args.join(", ")
+        );
+    }
+
+    format!("{}], ({}) => {{\n", imports, args.join(", "))
+}
+
+/// Formats the path of a local dependency into the name of its argument.
+///
+/// Uses the last segment of the path in camel case, so `./customer_constants` becomes
+/// `customerConstants`.
+fn format_dependency_arg(dependency: &str) -> String {
+    let name = dependency.rsplit('/').next().unwrap_or(dependency);
+    let mut arg = String::new();
+    for (index, word) in name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .enumerate()
+    {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            if index == 0 {
+                arg.push(first.to_ascii_lowercase());
+            } else {
+                arg.push(first.to_ascii_uppercase());
+            }
+            arg.extend(chars);
+        }
+    }
+    arg
+}
+
+/// Gets the recipes to add to a script.
+///
+/// These are the recipes selected by name, followed by the recipes triggered by an imported
+/// module.
+fn get_recipes(config: &ScriptSpec) -> Vec<&'static Recipe> {
+    let modules = get_module_names(&config.modules);
+    let selected = |recipe: &Recipe| {
+        config
+            .recipes
+            .iter()
+            .any(|name| name.to_lowercase() == recipe.name)
+    };
+    let triggered = |recipe: &Recipe| {
+        recipe
+            .trigger
+            .is_some_and(|module| modules.iter().any(|name| name == module))
+    };
+
+    RECIPES
+        .iter()
+        .filter(|recipe| selected(recipe))
+        .chain(
+            RECIPES
+                .iter()
+                .filter(|recipe| !selected(recipe) && triggered(recipe)),
+        )
+        .collect()
+}
+
+/// Adds the modules needed by the recipes that are missing from the modules to import.
+///
+/// Returns a note for each added module, so that the imports of the script are never a surprise.
+fn add_recipe_modules(mut modules: Vec<String>, recipes: &[&Recipe]) -> (Vec<String>, Vec<Notice>) {
+    let mut notices = Vec::new();
+    for recipe in recipes {
+        for module in recipe.modules {
+            if get_module_names(&modules).iter().any(|name| name == module) {
+                continue;
+            }
+
+            notices.push(Notice::Note(format!(
+                "added module `{}` needed by the `{}` recipe",
+                module, recipe.name
+            )));
+            modules.retain(|name| !name.is_empty());
+            modules.push((*module).to_owned());
+        }
+    }
+    (modules, notices)
+}
+
+/// Gets the modules to import that are not supported in SuiteCommerce website contexts.
+fn get_suitecommerce_unsupported(modules: &[String]) -> Vec<String> {
+    get_module_names(modules)
+        .into_iter()
+        .filter(|module| SUITECOMMERCE_UNSUPPORTED.contains(&module.to_lowercase().as_str()))
+        .collect()
+}
+
+/// Gets the entry points to write into the body of a script.
+///
+/// These are the entry points of the script type, limited to the selected ones if any are
+/// selected, in the order `NetSuite` calls them. Some entry points use the imported modules, such
+/// as the form of a Suitelet importing `ui/serverwidget` or the governance check of a scheduled
+/// script importing `runtime`.
+fn get_entry_points(config: &ScriptSpec) -> Vec<&'static EntryPoint> {
+    let imports = |module: &str| {
+        get_module_names(&config.modules)
+            .iter()
+            .any(|name| name.to_lowercase() == module)
+    };
+
+    match map_script_to_name(&config.script_type) {
+        "UserEvent" => select_entry_points(&USER_EVENT, &config.events),
+        "Client" if config.suitecommerce => SUITECOMMERCE.iter().collect(),
+        "Client" => select_entry_points(&CLIENT, &config.entries),
+        "RESTlet" => select_entry_points(&RESTLET, &config.methods),
+        "Suitelet" if config.pair => SUITELET_PAIR.iter().collect(),
+        "Suitelet" if imports("ui/serverwidget") => SUITELET_FORM.iter().collect(),
+        "Suitelet" => SUITELET.iter().collect(),
+        "Scheduled" if imports("runtime") => SCHEDULED_GOVERNANCE.iter().collect(),
+        "Scheduled" => SCHEDULED.iter().collect(),
+        "BundleInstallation" => BUNDLE_INSTALLATION.iter().collect(),
+        "MassUpdate" => MASS_UPDATE.iter().collect(),
+        "WorkflowAction" => WORKFLOW_ACTION.iter().collect(),
+        "SDFInstallation" => SDF_INSTALLATION.iter().collect(),
+        "Library" => LIBRARY.iter().collect(),
+        "Portlet" => PORTLET
+            .iter()
+            .filter(|(portlet_type, _)| {
+                Some((*portlet_type).to_owned()) == get_portlet_type(config)
+            })
+            .map(|(_, entry_point)| entry_point)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Gets the names of the entry points of a script, such as the methods handled by a RESTlet.
+pub fn get_entry_point_names(config: &ScriptSpec) -> Vec<&'static str> {
+    get_entry_points(config)
+        .iter()
+        .map(|entry_point| entry_point.name)
+        .collect()
+}
+
+/// Selects the entry points matching the given names, or all of them if no name is given.
+///
+/// Converts the names to lowercase to support mangled inputs.
+fn select_entry_points(
+    entry_points: &'static [EntryPoint],
+    names: &[String],
+) -> Vec<&'static EntryPoint> {
+    entry_points
+        .iter()
+        .filter(|entry_point| {
+            names.is_empty()
+                || names
+                    .iter()
+                    .any(|name| name.to_lowercase() == entry_point.name.to_lowercase())
+        })
+        .collect()
+}
+
+/// Replaces the `{cost:<api>}` markers of a snippet with the governance cost of the API call.
+///
+/// # Panics
+/// The function panics if a marker names an API call missing from the cost table in `assets/`
+fn annotate_costs(snippet: &str) -> String {
+    let mut annotated = String::new();
+    let mut rest = snippet;
+    while let Some(start) = rest.find("{cost:") {
+        let end = start
+            + rest[start..]
+                .find('}')
+                .expect("Unclosed governance cost marker");
+        let api = &rest[start + "{cost:".len()..end];
+        let (_, min, max) = COSTS
+            .iter()
+            .find(|(name, _, _)| *name == api)
+            .expect("Unknown governance cost");
+        annotated.push_str(&rest[..start]);
+        if min == max {
+            let units = if *min == 1 { "unit" } else { "units" };
+            annotated.push_str(&format!("{} costs {} governance {}", api, min, units));
+        } else {
+            annotated.push_str(&format!(
+                "{} costs {} to {} governance units, depending on the record type",
+                api, min, max
+            ));
+        }
+        rest = &rest[end + 1..];
+    }
+    annotated.push_str(rest);
+    annotated
+}
+
+/// Writes the lines of the `JSDoc` block of an entry point as a comment.
+fn format_doc(doc: &[&str]) -> String {
+    let lines: String = doc
+        .iter()
+        .map(|line| format!("   *{}{}\n", if line.is_empty() { "" } else { " " }, line))
+        .collect();
+    format!("  /**\n{}   */\n", lines)
+}
+
+/// Writes an entry point as a function with its `JSDoc` block.
+///
+/// The body of the function is a keep region named after the function, so that the code written in
+/// it survives regeneration.
+fn format_entry_point(entry_point: &EntryPoint) -> String {
+    let name = entry_point.function.unwrap_or(entry_point.name);
+    format!(
+        "{}  const {} = ({}) => {{\n{}\n  }};",
+        format_doc(entry_point.doc),
+        name,
+        entry_point.param,
+        wrap_keep_region(name, &annotate_costs(entry_point.body), "    ")
+    )
+}
+
+/// Wraps code in a keep region named after the function or recipe it belongs to, such as
+/// `// <suitescript:keep onRequest>`, with the markers at the indentation of the code.
+fn wrap_keep_region(name: &str, code: &str, indent: &str) -> String {
+    format!(
+        "{}{} {}>\n{}\n{}{}",
+        indent, KEEP_START, name, code, indent, KEEP_END
+    )
+}
+
+/// Writes the body of the AMD module.
+///
+/// The body contains the snippets of the given recipes, if any, followed by the given entry points
+/// and the object returning them. API calls of the snippets are annotated with their governance
+/// cost. Each snippet and the body of each entry point is a keep region, so that code written
+/// inside it survives regeneration, and a body without either is an empty keep region.
+fn get_body(recipes: &[&Recipe], entry_points: &[&EntryPoint]) -> String {
+    if recipes.is_empty() && entry_points.is_empty() {
+        return format!("  {}>\n\n  {}\n", KEEP_START, KEEP_END);
+    }
+
+    let mut parts: Vec<String> = recipes
+        .iter()
+        .map(|recipe| wrap_keep_region(recipe.name, &annotate_costs(recipe.snippet), "  "))
+        .collect();
+    parts.extend(
+        entry_points
+            .iter()
+            .map(|entry_point| format_entry_point(entry_point)),
+    );
+    if !entry_points.is_empty() {
+        let names: Vec<String> = entry_points
+            .iter()
+            .map(|entry_point| match entry_point.function {
+                Some(function) => format!("{}: {}", entry_point.name, function),
+                None => entry_point.name.to_owned(),
+            })
+            .collect();
+        parts.push(format!("  return {{ {} }};", names.join(", ")));
+    }
+
+    format!("{}\n", parts.join("\n\n"))
+}
+
+/// Collects the keep regions of a script.
+///
+/// Returns each region's opening marker line, trimmed, together with the lines between the
+/// opening and closing markers. Regions without a closing marker are ignored.
+pub fn get_keep_regions(contents: &str) -> Vec<(String, Vec<&str>)> {
+    let mut regions = Vec::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed == KEEP_END {
+            if let Some(region) = current.take() {
+                regions.push(region);
+            }
+        } else if let Some((_, lines)) = current.as_mut() {
+            lines.push(line);
+        } else if trimmed.starts_with(KEEP_START) && trimmed.ends_with('>') {
+            current = Some((trimmed.to_owned(), Vec::new()));
+        }
+    }
+
+    regions
+}
+
+/// Carries the keep regions of a previously generated script over to its regenerated contents.
+///
+/// Regions are matched by their opening marker. The contents of each matched region in `previous`
+/// replace the contents of the region in `generated`. Anything outside the keep regions is taken
+/// from `generated`.
+///
+/// Returns the merged contents and the opening markers of the regions of `previous` that are not
+/// in `generated`, whose code is dropped.
+pub fn preserve_keep_regions(generated: &str, previous: &str) -> (String, Vec<String>) {
+    let mut merged = Vec::new();
+    let mut writer = KeepRegionWriter::new(&mut merged, previous);
+    let dropped = writer
+        .write_all(generated.as_bytes())
+        .and_then(|()| writer.finish())
+        .expect("Writing into memory does not fail");
+    (String::from_utf8_lossy(&merged).into_owned(), dropped)
+}
+
+/// A writer that carries the keep regions of a previously generated script over to the contents
+/// written through it, as described by [`preserve_keep_regions`].
+///
+/// The contents are merged line by line as they are written, so that a script can be rendered
+/// straight into its file. [`KeepRegionWriter::finish`] writes the last line once the contents
+/// are complete.
+pub struct KeepRegionWriter<'a, W: Write> {
+    inner: W,
+    kept: Vec<(String, Vec<&'a str>)>,
+    /// Whether each kept region was matched by an opening marker of the contents
+    matched: Vec<bool>,
+    /// The line being written, up to its line break
+    line: Vec<u8>,
+    /// Whether the lines of a kept region are being replaced by the previous ones
+    skipping: bool,
+    /// Whether a line was written, so that the next one is written after a line break
+    started: bool,
+}
+
+impl<'a, W: Write> KeepRegionWriter<'a, W> {
+    /// Creates a writer that carries the keep regions of `previous` over to `inner`.
+    pub fn new(inner: W, previous: &'a str) -> Self {
+        let kept = get_keep_regions(previous);
+        KeepRegionWriter {
+            inner,
+            matched: vec![false; kept.len()],
+            kept,
+            line: Vec::new(),
+            skipping: false,
+            started: false,
+        }
+    }
+
+    /// Writes the last line, which has no line break, and flushes the writer.
+    ///
+    /// Returns the opening markers of the kept regions that are not in the contents, whose code
+    /// was not carried over.
+    ///
+    /// # Errors
+    /// Returns an IO error if the inner writer fails
+    pub fn finish(mut self) -> std::io::Result<Vec<String>> {
+        if !self.line.is_empty() {
+            let line = std::mem::take(&mut self.line);
+            self.merge_line(&line)?;
+        } else if self.started {
+            self.inner.write_all(b"\n")?;
+        }
+        self.inner.flush()?;
+        Ok(self
+            .kept
+            .into_iter()
+            .zip(self.matched)
+            .filter(|(_, matched)| !matched)
+            .map(|((marker, _), _)| marker)
+            .collect())
+    }
+
+    /// Writes a line, unless it is replaced by a kept region, followed by the kept region it opens.
+    fn merge_line(&mut self, line: &[u8]) -> std::io::Result<()> {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let text = String::from_utf8_lossy(line);
+        let trimmed = text.trim();
+        if self.skipping {
+            if trimmed != KEEP_END {
+                return Ok(());
+            }
+            self.skipping = false;
+        }
+
+        self.push_line(line)?;
+        let index = self.kept.iter().position(|(marker, _)| marker == trimmed);
+        if let Some(index) = index {
+            self.matched[index] = true;
+            let lines = self.kept[index].1.clone();
+            for kept_line in lines {
+                self.push_line(kept_line.as_bytes())?;
+            }
+            self.skipping = true;
+        }
+        Ok(())
+    }
+
+    /// Writes a line after the line break of the previous one.
+    fn push_line(&mut self, line: &[u8]) -> std::io::Result<()> {
+        if self.started {
+            self.inner.write_all(b"\n")?;
+        }
+        self.started = true;
+        self.inner.write_all(line)
+    }
+}
+
+impl<W: Write> Write for KeepRegionWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut rest = buf;
+        while let Some(end) = rest.iter().position(|byte| *byte == b'\n') {
+            self.line.extend_from_slice(&rest[..end]);
+            let line = std::mem::take(&mut self.line);
+            self.merge_line(&line)?;
+            rest = &rest[end + 1..];
+        }
+        self.line.extend_from_slice(rest);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Gets the files generated alongside a script, such as its test harness and Postman collection.
+///
+/// Fixtures, tests, AMD configs, and SDF objects that already exist are left out, so that they are
+/// never overwritten.
+///
+/// # Errors
+/// Returns an IO or template error if the scripts generated by `--pair` cannot be rendered
+pub fn get_companion_files(config: &ScriptSpec) -> Result<Vec<OutputFile>, SuiteScriptError> {
+    let script = match config.file_name.as_deref() {
+        Some(script) => script,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut files = Vec::new();
+    if config.with_harness {
+        files.extend(harness::get_files(script, &config.modules));
+    }
+    if let Some(amd_config) = &config.amd_config {
+        files.extend(amdconfig::get_file(script, amd_config));
+    }
+    if config.with_tests {
+        files.extend(jest::get_file(script, config));
+    }
+    if config.postman {
+        files.push(postman::get_file(script, &get_entry_point_names(config)));
+    }
+    if config.pair {
+        files.push(pair::get_client_file(config)?);
+        if map_script_to_name(&config.script_type) == "UserEvent" {
+            files.push(pair::get_constants_file(config)?);
+        }
+    }
+    if config.sdf_object {
+        files.extend(sdfobject::get_file(script, config));
+    }
+    Ok(files)
+}
+
+/// Gets the name of a file from its path, such as `customer_sl.js`.
+fn get_file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Writes a file, creating its parent directories if they are missing.
+///
+/// # Errors
+/// Returns an IO error if the file or its parent directories cannot be created
+pub fn write_output_file(file: &OutputFile) -> Result<(), SuiteScriptError> {
+    create_parent_dirs(&file.path)?;
+    write_atomically(&file.path, &file.contents)
+}
+
+/// Creates the parent directories of a file if they are missing.
+///
+/// # Errors
+/// Returns an IO error if the directories cannot be created
+fn create_parent_dirs(path: &Path) -> Result<(), SuiteScriptError> {
+    match path.parent() {
+        Some(parent) => std::fs::create_dir_all(parent).map_err(|source| {
+            SuiteScriptError::io(
+                format!("Failed to create directory {}", parent.display()),
+                source,
+            )
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Writes contents to a file through a temporary file in the same directory, which is renamed over
+/// the file once it is complete, so that the file is never left half written.
+///
+/// The permissions of an existing file are kept.
+///
+/// # Errors
+/// Returns an IO error if the temporary file cannot be written or renamed
+pub fn write_atomically(path: &Path, contents: &str) -> Result<(), SuiteScriptError> {
+    write_atomically_with(path, |out| {
+        out.write_all(contents.as_bytes()).map_err(|source| {
+            SuiteScriptError::io(format!("Failed to write {}", path.display()), source)
+        })
+    })
+}
+
+/// Writes a file through a temporary file like [`write_atomically`], with the contents written by
+/// `write` into a buffered writer of the temporary file.
+///
+/// The temporary file is removed if `write` fails, leaving the file untouched.
+///
+/// # Errors
+/// Returns the error of `write`, and an IO error if the temporary file cannot be written or
+/// renamed
+pub fn write_atomically_with<F>(path: &Path, write: F) -> Result<(), SuiteScriptError>
+where
+    F: FnOnce(&mut BufWriter<File>) -> Result<(), SuiteScriptError>,
+{
+    let temporary = get_temporary_path(path);
+    let io_error =
+        |source| SuiteScriptError::io(format!("Failed to write {}", path.display()), source);
+    let written = File::create(&temporary)
+        .map(BufWriter::new)
+        .map_err(io_error)
+        .and_then(|mut out| {
+            write(&mut out)?;
+            let file = out
+                .into_inner()
+                .map_err(|error| io_error(error.into_error()))?;
+            finish_temporary(&file, path).map_err(io_error)
+        })
+        .and_then(|()| std::fs::rename(&temporary, path).map_err(io_error));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&temporary);
+    }
+    written
+}
+
+/// Completes the temporary file of an atomic write, with the permissions of the file it replaces.
+fn finish_temporary(file: &File, path: &Path) -> std::io::Result<()> {
+    file.sync_all()?;
+    if let Ok(metadata) = std::fs::metadata(path) {
+        file.set_permissions(metadata.permissions())?;
+    }
+    Ok(())
+}
+
+/// Gets the path of the temporary file written before a file is replaced, such as
+/// `.customer_ue.js.1234.tmp` for process 1234.
+fn get_temporary_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()))
+}
+
+/// Copies a file to a timestamped backup next to it, returning the path of the backup.
+///
+/// # Errors
+/// Returns an IO error if the file cannot be copied
+pub fn backup_file(path: &Path) -> Result<PathBuf, SuiteScriptError> {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let backup = get_backup_path(path, seconds);
+    std::fs::copy(path, &backup).map_err(|source| {
+        SuiteScriptError::io(format!("Failed to back up {}", path.display()), source)
+    })?;
+    Ok(backup)
+}
+
+/// Describes the backup of a file made by [`backup_file`].
+pub fn describe_backup(path: &Path, backup: &Path) -> Notice {
+    Notice::Note(format!(
+        "backed up {} to {}",
+        path.display(),
+        backup.display()
+    ))
+}
+
+/// Gets the path of the backup of a file made at a time given as seconds since the Unix epoch,
+/// such as `customer_ue.js.bak-20240101T1200` in UTC.
+fn get_backup_path(path: &Path, seconds: u64) -> PathBuf {
+    let (year, month, day) = date_from_days((seconds / 86_400) as i64);
+    let minutes = seconds % 86_400 / 60;
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(
+        ".bak-{:04}{:02}{:02}T{:02}{:02}",
+        year,
+        month,
+        day,
+        minutes / 60,
+        minutes % 60
+    ));
+    PathBuf::from(name)
+}
+
+/// Reads a file, with an error naming the file.
+fn read_to_string(path: &Path) -> Result<String, SuiteScriptError> {
+    std::fs::read_to_string(path).map_err(|source| {
+        SuiteScriptError::io(format!("Failed to read {}", path.display()), source)
+    })
+}
+
+/// The result of the `new` command, as formatted by [`format_json_result`].
+#[derive(Serialize)]
+struct JsonResult<'a> {
+    path: String,
+    #[serde(rename = "type")]
+    script_type: Option<&'a str>,
+    modules: Vec<String>,
+    files: Vec<String>,
+    warnings: Vec<&'a str>,
+    notes: Vec<&'a str>,
+}
+
+/// The files the `new` command would create, as formatted by [`format_plan`].
+#[derive(Serialize)]
+struct JsonPlan {
+    files: Vec<JsonPlannedFile>,
+}
+
+/// A file the `new` command would create, with the details of the script.
+#[derive(Serialize)]
+struct JsonPlannedFile {
+    path: String,
+    #[serde(flatten)]
+    script: Option<JsonPlannedScript>,
+}
+
+/// The template, header tags, and modules of a script the `new` command would create.
+#[derive(Serialize)]
+struct JsonPlannedScript {
+    template: Option<String>,
+    tags: serde_json::Map<String, serde_json::Value>,
+    modules: Vec<String>,
+}
+
+/// Formats the result of the `new` command as a JSON object, for tools that wrap the CLI.
+///
+/// The object holds the path of the script, its `NetSuite` script type, the modules it imports,
+/// the paths of the files generated alongside it, and the warnings and notes about its options.
+pub fn format_json_result(
+    config: &ScriptSpec,
+    path: &Path,
+    companions: &[OutputFile],
+    notices: &[Notice],
+) -> String {
+    let messages = |warnings: bool| -> Vec<&str> {
+        notices
+            .iter()
+            .filter_map(|notice| match notice {
+                Notice::Warning(message) if warnings => Some(message.as_str()),
+                Notice::Note(message) if !warnings => Some(message.as_str()),
+                _ => None,
+            })
+            .collect()
+    };
+    let result = JsonResult {
+        path: path.display().to_string(),
+        script_type: Some(map_script_to_name(&config.script_type)).filter(|name| !name.is_empty()),
+        modules: get_json_modules(config),
+        files: companions
+            .iter()
+            .map(|file| file.path.display().to_string())
+            .collect(),
+        warnings: messages(true),
+        notes: messages(false),
+    };
+    to_json(&result)
+}
+
+/// Formats the files the `new` command would create as a JSON object, for tools that wrap the CLI.
+///
+/// Each file is listed with its path. The script is also listed with the template it is rendered
+/// from, or `null` for the built-in layout, the tags of its header, and the modules it imports.
+/// A tag written more than once, such as `@see`, has an array of its values.
+pub fn format_plan(config: &ScriptSpec, script: &OutputFile, companions: &[OutputFile]) -> String {
+    let mut tags = serde_json::Map::new();
+    for (tag, value) in get_header_tags(&script.contents) {
+        let value = serde_json::Value::from(value);
+        match tags.get_mut(tag) {
+            Some(serde_json::Value::Array(values)) => values.push(value),
+            Some(first) => *first = serde_json::Value::Array(vec![first.take(), value]),
+            None => {
+                tags.insert(tag.to_owned(), value);
+            }
+        }
+    }
+
+    let script = JsonPlannedFile {
+        path: script.path.display().to_string(),
+        script: Some(JsonPlannedScript {
+            template: config
+                .template
+                .as_ref()
+                .map(|path| path.display().to_string()),
+            tags,
+            modules: get_json_modules(config),
+        }),
+    };
+    let files = std::iter::once(script)
+        .chain(companions.iter().map(|file| JsonPlannedFile {
+            path: file.path.display().to_string(),
+            script: None,
+        }))
+        .collect();
+    to_json(&JsonPlan { files })
+}
+
+/// Formats a value as indented JSON, followed by a line break.
+fn to_json(value: &impl Serialize) -> String {
+    let mut json = serde_json::to_string_pretty(value).expect("Values are valid JSON");
+    json.push('\n');
+    json
+}
+
+/// Gets the modules imported by a script, such as `N/record`, followed by its local dependencies.
+fn get_json_modules(config: &ScriptSpec) -> Vec<String> {
+    get_module_names(&config.modules)
+        .into_iter()
+        .filter(|name| !name.is_empty())
+        .map(|name| format!("N/{}", get_module_path(&name)))
+        .chain(config.dependencies.iter().cloned())
+        .collect()
+}
+
+/// Gets the `JSDoc` tags in the comments at the top of a script, such as `@NApiVersion`, with their
+/// values.
+fn get_header_tags(contents: &str) -> Vec<(&str, &str)> {
+    contents
+        .lines()
+        .map(str::trim)
+        .take_while(|line| {
+            line.is_empty()
+                || line.starts_with("/*")
+                || line.starts_with('*')
+                || line.starts_with("//")
+        })
+        .filter_map(|line| line.strip_prefix("* "))
+        .filter(|tag| tag.starts_with('@'))
+        .map(|tag| match tag.split_once(' ') {
+            Some((name, value)) => (name, value.trim()),
+            None => (tag, ""),
+        })
+        .collect()
+}
+
+/// Escapes a string for use inside a JSON string literal.
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Checks if a file has an extension.
+///
+/// Retrieves the file extension from a given path, if available.
+///
+/// # Errors
+/// Returns an error if the path extension is not valid Unicode
+fn validate_file(path: &Path) -> Result<Option<&str>, String> {
+    path.extension()
+        .map(|ext| {
+            ext.to_str().ok_or_else(|| {
+                format!(
+                    "Invalid file name {}: extension is not valid Unicode",
+                    path.display()
+                )
+            })
+        })
+        .transpose()
+}
+
+/// Validates a given file name for a copyright file.
+///
+/// A copyright file is required to be a text, Markdown, or JavaScript file, or to have no
+/// extension. The contents may be plain text or a comment block with a copyright message.
+fn validate_copyright_file(name: String) -> Result<(), String> {
+    // TODO: Check if file exists
+    if name.is_empty() {
+        return Ok(());
+    }
+
+    let path = Path::new(remote::strip_checksum(&name));
+    if validate_file(path)?.is_some_and(|ext| !COPYRIGHT_EXTENSIONS.contains(&ext)) {
+        return Err(String::from(
+            "Invalid file type: copyright file must be a text, Markdown, or JavaScript file.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates a given path to a template file.
+///
+/// The template is required to exist, so that a misspelled path fails before any file is written.
+/// HTTPS URLs are checked when the template is fetched.
+fn validate_template(name: String) -> Result<(), String> {
+    if Path::new(&name).is_file() || remote::is_url(Path::new(&name)) {
+        return Ok(());
+    }
+
+    Err(format!("Template file {} not found", name))
+}
+
+/// Validates a given file name for a `SuiteScript` file.
+///
+/// The file name is checked for its extension. SuiteScript files must have a `.js` extension.
+fn validate_file_name(name: String) -> Result<(), String> {
+    let ext = validate_file(Path::new(&name))?;
+    if ext != Some("js") && ext != Some("ts") {
+        return Err(String::from(
+            "Invalid file type: SuiteScript file must be a JavaScript or TypeScript file.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates a file name against the naming convention of the project, if a config sets a
+/// `filename-pattern`.
+///
+/// The pattern is matched against the file name without its directory, such as `customer_ue.js`.
+///
+/// # Errors
+/// Returns an error explaining the convention if the file name does not match the pattern, or if
+/// the pattern is invalid
+pub fn validate_naming_convention(config: &ScriptSpec, path: &Path) -> Result<(), String> {
+    let pattern = match &config.file_name_pattern {
+        Some(pattern) => pattern,
+        None => return Ok(()),
+    };
+    let name = get_file_name(path);
+    let matched = Regex::new(pattern)
+        .map_err(|error| format!("Invalid filename-pattern {}: {}", pattern, error))?
+        .is_match(&name);
+    if matched {
+        return Ok(());
+    }
+
+    Err(describe_naming_convention(
+        &name,
+        pattern,
+        config.file_name_convention.as_deref(),
+    ))
+}
+
+/// Describes why a file name does not follow the naming convention of the project, with the
+/// `filename-convention` of the config if it sets one.
+pub fn describe_naming_convention(name: &str, pattern: &str, convention: Option<&str>) -> String {
+    match convention {
+        Some(convention) => format!(
+            "File name {} does not follow the naming convention of the project: {} (filename-pattern {})",
+            name, convention, pattern
+        ),
+        None => format!(
+            "File name {} does not follow the naming convention of the project, which requires names matching {}",
+            name, pattern
+        ),
+    }
+}
+
+/// Validates that the parent directory of a file exists, if the file name has one.
+///
+/// # Errors
+/// Returns an error suggesting `--create-dirs` if the parent directory does not exist
+pub fn validate_parent_dir(path: &Path) -> Result<(), String> {
+    let name = path.to_string_lossy();
+    if name.contains('/') || name.contains('\\') {
+        if let Some(parent) = path.parent() {
+            if !parent.is_dir() {
+                return Err(format!(
+                    "Parent directory {} does not exist, use --create-dirs to create it",
+                    parent.display()
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a given file name for an AMD config file.
+///
+/// AMD config files are required to be JSON files.
+fn validate_amd_config(name: String) -> Result<(), String> {
+    if validate_file(Path::new(&name))? != Some("json") {
+        return Err(String::from(
+            "Invalid file type: AMD config file must be a JSON file.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates a given `SuiteScript` script type against the list of supported script types.
+///
+/// Converts the given script name to lowercase to support mangled inputs. Checks the lowercase
+/// name against the list of supported script types in `assets/` and their abbreviations.
+fn validate_script_type(name: String) -> Result<(), String> {
+    if name.is_empty() {
+        return Ok(());
+    }
+
+    let lower_case = name.to_lowercase();
+    if TYPES.contains(&&lower_case[..]) || !map_script_to_name(&lower_case).is_empty() {
+        return Ok(());
+    }
+
+    Err(String::from("Invalid script type"))
+}
+
+/// Validates a given `SuiteScript` API version against the list of supported versions.
+fn validate_api_version(api: String) -> Result<(), String> {
+    if API.contains(&&api[..]) {
+        return Ok(());
+    }
+
+    Err(String::from("Invalid API version"))
+}
+
+/// Validates a given recipe name against the list of supported recipes.
+///
+/// Converts the given name to lowercase to support mangled inputs.
+fn validate_recipe(name: String) -> Result<(), String> {
+    let lower_case = name.to_lowercase();
+    if RECIPES.iter().any(|recipe| recipe.name == lower_case) {
+        return Ok(());
+    }
+
+    Err(format!("Invalid recipe name {}", name))
+}
+
+/// Validates a given entry point name against the entry points of a script type.
+///
+/// Converts the given name to lowercase to support mangled inputs.
+fn validate_entry_point(name: &str, entry_points: &[EntryPoint], kind: &str) -> Result<(), String> {
+    let lower_case = name.to_lowercase();
+    if entry_points
+        .iter()
+        .any(|entry_point| entry_point.name.to_lowercase() == lower_case)
+    {
+        return Ok(());
+    }
+
+    Err(format!("Invalid {} name {}", kind, name))
+}
+
+/// Validates a given user event entry point name.
+fn validate_event(name: String) -> Result<(), String> {
+    validate_entry_point(&name, &USER_EVENT, "event")
+}
+
+/// Validates a given client entry point name.
+fn validate_entry(name: String) -> Result<(), String> {
+    validate_entry_point(&name, &CLIENT, "entry point")
+}
+
+/// Validates a given RESTlet method name.
+fn validate_method(name: String) -> Result<(), String> {
+    validate_entry_point(&name, &RESTLET, "method")
+}
+
+/// Validates a given portlet type against the portlet types in `assets/`.
+///
+/// Converts the given type to lowercase to support mangled inputs.
+fn validate_portlet_type(name: String) -> Result<(), String> {
+    let lower_case = name.to_lowercase();
+    if lower_case.is_empty()
+        || PORTLET
+            .iter()
+            .any(|(portlet_type, _)| *portlet_type == lower_case)
+    {
+        return Ok(());
+    }
+
+    Err(format!("Invalid portlet type {}", name))
+}
+
+/// Validates a given script language, `js` or `ts`.
+fn validate_lang(lang: String) -> Result<(), String> {
+    match lang.as_str() {
+        "" | "js" | "ts" => Ok(()),
+        _ => Err(format!("Invalid language {}, expected js or ts", lang)),
+    }
+}
+
+/// Validates a given record type, such as `customer` or `customrecord_approval`.
+///
+/// Record types are written in the constants module of a user event generated with `--pair` and in
+/// the IDs of deployments, so they can only contain lowercase letters, digits, and underscores.
+fn validate_record(name: String) -> Result<(), String> {
+    let valid = name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !name.is_empty() && valid {
+        return Ok(());
+    }
+
+    Err(format!("Invalid record type {}", name))
+}
+
+/// Validates that a record type is a standard record type in `assets/`, a custom record type such
+/// as `customrecord_approval`, or one of the record types added by `record-types` in a config.
+fn validate_record_type(config: &ScriptSpec, name: &str) -> Result<(), String> {
+    if RECORD_TYPES.contains(&name)
+        || name.starts_with("customrecord")
+        || config.record_types.iter().any(|record| record == name)
+    {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Unknown record type {}, see `suitescript list records` for the standard record types, or add it to record-types in a config",
+        name
+    ))
+}
+
+/// Validates the ID of a script record, such as `customscript_customer_ue`.
+///
+/// Script IDs start with `customscript_`, are at most 40 characters long, and can only contain
+/// lowercase letters, digits, and underscores, which SDF validation would otherwise reject when the
+/// project is deployed.
+fn validate_script_id(id: String) -> Result<(), String> {
+    let name = id
+        .strip_prefix(sdfobject::SCRIPT_ID_PREFIX)
+        .unwrap_or_default();
+    if name.is_empty() {
+        return Err(format!(
+            "Invalid script ID {}, which must start with {} followed by a name",
+            id,
+            sdfobject::SCRIPT_ID_PREFIX
+        ));
+    }
+    if id.len() > sdfobject::MAX_ID_LENGTH {
+        return Err(format!(
+            "Invalid script ID {}, which is longer than {} characters",
+            id,
+            sdfobject::MAX_ID_LENGTH
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(format!(
+            "Invalid script ID {}, which can only contain lowercase letters, digits, and underscores",
+            id
+        ));
+    }
+    Ok(())
+}
+
+/// Validates a given deployment status against the statuses in `assets/`, ignoring case.
+fn validate_deploy_status(status: String) -> Result<(), String> {
+    if status.is_empty() || DEPLOYMENT_STATUSES.contains(&status.to_lowercase().as_str()) {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Invalid deployment status {}, expected testing or released",
+        status
+    ))
+}
+
+/// Validates a given log level against the log levels in `assets/`, ignoring case.
+fn validate_log_level(level: String) -> Result<(), String> {
+    if level.is_empty() || LOG_LEVELS.contains(&level.to_lowercase().as_str()) {
+        return Ok(());
+    }
+
+    Err(format!("Invalid log level {}", level))
+}
+
+/// Validates the ID of a role, such as `administrator` or `customrole_sales`.
+///
+/// Role IDs are written in the deployments of SDF objects, so they can only contain letters,
+/// digits, and underscores.
+fn validate_role(name: String) -> Result<(), String> {
+    let valid = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !name.is_empty() && valid {
+        return Ok(());
+    }
+
+    Err(format!("Invalid role {}", name))
+}
+
+/// Validates the internal ID of a department, such as `3`.
+///
+/// Departments have no script IDs in SDF objects, so they are referenced by their internal IDs.
+fn validate_department(id: String) -> Result<(), String> {
+    if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(());
+    }
+
+    Err(format!("Invalid department {}", id))
+}
+
+/// Validates a given module scope against the scopes in `assets/`.
+///
+/// Converts the given scope to lowercase to support mangled inputs.
+fn validate_scope(scope: String) -> Result<(), String> {
+    if scope.is_empty() || get_scope(&scope).is_some() {
+        return Ok(());
+    }
+
+    Err(format!("Invalid module scope {}", scope))
+}
+
+/// Validates a given `NetSuite` module name against the list of supported modules.
+///
+/// Converts the given module to lowercase to support mangled inputs. Checks the lowercase name
+/// against the list of supported modules in `assets/`. A `-` is accepted in place of a module name
+/// to read the modules from stdin.
+pub fn validate_modules(name: String) -> Result<(), String> {
+    if name.is_empty() || name == "-" {
+        return Ok(());
+    }
+
+    let lower_case = name.to_lowercase();
+    if !MODULES.contains(&&lower_case[..]) {
+        return Err(format!("Invalid module name {}", name));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_mod() {
+        assert_eq!(validate_modules(String::from("record")), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_mod() {
+        assert_eq!(
+            validate_modules(String::from("reecord")),
+            Err(String::from("Invalid module name reecord"))
+        );
+    }
+
+    #[test]
+    fn test_valid_api() {
+        assert_eq!(validate_api_version(String::from("2")), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_api() {
+        assert_eq!(
+            validate_api_version(String::from("1")),
+            Err(String::from("Invalid API version"))
+        );
+    }
+
+    #[test]
+    fn test_valid_script_type() {
+        assert_eq!(validate_script_type(String::from("mapreduce")), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_script_type() {
+        assert_eq!(
+            validate_script_type(String::from("rest")),
+            Err(String::from("Invalid script type"))
+        );
+    }
+
+    #[test]
+    fn test_valid_file() {
+        assert_eq!(validate_file(Path::new("test.js")), Ok(Some("js")));
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        assert_eq!(validate_file(Path::new("test")), Ok(None));
+    }
+
+    #[test]
+    fn test_valid_copyright() {
+        assert_eq!(
+            validate_copyright_file(String::from("copyright.txt")),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_valid_copyright_formats() {
+        for name in &["copyright.md", "copyright.js", "COPYRIGHT"] {
+            assert_eq!(validate_copyright_file(String::from(*name)), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_invalid_copyright() {
+        assert_eq!(
+            validate_copyright_file(String::from("copyright.pdf")),
+            Err(String::from(
+                "Invalid file type: copyright file must be a text, Markdown, or JavaScript file."
+            ))
+        );
+    }
+
+    #[test]
+    fn test_valid_script_file() {
+        assert_eq!(validate_file_name(String::from("test.js")), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_script_file() {
+        assert_eq!(
+            validate_file_name(String::from("test")),
+            Err(String::from(
+                "Invalid file type: SuiteScript file must be a JavaScript or TypeScript file."
+            ))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_non_unicode_extension() {
+        use std::os::unix::ffi::OsStrExt;
+        let path = Path::new(std::ffi::OsStr::from_bytes(b"test.j\xff"));
+        assert_eq!(
+            validate_file(path),
+            Err(String::from(
+                "Invalid file name test.j\u{fffd}: extension is not valid Unicode"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_naming_convention() {
+        let mut spec = ScriptSpec {
+            file_name_pattern: Some(String::from(r"^[a-z0-9_]+_(ue|cs|mr|sl|rl)\.js$")),
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            validate_naming_convention(&spec, Path::new("src/customer_ue.js")),
+            Ok(())
+        );
+        assert_eq!(
+            validate_naming_convention(&spec, Path::new("src/Customer.js")),
+            Err(String::from(
+                r"File name Customer.js does not follow the naming convention of the project, which requires names matching ^[a-z0-9_]+_(ue|cs|mr|sl|rl)\.js$"
+            ))
+        );
+
+        spec.file_name_convention = Some(String::from(
+            "lowercase words ending with the suffix of the script type, such as customer_ue.js",
+        ));
+        assert_eq!(
+            validate_naming_convention(&spec, Path::new("customer.js")),
+            Err(String::from(
+                r"File name customer.js does not follow the naming convention of the project: lowercase words ending with the suffix of the script type, such as customer_ue.js (filename-pattern ^[a-z0-9_]+_(ue|cs|mr|sl|rl)\.js$)"
+            ))
+        );
+
+        // Nested quantifiers are matched in linear time
+        spec.file_name_pattern = Some(String::from(r"^(a+)+$"));
+        assert!(
+            validate_naming_convention(&spec, Path::new(&format!("{}b", "a".repeat(64)))).is_err()
+        );
+    }
+
+    #[test]
+    fn test_valid_script_parent_dir() {
+        assert_eq!(validate_parent_dir(Path::new("src/test.js")), Ok(()));
+        assert_eq!(validate_parent_dir(Path::new("test.js")), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_script_parent_dir() {
+        assert_eq!(
+            validate_parent_dir(Path::new("nonexistent/test.js")),
+            Err(String::from(
+                "Parent directory nonexistent does not exist, use --create-dirs to create it"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_format_imports() {
+        assert_eq!(
+            format_imports(&["record".into(), "search".into()]),
+            String::from("record',\n  'N/search")
+        )
+    }
+
+    #[test]
+    fn test_format_args() {
+        assert_eq!(
+            format_args(&["record".into(), "search".into(), "ui/dialog".into()]),
+            String::from("record, search, uidialog")
+        )
+    }
+
+    #[test]
+    fn test_parse_module_list() {
+        assert_eq!(
+            parse_module_list("  'N/record',\n\nsearch\n\"N/ui/serverwidget\"\nrecord\n"),
+            vec![
+                String::from("record"),
+                String::from("search"),
+                String::from("ui/serverwidget")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_sort_modules() {
+        assert_eq!(
+            sort_modules(vec![
+                String::from("search"),
+                String::from("RECORD"),
+                String::from("record"),
+            ]),
+            vec![String::from("RECORD"), String::from("search")]
+        )
+    }
+
+    #[test]
+    fn test_get_mod_names() {
+        assert_eq!(
+            get_module_names(&[String::from("rEcOrD"), String::from("RECORDcontext")]),
+            vec![String::from("record"), String::from("recordContext")]
+        )
+    }
+
+    #[test]
+    fn test_map_script_name() {
+        assert_eq!(map_script_to_name("mApReDuCe"), "MapReduce")
+    }
+
+    #[test]
+    fn test_map_script_alias() {
+        assert_eq!(map_script_to_name("UE"), "UserEvent")
+    }
+
+    #[test]
+    fn test_valid_script_alias() {
+        assert_eq!(validate_script_type(String::from("sl")), Ok(()));
+    }
+
+    #[test]
+    fn test_infer_script_type() {
+        let suffixes = get_suffixes(&ScriptSpec::default());
+        assert_eq!(
+            infer_script_type(Path::new("src/customer_UE.js"), &suffixes),
+            Some(String::from("userevent"))
+        );
+        assert_eq!(infer_script_type(Path::new("customer.js"), &suffixes), None);
+
+        let spec = ScriptSpec {
+            suffixes: vec![
+                (String::from("_userevent"), String::from("ue")),
+                (String::from("_s"), String::from("suitelet")),
+            ],
+            ..ScriptSpec::default()
+        };
+        let suffixes = get_suffixes(&spec);
+        assert_eq!(
+            infer_script_type(Path::new("customer_userevent.js"), &suffixes),
+            Some(String::from("ue"))
+        );
+        assert_eq!(
+            infer_script_type(Path::new("customer_ue.js"), &suffixes),
+            None
+        );
+        assert_eq!(
+            infer_script_type(Path::new("nightly_ss.js"), &suffixes),
+            Some(String::from("scheduled"))
+        );
+    }
+
+    #[test]
+    fn test_get_script_type_alias() {
+        assert_eq!(get_script_type("cs"), " * @NScriptType ClientScript\n");
+        assert_eq!(get_script_type("sl"), " * @NScriptType Suitelet\n");
+    }
+
+    #[test]
+    fn test_format_raw_copyright() {
+        assert_eq!(
+            format_copyright("Copyright (c) MyCompany\n\nAll rights reserved."),
+            String::from("/**\n * Copyright (c) MyCompany\n *\n * All rights reserved.\n */")
+        )
+    }
+
+    #[test]
+    fn test_format_commented_copyright() {
+        assert_eq!(
+            format_copyright("/**\n * Copyright (c) MyCompany\n */"),
+            String::from("/**\n * Copyright (c) MyCompany\n */")
+        )
+    }
+
+    #[test]
+    fn test_format_line_comment_copyright() {
+        assert_eq!(
+            format_copyright("// Copyright (c) MyCompany\n// All rights reserved."),
+            String::from("// Copyright (c) MyCompany\n// All rights reserved.")
+        )
+    }
+
+    #[test]
+    fn test_write_script() {
+        let config =
+            ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "mr", "-m", "record"]);
+        let mut contents = Vec::new();
+        write_script(&mut contents, &config).unwrap();
+        assert_eq!(
+            String::from_utf8(contents).unwrap(),
+            format!(
+                "/**\n * @NScriptType MapReduceScript\n * @NApiVersion 2.1\n */\n\ndefine([\n  'N/record',\n], (record) => {{\n{}}});",
+                get_body(&[], &[])
+            )
+        )
+    }
+
+    #[test]
+    fn test_format_type_name() {
+        assert_eq!(format_type_name("ui/serverWidget"), "NUiServerWidget");
+        assert_eq!(format_type_name("currentRecord"), "NCurrentRecord");
+    }
+
+    #[test]
+    fn test_write_ts_check_script() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            "test.js",
+            "--ts-check",
+            "-m",
+            "ui/message",
+        ]);
+        let mut contents = Vec::new();
+        write_script(&mut contents, &config).unwrap();
+        assert_eq!(
+            String::from_utf8(contents).unwrap(),
+            format!(
+                "// @ts-check\n/**\n * @typedef {{typeof import('N/ui/message')}} NUiMessage\n */\n\n/**\n * @NApiVersion 2.1\n */\n\ndefine([\n  'N/ui/message',\n],\n/**\n * @param {{NUiMessage}} uimessage\n */\n(uimessage) => {{\n{}}});",
+                get_body(&[], &[])
+            )
+        )
+    }
+
+    #[test]
+    fn test_provenance() {
+        let config =
+            ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-m", "record", "search"]);
+        let mangled =
+            ScriptSpec::from_iter(&["suitescript", "-f", "other.js", "-m", "RECORD", "Search"]);
+        let typed = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "mr"]);
+        assert_eq!(
+            hash_options(&config).unwrap(),
+            hash_options(&mangled).unwrap()
+        );
+        assert_ne!(
+            hash_options(&config).unwrap(),
+            hash_options(&typed).unwrap()
+        );
+        assert!(get_provenance(&config).unwrap().starts_with(&format!(
+            "// suitescript:provenance version={} template=default options=",
+            env!("CARGO_PKG_VERSION")
+        )));
+    }
+
+    #[test]
+    fn test_valid_recipe() {
+        assert_eq!(validate_recipe(String::from("Cache")), Ok(()));
+        assert_eq!(
+            validate_recipe(String::from("cash")),
+            Err(String::from("Invalid recipe name cash"))
+        );
+    }
+
+    #[test]
+    fn test_recipe_triggered_by_module() {
+        let config = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-m", "CACHE"]);
+        let names: Vec<&str> = get_recipes(&config).iter().map(|r| r.name).collect();
+        assert_eq!(names, vec!["cache"]);
+    }
+
+    #[test]
+    fn test_add_recipe_modules() {
+        let config = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-r", "cache"]);
+        assert_eq!(
+            add_recipe_modules(config.modules.clone(), &get_recipes(&config)).0,
+            vec![String::from("cache")]
+        );
+    }
+
+    #[test]
+    fn test_add_missing_recipe_modules() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            "test.js",
+            "-r",
+            "workflow",
+            "-m",
+            "RUNTIME",
+        ]);
+        assert_eq!(
+            add_recipe_modules(config.modules.clone(), &get_recipes(&config)).0,
+            vec![String::from("RUNTIME"), String::from("workflow")]
+        );
+    }
+
+    #[test]
+    fn test_body_with_recipe() {
+        let body = get_body(&[&RECIPES[0]], &[]);
+        assert!(body.starts_with("  // <suitescript:keep cache>\n  /**\n"));
+        assert!(body.contains("scriptCache.get({ key, loader, ttl: CACHE_TTL })"));
+        assert!(body.ends_with("};\n  // </suitescript:keep>\n"));
+    }
+
+    #[test]
+    fn test_body_with_entry_points() {
+        let body = get_body(&[], &[&SUITECOMMERCE[0]]);
+        assert!(body.contains("   *\n   * @param {Object} container"));
+        assert!(body.contains(
+            "  const mountToApp = (container) => {\n    // <suitescript:keep mountToApp>\n"
+        ));
+        assert!(body.ends_with("    // </suitescript:keep>\n  };\n\n  return { mountToApp };\n"));
+    }
+
+    #[test]
+    fn test_valid_event() {
+        assert_eq!(validate_event(String::from("AFTERSUBMIT")), Ok(()));
+        assert_eq!(
+            validate_event(String::from("afterLoad")),
+            Err(String::from("Invalid event name afterLoad"))
+        );
+    }
+
+    #[test]
+    fn test_user_event_entry_points() {
+        let all = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "ue"]);
+        let selected = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            "test.js",
+            "-s",
+            "ue",
+            "--events",
+            "afterSubmit,beforeload",
+        ]);
+        let names = |config: &ScriptSpec| -> Vec<&str> {
+            get_entry_points(config)
+                .iter()
+                .map(|entry_point| entry_point.name)
+                .collect()
+        };
+        assert_eq!(
+            names(&all),
+            vec!["beforeLoad", "beforeSubmit", "afterSubmit"]
+        );
+        assert_eq!(names(&selected), vec!["beforeLoad", "afterSubmit"]);
+    }
+
+    #[test]
+    fn test_client_entry_points() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            "test.js",
+            "-s",
+            "client",
+            "--entries",
+            "saveRecord,pageInit",
+        ]);
+        let body = get_body(&[], &get_entry_points(&config));
+        assert!(body.contains("  const pageInit = (scriptContext) => {\n"));
+        assert!(body.contains("   * @returns {boolean} Whether the record is valid\n"));
+        assert!(body.contains("  return { pageInit, saveRecord };\n"));
+        assert!(validate_entry(String::from("onInit")).is_err());
+    }
+
+    #[test]
+    fn test_restlet_entry_points() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            "test.js",
+            "-s",
+            "restlet",
+            "--methods",
+            "DELETE,get",
+        ]);
+        let body = get_body(&[], &get_entry_points(&config));
+        assert!(body.contains("  const doDelete = (requestParams) => {\n"));
+        assert!(body.contains("  return { get: doGet, delete: doDelete };\n"));
+        assert!(validate_method(String::from("patch")).is_err());
+    }
+
+    #[test]
+    fn test_suitelet_entry_points() {
+        let plain = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "sl"]);
+        let form = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            "test.js",
+            "-s",
+            "sl",
+            "-m",
+            "ui/serverWidget",
+        ]);
+        let body = get_body(&[], &get_entry_points(&plain));
+        assert!(body.contains("    if (context.request.method === 'GET') {\n"));
+        assert!(body.contains("  return { onRequest };\n"));
+        assert!(!body.contains("createForm"));
+        assert!(get_body(&[], &get_entry_points(&form)).contains("uiserverwidget.createForm("));
+    }
+
+    #[test]
+    fn test_scheduled_entry_points() {
+        let plain = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "ss"]);
+        let runtime =
+            ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "ss", "-m", "runtime"]);
+        let body = get_body(&[], &get_entry_points(&plain));
+        assert!(body.contains("  const execute = (context) => {\n"));
+        assert!(!body.contains("getRemainingUsage"));
+        assert!(get_body(&[], &get_entry_points(&runtime))
+            .contains("    // if (runtime.getCurrentScript().getRemainingUsage() < 100) {\n"));
+    }
+
+    #[test]
+    fn test_portlet_type() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-s",
+            "portlet",
+            "--portlet-type",
+            "LIST",
+            "--header-only",
+        ]);
+        let mut header = Vec::new();
+        write_header(&mut header, &config).unwrap();
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            "/**\n * @NScriptType Portlet\n * @NScriptPortletType list\n * @NApiVersion 2.1\n */\n\n"
+        );
+        assert!(get_body(&[], &get_entry_points(&config)).contains("params.portlet.addColumn("));
+        assert_eq!(
+            get_portlet_type(&ScriptSpec::from_iter(&[
+                "suitescript",
+                "-f",
+                "test.js",
+                "-s",
+                "portlet"
+            ])),
+            Some(String::from("html"))
+        );
+        assert!(validate_portlet_type(String::from("chart")).is_err());
+    }
+
+    #[test]
+    fn test_bundle_installation() {
+        assert_eq!(
+            get_script_type("BundleInstallation"),
+            " * @NScriptType BundleInstallationScript\n"
+        );
+        let config =
+            ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "bundleinstallation"]);
+        assert!(get_body(&[], &get_entry_points(&config)).contains(
+            "  return { beforeInstall, afterInstall, beforeUpdate, afterUpdate, beforeUninstall };\n"
+        ));
+    }
+
+    #[test]
+    fn test_mass_update() {
+        assert_eq!(
+            get_script_type("massupdate"),
+            " * @NScriptType MassUpdateScript\n"
+        );
+        let config = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "MassUpdate"]);
+        assert!(
+            get_body(&[], &get_entry_points(&config)).contains("  const each = (params) => {\n")
+        );
+    }
+
+    #[test]
+    fn test_workflow_action() {
+        assert_eq!(
+            get_script_type("wa"),
+            " * @NScriptType WorkflowActionScript\n"
+        );
+        assert_eq!(
+            infer_script_type(
+                Path::new("approve_wa.js"),
+                &get_suffixes(&ScriptSpec::default())
+            ),
+            Some(String::from("workflowaction"))
+        );
+        let config =
+            ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "workflowaction"]);
+        assert!(get_body(&[], &get_entry_points(&config)).contains("    // return 'T';\n"));
+    }
+
+    #[test]
+    fn test_sdf_installation() {
+        assert_eq!(
+            get_script_type("SdfInstallation"),
+            " * @NScriptType SDFInstallationScript\n"
+        );
+        let config =
+            ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "sdfinstallation"]);
+        assert!(get_body(&[], &get_entry_points(&config)).contains("  const run = (params) => {\n"));
+    }
+
+    #[test]
+    fn test_custom_gl_plugin() {
+        let config = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "customgl"]);
+        let mut contents = Vec::new();
+        write_script(&mut contents, &config).unwrap();
+        let contents = String::from_utf8(contents).unwrap();
+        assert!(contents.starts_with("/**\n * Customizes the GL impact of a transaction.\n"));
+        assert!(contents.contains(
+            "function customizeGlImpact(transactionRecord, standardLines, customLines, book) {\n"
+        ));
+        assert!(!contents.contains("define("));
+        assert!(!contents.contains("@NApiVersion"));
+    }
+
+    #[test]
+    fn test_library() {
+        let config = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-s", "module"]);
+        let mut contents = Vec::new();
+        write_script(&mut contents, &config).unwrap();
+        let contents = String::from_utf8(contents).unwrap();
+        assert!(contents.starts_with("/**\n * @NApiVersion 2.1\n */\n"));
+        assert!(contents.contains("  const example = () => {\n"));
+        assert!(contents.contains("  return { example };\n"));
+    }
+
+    #[test]
+    fn test_module_scope() {
+        let config =
+            ScriptSpec::from_iter(&["suitescript", "--header-only", "--scope", "sameaccount"]);
+        let mut header = Vec::new();
+        write_header(&mut header, &config).unwrap();
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            "/**\n * @NApiVersion 2.1\n * @NModuleScope SameAccount\n */\n\n"
+        );
+        assert!(validate_scope(String::from("Private")).is_err());
+    }
+
+    #[test]
+    fn test_amd_config_tag() {
+        let config =
+            ScriptSpec::from_iter(&["suitescript", "--header-only", "--amdconfig", "./amd.json"]);
+        let mut header = Vec::new();
+        write_header(&mut header, &config).unwrap();
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            "/**\n * @NApiVersion 2.1\n * @NAmdConfig ./amd.json\n */\n\n"
+        );
+        assert!(validate_amd_config(String::from("amd.js")).is_err());
+    }
+
+    #[test]
+    fn test_author_and_description() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "--header-only",
+            "--author",
+            "Jane Doe",
+            "--description",
+            "Syncs customers",
+        ]);
+        let mut header = Vec::new();
+        write_header(&mut header, &config).unwrap();
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            "/**\n * @NApiVersion 2.1\n * @author Jane Doe\n * @description Syncs customers\n */\n\n"
+        );
+    }
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(
+            interpolate(
+                "Copyright (c) {{year}} {{company}}, {{file}} {{unknown}}",
+                &[
+                    ("year", "2021"),
+                    ("company", "MyCompany"),
+                    ("file", "test.js")
+                ]
+            ),
+            "Copyright (c) 2021 MyCompany, test.js {{unknown}}"
+        );
+    }
+
+    #[test]
+    fn test_interpolate_single_pass() {
+        assert_eq!(
+            interpolate("{{a}} {{b}", &[("a", "{{b}}"), ("b", "x")]),
+            "{{b}} {{b}"
+        );
+    }
+
+    #[test]
+    fn test_default_template() {
+        let config = ScriptSpec::from_iter(&["suitescript", "-f", "test.js", "-m", "record"]);
+        let mut expected = Vec::new();
+        write!(
+            expected,
+            "{}define([\n{}{}}});",
+            render_header(&config).unwrap(),
+            get_modules(&config.modules, &[], false),
+            get_body(&[], &get_entry_points(&config))
+        )
+        .unwrap();
+        assert_eq!(render(&config).unwrap().into_bytes(), expected);
+    }
+
+    #[test]
+    fn test_render_template() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            "customers_mr.js",
+            "-s",
+            "mapreduce",
+            "--no-header",
+        ]);
+        let mut script = Vec::new();
+        render_template(
+            &mut script,
+            "// {{file}} {{scripttype}} {{apiversion}}\n{{header}}{{define}}",
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(script).unwrap(),
+            "// customers_mr.js MapReduce 2.1\ndefine([\n], () => {\n"
+        );
+    }
+
+    #[test]
+    fn test_render_template_helpers() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            "customer_ue.js",
+            "-m",
+            "record",
+            "search",
+            "--no-header",
+        ]);
+        let mut script = Vec::new();
+        render_template(
+            &mut script,
+            "{{#if header}}header{{else}}no header{{/if}}: {{#each modules}}{{this}};{{/each}}",
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(script).unwrap(),
+            "no header: N/record;N/search;"
+        );
+    }
+
+    #[test]
+    fn test_invalid_template() {
+        let config = ScriptSpec {
+            template: Some(PathBuf::from("layout.hbs")),
+            ..ScriptSpec::default()
+        };
+        let error =
+            render_template(&mut Vec::new(), "{{#if header}}{{body}}", &config).unwrap_err();
+        assert_eq!(error.kind(), "template");
+        assert!(error
+            .to_string()
+            .starts_with("Failed to load template file layout.hbs: "));
+    }
+
+    #[test]
+    fn test_find_type_template() {
+        let dir =
+            std::env::temp_dir().join(format!("suitescript-templates-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("userevent.hbs"), "{{define}}{{body}}});").unwrap();
+        std::fs::write(dir.join("userevent.tpl"), "{{define}}{{body}}});").unwrap();
+        std::fs::write(dir.join("userevent.ts.tpl"), "{{define}}{{body}}").unwrap();
+
+        let user_event = find_type_template(&dir, "ue", false);
+        let typescript = find_type_template(&dir, "ue", true);
+        let map_reduce = find_type_template(&dir, "mapreduce", false);
+        let unset = find_type_template(&dir, "", false);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(user_event, Some(dir.join("userevent.hbs")));
+        assert_eq!(typescript, Some(dir.join("userevent.ts.tpl")));
+        assert_eq!(map_reduce, None);
+        assert_eq!(unset, None);
+    }
+
+    #[test]
+    fn test_companion_files() {
+        let dir =
+            std::env::temp_dir().join(format!("suitescript-companions-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("fixtures/customer_rl")).unwrap();
+        std::fs::write(dir.join("fixtures/customer_rl/get.json"), "{}").unwrap();
+        let config = ScriptSpec {
+            file_name: Some(dir.join("customer_rl.js")),
+            script_type: String::from("restlet"),
+            with_harness: true,
+            postman: true,
+            ..ScriptSpec::default()
+        };
+
+        let paths: Vec<PathBuf> = get_companion_files(&config)
+            .unwrap()
+            .into_iter()
+            .map(|file| file.path)
+            .collect();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                dir.join("customer_rl.harness.js"),
+                dir.join("fixtures/customer_rl/post.json"),
+                dir.join("customer_rl.postman_collection.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pair_suitelet() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_sl.js")),
+            script_type: String::from("suitelet"),
+            pair: true,
+            ..ScriptSpec::default()
+        };
+        let (config, notices) = prepare(&spec).unwrap();
+        assert_eq!(config.modules, vec!["ui/serverwidget"]);
+        assert_eq!(notices.len(), 1);
+        let (imported, _) = prepare(&ScriptSpec {
+            modules: vec![String::from("ui/serverWidget")],
+            ..spec.clone()
+        })
+        .unwrap();
+        assert_eq!(imported.modules, vec!["ui/serverWidget"]);
+
+        let script = render(&config).unwrap();
+        assert!(script.contains(" * @see ./customer_cs.js Client script attached to the form\n"));
+        assert!(script.contains("form.clientScriptModulePath = './customer_cs.js';"));
+        assert!(check_conflicts(&ScriptSpec {
+            script_type: String::from("restlet"),
+            ..config
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_dependencies() {
+        assert_eq!(
+            get_modules(&[String::new()], &[String::from("./customer_constants")], true),
+            "  './customer_constants',\n],\n/**\n * @param {typeof import('./customer_constants')} customerConstants\n */\n(customerConstants) => {\n"
+        );
+        assert_eq!(format_dependency_arg("../lib/sales-order"), "salesOrder");
+        assert!(validate_record(String::from("customrecord_approval")).is_ok());
+        assert!(validate_record(String::from("Sales Order")).is_err());
+    }
+
+    #[test]
+    fn test_write_atomically() {
+        let dir = std::env::temp_dir().join(format!("suitescript-atomic-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("customer_ue.js");
+        std::fs::write(&path, "old").unwrap();
+
+        write_atomically(&path, "new").unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let entries = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(contents, "new");
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn test_backup_path() {
+        assert_eq!(
+            get_backup_path(Path::new("src/customer_ue.js"), 1_704_110_400),
+            PathBuf::from("src/customer_ue.js.bak-20240101T1200")
+        );
+        assert_eq!(
+            get_backup_path(Path::new("customer_ue.js"), 951_827_696),
+            PathBuf::from("customer_ue.js.bak-20000229T1234")
+        );
+    }
+
+    #[test]
+    fn test_missing_copyright_file() {
+        let config = ScriptSpec::from_iter(&["suitescript", "--header-only", "-c", "missing.txt"]);
+        let error = render_header(&config).unwrap_err();
+        assert!(matches!(error, SuiteScriptError::Io { .. }), "{:?}", error);
+        assert!(
+            error
+                .to_string()
+                .starts_with("Failed to read copyright file missing.txt: "),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_missing_template_file() {
+        let config = ScriptSpec {
+            template: Some(PathBuf::from("missing.tpl")),
+            ..ScriptSpec::default()
+        };
+        match render(&config).unwrap_err() {
+            SuiteScriptError::Template { path, .. } => assert_eq!(path, Path::new("missing.tpl")),
+            error => panic!("{:?}", error),
+        }
+    }
+
+    #[test]
+    fn test_copyright_text() {
+        let config = ScriptSpec::from_iter(&[
+            "suitescript",
+            "--header-only",
+            "--copyright-text",
+            "Copyright (c) MyCompany\\nAll rights reserved.",
+        ]);
+        assert_eq!(
+            get_copyright(&config).unwrap(),
+            "/**\n * Copyright (c) MyCompany\n * All rights reserved.\n */\n\n"
+        );
+    }
+
+    #[test]
+    fn test_directory_copyright() {
+        let dir =
+            std::env::temp_dir().join(format!("suitescript-copyrights-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("vendor.txt"), "Copyright (c) Vendor").unwrap();
+        std::fs::write(dir.join("lib.txt"), "Copyright (c) Vendor Library").unwrap();
+        let spec = |file: &str| ScriptSpec {
+            file_name: Some(dir.join(file)),
+            copyright_text: Some(String::from("Copyright (c) MyCompany")),
+            copyrights: vec![
+                (dir.join("vendor"), dir.join("vendor.txt")),
+                (dir.join("vendor/lib"), dir.join("lib.txt")),
+            ],
+            ..ScriptSpec::default()
+        };
+        let vendor = get_copyright(&spec("vendor/customer_ue.js"));
+        let library = get_copyright(&spec("vendor/lib/utils.js"));
+        let own = get_copyright(&spec("src/customer_ue.js"));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(vendor.unwrap(), "/**\n * Copyright (c) Vendor\n */\n\n");
+        assert_eq!(
+            library.unwrap(),
+            "/**\n * Copyright (c) Vendor Library\n */\n\n"
+        );
+        assert_eq!(own.unwrap(), "/**\n * Copyright (c) MyCompany\n */\n\n");
+    }
+
+    #[test]
+    fn test_year_from_days() {
+        assert_eq!(year_from_days(0), 1970);
+        assert_eq!(year_from_days(18_627), 2020);
+        assert_eq!(year_from_days(18_628), 2021);
+        assert_eq!(year_from_days(-1), 1969);
+    }
+
+    #[test]
+    fn test_annotate_costs() {
+        assert_eq!(
+            annotate_costs("    // {cost:workflow.initiate}\n    // {cost:record.load}"),
+            "    // workflow.initiate costs 20 governance units\n    // record.load costs 2 to 10 governance units, depending on the record type"
+        );
+    }
+
+    #[test]
+    fn test_recipe_costs() {
+        for recipe in &RECIPES {
+            assert!(!annotate_costs(recipe.snippet).contains("{cost:"));
+        }
+    }
+
+    #[test]
+    fn test_suitecommerce_unsupported() {
+        let modules = vec![
+            String::from("record"),
+            String::from("UI/Message"),
+            String::from("task"),
+        ];
+        assert_eq!(
+            get_suitecommerce_unsupported(&modules),
+            vec![String::from("ui/message"), String::from("task")]
+        );
+    }
+
+    #[test]
+    fn test_preserve_keep_regions() {
+        let previous = "define([\n], () => {\n  // <suitescript:keep>\n  const x = 1;\n  // </suitescript:keep>\n});";
+        let generated = format!(
+            "define([\n  'N/record',\n], (record) => {{\n{}}});",
+            get_body(&[], &[])
+        );
+        assert_eq!(
+            preserve_keep_regions(&generated, previous),
+            (
+                String::from("define([\n  'N/record',\n], (record) => {\n  // <suitescript:keep>\n  const x = 1;\n  // </suitescript:keep>\n});"),
+                Vec::new()
+            )
+        )
+    }
+
+    #[test]
+    fn test_preserve_entry_point_bodies() {
+        let spec = |events: &[&str]| ScriptSpec {
+            file_name: Some(PathBuf::from("customer_ue.js")),
+            events: events.iter().map(|event| String::from(*event)).collect(),
+            ..ScriptSpec::default()
+        };
+        let previous = generate(&spec(&["beforeLoad"]))
+            .unwrap()
+            .replace("    // TODO", "    log.debug('edited');\n    // TODO");
+        let (regenerated, dropped) = preserve_keep_regions(
+            &generate(&spec(&["beforeLoad", "afterSubmit"])).unwrap(),
+            &previous,
+        );
+        assert!(dropped.is_empty());
+        assert!(regenerated.contains(
+            "  const beforeLoad = (scriptContext) => {\n    // <suitescript:keep beforeLoad>\n    log.debug('edited');\n"
+        ));
+        assert!(regenerated.contains("  const afterSubmit = (scriptContext) => {\n"));
+        assert_eq!(regenerated.matches("log.debug('edited');").count(), 1);
+    }
+
+    #[test]
+    fn test_keep_region_writer_in_chunks() {
+        let previous = "  // <suitescript:keep>\r\n  const x = 1;\r\n  // </suitescript:keep>\r\n";
+        let generated =
+            "define([\n], () => {\n  // <suitescript:keep>\n\n  // </suitescript:keep>\n});\n";
+        let mut merged = Vec::new();
+        let mut writer = KeepRegionWriter::new(&mut merged, previous);
+        for chunk in generated.as_bytes().chunks(5) {
+            writer.write_all(chunk).unwrap();
+        }
+        assert!(writer.finish().unwrap().is_empty());
+        assert_eq!(
+            String::from_utf8(merged).unwrap(),
+            preserve_keep_regions(generated, previous).0
+        );
+    }
+
+    #[test]
+    fn test_preserve_dropped_keep_region() {
+        let spec = |events: &[&str]| ScriptSpec {
+            file_name: Some(PathBuf::from("customer_ue.js")),
+            events: events.iter().map(|event| String::from(*event)).collect(),
+            ..ScriptSpec::default()
+        };
+        let previous = generate(&spec(&["beforeLoad", "afterSubmit"]))
+            .unwrap()
+            .replace("    // TODO", "    log.debug('edited');\n    // TODO");
+        let (regenerated, dropped) =
+            preserve_keep_regions(&generate(&spec(&["beforeLoad"])).unwrap(), &previous);
+        assert_eq!(regenerated.matches("log.debug('edited');").count(), 1);
+        assert_eq!(
+            dropped,
+            vec![String::from("// <suitescript:keep afterSubmit>")]
+        );
+
+        let path = Path::new("customer_ue.js");
+        let error = check_dropped_keep_regions(path, &dropped, false).unwrap_err();
+        assert_eq!(error.kind(), "validation");
+        assert_eq!(
+            error.to_string(),
+            "customer_ue.js has keep regions that are no longer generated: // <suitescript:keep afterSubmit>. Pass --force to drop them"
+        );
+        assert_eq!(
+            check_dropped_keep_regions(path, &dropped, true).unwrap(),
+            vec![Notice::Warning(String::from(
+                "dropped the keep region `// <suitescript:keep afterSubmit>` of customer_ue.js, which is no longer generated"
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_write_script_file_keeps_dropped_region() {
+        let dir = std::env::temp_dir().join(format!("suitescript-dropped-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("customer_ue.js");
+        let config = |events: &str, force: bool| ScriptSpec {
+            file_name: Some(path.clone()),
+            events: vec![String::from(events)],
+            force,
+            ..prepare(&ScriptSpec::from_iter(&[
+                "suitescript",
+                "-f",
+                "customer_ue.js",
+            ]))
+            .unwrap()
+            .0
+        };
+        write_script_file(&config("afterSubmit", false), &path, None).unwrap();
+        let edited = std::fs::read_to_string(&path)
+            .unwrap()
+            .replace("    // TODO", "    log.debug('edited');");
+        std::fs::write(&path, &edited).unwrap();
+
+        let refused = write_script_file(&config("beforeLoad", false), &path, None);
+        let unchanged = std::fs::read_to_string(&path).unwrap();
+        let forced = write_script_file(&config("beforeLoad", true), &path, None).unwrap();
+        let regenerated = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(refused.unwrap_err().kind(), "validation");
+        assert_eq!(unchanged, edited);
+        assert_eq!(forced.0.len(), 1);
+        assert!(!regenerated.contains("log.debug('edited');"));
+    }
+
+    #[test]
+    fn test_write_script_file() {
+        let dir = std::env::temp_dir().join(format!("suitescript-stream-{}", std::process::id()));
+        let path = dir.join("src/customer_ue.js");
+        let config = prepare(&ScriptSpec::from_iter(&[
+            "suitescript",
+            "-f",
+            path.to_str().unwrap(),
+            "-s",
+            "userevent",
+            "--events",
+            "beforeLoad",
+            "--no-header",
+        ]))
+        .unwrap()
+        .0;
+        write_script_file(&config, &path, None).unwrap();
+        let generated = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(
+            &path,
+            generated.replace("    // TODO", "    log.debug('edited');"),
+        )
+        .unwrap();
+        write_script_file(&config, &path, None).unwrap();
+        let regenerated = std::fs::read_to_string(&path).unwrap();
+        let entries = std::fs::read_dir(path.parent().unwrap()).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(generated, render(&config).unwrap());
+        assert!(regenerated.contains("log.debug('edited');"));
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn test_write_atomically_with_error() {
+        let dir = std::env::temp_dir().join(format!("suitescript-failed-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("customer_ue.js");
+        std::fs::write(&path, "old").unwrap();
+
+        let error = write_atomically_with(&path, |out| {
+            out.write_all(b"partial").unwrap();
+            Err(SuiteScriptError::Validation(String::from("failed")))
+        })
+        .unwrap_err();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let entries = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(error.to_string(), "failed");
+        assert_eq!(contents, "old");
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn test_preserve_without_keep_regions() {
+        let generated = format!("define([\n], () => {{\n{}}});", get_body(&[], &[]));
+        assert_eq!(
+            preserve_keep_regions(&generated, "// legacy script"),
+            (generated, Vec::new())
+        )
+    }
+
+    #[test]
+    fn test_generate() {
+        let spec = ScriptSpec {
+            script_type: String::from("restlet"),
+            modules: vec![String::from("record")],
+            methods: vec![String::from("get")],
+            ..ScriptSpec::default()
+        };
+        let script = generate(&spec).unwrap();
+        assert!(script.starts_with("/**\n * @NScriptType RESTlet\n * @NApiVersion 2.1\n */\n"));
+        assert!(script.contains("define([\n  'N/record',\n], (record) => {"));
+        assert!(script.contains("get: doGet"));
+    }
+
+    #[test]
+    fn test_generate_conflict() {
+        let spec = ScriptSpec {
+            script_type: String::from("client"),
+            methods: vec![String::from("get")],
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            generate(&spec).unwrap_err().to_string(),
+            "--methods requires the restlet script type"
+        );
+    }
+
+    #[test]
+    fn test_deployment_conflicts() {
+        let spec = ScriptSpec {
+            script_type: String::from("suitelet"),
+            sdf_object: true,
+            deployments: vec![String::from("salesorder")],
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            generate(&spec).unwrap_err().to_string(),
+            "--deployments requires the userevent or client script type"
+        );
+
+        let spec = ScriptSpec {
+            script_type: String::from("userevent"),
+            sdf_object: true,
+            log_level: String::from("audit"),
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            generate(&spec).unwrap_err().to_string(),
+            "--log-level requires --deployments"
+        );
+
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_credit_limit_approval_ue.js")),
+            script_type: String::from("userevent"),
+            sdf_object: true,
+            deployments: vec![String::from("salesorder")],
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            generate(&spec).unwrap_err().to_string(),
+            "Deployment ID customdeploy_customer_credit_limit_appro_salesorder is longer than 40 characters, shorten the script ID with --script-id"
+        );
+    }
+
+    #[test]
+    fn test_validate_record_type() {
+        let mut spec = ScriptSpec {
+            record_types: vec![String::from("customtransaction_expense")],
+            ..ScriptSpec::default()
+        };
+        assert!(validate_record_type(&spec, "salesorder").is_ok());
+        assert!(validate_record_type(&spec, "customrecord_approval").is_ok());
+        assert!(validate_record_type(&spec, "customtransaction_expense").is_ok());
+        assert_eq!(
+            validate_record_type(&spec, "salesordr"),
+            Err(String::from("Unknown record type salesordr, see `suitescript list records` for the standard record types, or add it to record-types in a config"))
+        );
+
+        spec.script_type = String::from("userevent");
+        spec.sdf_object = true;
+        spec.deployments = vec![String::from("salesordr")];
+        assert_eq!(
+            generate(&spec).unwrap_err().to_string(),
+            "Unknown record type salesordr, see `suitescript list records` for the standard record types, or add it to record-types in a config"
+        );
+    }
+
+    #[test]
+    fn test_validate_script_id() {
+        assert!(validate_script_id(String::from("customscript_customer_ue")).is_ok());
+        assert!(validate_script_id(String::from("customer_ue")).is_err());
+        assert!(validate_script_id(String::from("customscript_")).is_err());
+        assert!(validate_script_id(String::from("customscript_Customer-UE")).is_err());
+        assert!(validate_script_id(format!("customscript_{}", "a".repeat(28))).is_err());
+    }
+
+    #[test]
+    fn test_generate_invalid_module() {
+        let spec = ScriptSpec {
+            modules: vec![String::from("notamodule")],
+            ..ScriptSpec::default()
+        };
+        match generate(&spec).unwrap_err() {
+            SuiteScriptError::Validation(message) => {
+                assert_eq!(message, "Invalid module name notamodule")
+            }
+            error => panic!("{:?}", error),
+        }
+    }
+
+    #[test]
+    fn test_format_json_result() {
+        let spec =
+            ScriptSpec::from_iter(&["suitescript", "-f", "a_ue.js", "-m", "search", "record"]);
+        let (config, notices) = prepare(&spec).unwrap();
+        let companions = vec![OutputFile {
+            path: PathBuf::from("amd.json"),
+            contents: String::new(),
+        }];
+        let result = format_json_result(&config, Path::new("a_ue.js"), &companions, &notices);
+        assert!(result.ends_with("}\n"));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&result).unwrap(),
+            serde_json::json!({
+                "path": "a_ue.js",
+                "type": "UserEvent",
+                "modules": ["N/search", "N/record"],
+                "files": ["amd.json"],
+                "warnings": ["inferred script type `userevent` from the file name"],
+                "notes": [],
+            })
+        );
+    }
+
+    #[test]
+    fn test_format_plan() {
+        let spec = ScriptSpec::from_iter(&["suitescript", "-f", "a_rl.js", "-m", "record"]);
+        let (config, _) = prepare(&spec).unwrap();
+        let script = OutputFile {
+            path: PathBuf::from("a_rl.js"),
+            contents: render(&config).unwrap(),
+        };
+        let companions = vec![OutputFile {
+            path: PathBuf::from("a_rl.harness.js"),
+            contents: String::new(),
+        }];
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&format_plan(&config, &script, &companions))
+                .unwrap(),
+            serde_json::json!({
+                "files": [
+                    {
+                        "path": "a_rl.js",
+                        "template": null,
+                        "tags": { "@NScriptType": "RESTlet", "@NApiVersion": "2.1" },
+                        "modules": ["N/record"],
+                    },
+                    { "path": "a_rl.harness.js" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_header_tags() {
+        let contents = "/**\n * Copyright\n */\n\n/**\n * @NApiVersion 2.1\n * @NModuleScope\n */\n\ndefine([], () => {\n  /**\n   * @param {Object} context\n   */\n});\n";
+        assert_eq!(
+            get_header_tags(contents),
+            vec![("@NApiVersion", "2.1"), ("@NModuleScope", "")]
+        );
+    }
+
+    #[test]
+    fn test_prepare_notices() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_ue.js")),
+            recipes: vec![String::from("cache")],
+            ..ScriptSpec::default()
+        };
+        let (config, notices) = prepare(&spec).unwrap();
+        assert_eq!(config.script_type, "userevent");
+        assert_eq!(
+            notices,
+            vec![
+                Notice::Note(String::from(
+                    "added module `cache` needed by the `cache` recipe"
+                )),
+                Notice::Warning(String::from(
+                    "inferred script type `userevent` from the file name"
+                )),
+            ]
+        );
+    }
+}
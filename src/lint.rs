@@ -0,0 +1,363 @@
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::assets::netsuite_types::{API, MODULES};
+use super::audit::{find_scripts, format_csv, format_json, get_tag, print_findings};
+use super::config::load_spec;
+use super::formatter::{get_dependencies, split_define};
+use super::{
+    get_module_path, get_script_type, get_suffixes, infer_script_type, map_script_to_name,
+    read_to_string, SuiteScriptError,
+};
+
+/// Options for linting existing scripts.
+#[derive(StructOpt, Debug)]
+pub struct LintOpt {
+    /// Script, or directory of scripts, to lint
+    #[structopt(parse(from_os_str))]
+    path: PathBuf,
+
+    /// Format of the findings report
+    #[structopt(long = "format", default_value = "table", possible_values = &["table", "json", "csv"])]
+    format: String,
+}
+
+/// Header tags checked in every script, in the casing `NetSuite` requires.
+pub const TAGS: [&str; 2] = ["@NApiVersion", "@NScriptType"];
+
+/// Lints every script at a path and prints a report of findings.
+///
+/// Returns whether no script has findings, so that the CLI can exit with a non-zero status and the
+/// lint can be used as a CI check.
+///
+/// # Errors
+/// Returns an error if the path, a script, or a config cannot be read
+pub fn run(opt: &LintOpt) -> Result<bool, SuiteScriptError> {
+    let suffixes = get_suffixes(&load_spec(&opt.path)?);
+    let scripts = find_scripts(&opt.path)?;
+    let mut findings = Vec::new();
+    for script in &scripts {
+        let contents = read_to_string(script)?;
+        for finding in lint_script(script, &contents, &suffixes) {
+            findings.push((script.display().to_string(), finding));
+        }
+    }
+
+    match opt.format.as_str() {
+        "json" => print!("{}", format_json(scripts.len(), &findings)),
+        "csv" => print!("{}", format_csv(&findings)),
+        _ if findings.is_empty() => println!("No findings in {} scripts", scripts.len()),
+        _ => print_findings(&findings),
+    }
+
+    Ok(findings.is_empty())
+}
+
+/// Checks a single script for problems `NetSuite` would reject or that break the script at run
+/// time.
+///
+/// Returns a description of each problem: a missing or malformed `@NApiVersion` or `@NScriptType`
+/// tag, a module of the `define` array that does not exist or is not in its documented casing, and
+/// a callback that does not take one argument per module. A missing `@NScriptType` is only reported
+/// if the file name has one of the suffixes of script types, since libraries have none, and custom
+/// GL plugins are not versioned, so they are not checked for an API version.
+pub fn lint_script(path: &Path, contents: &str, suffixes: &[(String, String)]) -> Vec<String> {
+    let mut findings = get_malformed_tags(contents);
+
+    match get_tag(contents, "@NApiVersion") {
+        None if contents.contains("function customizeGlImpact(") => {}
+        None => findings.push(String::from("Missing @NApiVersion tag")),
+        Some(version) if !API.contains(&version) => findings.push(format!(
+            "Invalid @NApiVersion {}, expected 2.0, 2.1, or 2.x",
+            version
+        )),
+        Some(_) => {}
+    }
+
+    match get_tag(contents, "@NScriptType") {
+        None => {
+            if let Some(script_type) = infer_script_type(path, suffixes) {
+                findings.push(format!(
+                    "Missing @NScriptType tag for a {} script",
+                    script_type
+                ));
+            }
+        }
+        Some(tag) => {
+            if let Some(expected) = get_expected_script_type(tag) {
+                if !expected.eq_ignore_ascii_case(tag) {
+                    findings.push(format!(
+                        "Invalid @NScriptType {}, expected {}",
+                        tag, expected
+                    ));
+                }
+            } else {
+                findings.push(format!("Unknown @NScriptType {}", tag));
+            }
+        }
+    }
+
+    let dependencies = get_dependencies(contents);
+    for dependency in dependencies.iter().flatten() {
+        if !dependency.starts_with("N/") {
+            continue;
+        }
+        match get_module_name(dependency) {
+            None => findings.push(format!("Unknown module {} in define", dependency)),
+            Some(expected) if expected != *dependency => findings.push(format!(
+                "Module {} in define must be written {}",
+                dependency, expected
+            )),
+            Some(_) => {}
+        }
+    }
+
+    if let (Some(dependencies), Some(params)) = (&dependencies, get_callback_params(contents)) {
+        if params.len() != dependencies.len() {
+            findings.push(format!(
+                "define imports {} but its callback takes {}",
+                plural(dependencies.len(), "module"),
+                plural(params.len(), "argument")
+            ));
+        }
+    }
+
+    findings
+}
+
+/// Finds the `@NApiVersion` and `@NScriptType` tags written in the wrong casing or without a
+/// value, which `NetSuite` ignores.
+fn get_malformed_tags(contents: &str) -> Vec<String> {
+    let mut findings = Vec::new();
+    for line in contents.lines() {
+        let mut words = line.split_whitespace();
+        while let Some(word) = words.next() {
+            let tag = match TAGS.iter().find(|tag| tag.eq_ignore_ascii_case(word)) {
+                Some(tag) => tag,
+                None => continue,
+            };
+            if word != *tag {
+                findings.push(format!("Malformed tag {}, expected {}", word, tag));
+            } else if words.next().is_none() {
+                findings.push(format!("Malformed tag {} without a value", tag));
+            }
+        }
+    }
+    findings
+}
+
+/// Gets the value of the `@NScriptType` tag that `NetSuite` expects for a given value, such as
+/// `UserEventScript` for `UserEvent`. The value is not case sensitive.
+///
+/// Returns `None` if the value names no script type.
+fn get_expected_script_type(tag: &str) -> Option<String> {
+    let lower_case = tag.to_lowercase();
+    let name = map_script_to_name(lower_case.trim_end_matches("script"));
+    let expected = get_script_type(&name.to_lowercase());
+    let expected = expected
+        .trim()
+        .strip_prefix("* @NScriptType ")?
+        .trim()
+        .to_owned();
+    Some(expected)
+}
+
+/// Gets the name of an `N/` module in its documented casing, such as `N/ui/serverWidget` for
+/// `N/UI/serverwidget`.
+///
+/// Returns `None` if the dependency is not a module of `NetSuite`.
+pub fn get_module_name(dependency: &str) -> Option<String> {
+    let name = dependency.strip_prefix("N/")?.to_lowercase();
+    if !MODULES.contains(&name.as_str()) {
+        return None;
+    }
+    Some(format!("N/{}", get_module_path(&name)))
+}
+
+/// Gets the names of the arguments of the callback of a script's `define` call, written as a
+/// function expression or an arrow function.
+///
+/// Returns `None` if the script has no `define` call with a dependency array, or the callback is
+/// written another way, such as a variable.
+fn get_callback_params(contents: &str) -> Option<Vec<String>> {
+    let (_, _, after) = split_define(contents)?;
+    let callback = after.trim_start().strip_prefix(',')?.trim_start();
+    let params = if let Some(function) = callback.strip_prefix("function") {
+        let open = function.find('(')?;
+        let name = function[..open].trim();
+        if !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        {
+            return None;
+        }
+        let params = &function[open + 1..];
+        &params[..params.find(')')?]
+    } else if let Some(params) = callback.strip_prefix('(') {
+        let params = &params[..params.find(')')?];
+        let arrow = callback[params.len() + 2..].trim_start();
+        if !arrow.starts_with("=>") {
+            return None;
+        }
+        params
+    } else {
+        let end = callback.find("=>")?;
+        let param = callback[..end].trim();
+        if param.is_empty()
+            || !param
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        {
+            return None;
+        }
+        param
+    };
+
+    Some(
+        params
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty())
+            .map(str::to_owned)
+            .collect(),
+    )
+}
+
+/// Formats a count with a noun, adding an `s` unless the count is one.
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        return format!("1 {}", noun);
+    }
+    format!("{} {}s", count, noun)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{generate, ScriptSpec};
+    use super::*;
+
+    fn suffixes() -> Vec<(String, String)> {
+        get_suffixes(&ScriptSpec::default())
+    }
+
+    #[test]
+    fn test_lint_valid_script() {
+        let contents = "/**\n * @NApiVersion 2.1\n * @NScriptType UserEventScript\n */\n\ndefine([\n  'N/record',\n  'N/ui/serverWidget',\n  './constants',\n], (record, serverWidget, constants) => {\n  return {};\n});\n";
+        assert_eq!(
+            lint_script(Path::new("customer_ue.js"), contents, &suffixes()),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_lint_generated_scripts() {
+        for (file_name, script_type, modules) in &[
+            ("form_sl.js", "suitelet", vec!["ui/serverwidget", "record"]),
+            (
+                "invoice_cs.js",
+                "client",
+                vec!["currentrecord", "ui/message"],
+            ),
+            ("customer_ue.js", "userevent", vec!["record", "search"]),
+            ("nightly_ss.js", "scheduled", vec!["runtime"]),
+            ("gl_plugin.js", "customgl", Vec::new()),
+            ("utils.js", "library", vec!["search"]),
+        ] {
+            let spec = ScriptSpec {
+                file_name: Some(PathBuf::from(file_name)),
+                script_type: String::from(*script_type),
+                modules: modules.iter().map(|module| String::from(*module)).collect(),
+                ..ScriptSpec::default()
+            };
+            let contents = generate(&spec).unwrap();
+            assert_eq!(
+                lint_script(Path::new(file_name), &contents, &get_suffixes(&spec)),
+                Vec::<String>::new(),
+                "{}",
+                contents
+            );
+        }
+    }
+
+    #[test]
+    fn test_lint_invalid_script() {
+        let contents = "/**\n * @napiversion 2.1\n * @NScriptType UserEvent\n */\n\ndefine(['N/Record', 'N/records'], function (record) {\n  return {};\n});\n";
+        assert_eq!(
+            lint_script(Path::new("customer_ue.js"), contents, &suffixes()),
+            vec![
+                String::from("Malformed tag @napiversion, expected @NApiVersion"),
+                String::from("Missing @NApiVersion tag"),
+                String::from("Invalid @NScriptType UserEvent, expected UserEventScript"),
+                String::from("Module N/Record in define must be written N/record"),
+                String::from("Unknown module N/records in define"),
+                String::from("define imports 2 modules but its callback takes 1 argument"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_tags() {
+        assert_eq!(
+            lint_script(
+                Path::new("customer_cs.js"),
+                "/**\n * @NApiVersion 3.0\n */\n",
+                &suffixes()
+            ),
+            vec![
+                String::from("Invalid @NApiVersion 3.0, expected 2.0, 2.1, or 2.x"),
+                String::from("Missing @NScriptType tag for a client script"),
+            ]
+        );
+        assert_eq!(
+            lint_script(
+                Path::new("utils.js"),
+                "/**\n * @NApiVersion 2.x\n * @NScriptType Widget\n * @NScriptType\n */\n",
+                &suffixes()
+            ),
+            vec![
+                String::from("Malformed tag @NScriptType without a value"),
+                String::from("Unknown @NScriptType Widget"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_configured_suffixes() {
+        let spec = ScriptSpec {
+            suffixes: vec![(String::from("_userevent"), String::from("userevent"))],
+            ..ScriptSpec::default()
+        };
+        let contents = "/**\n * @NApiVersion 2.1\n */\n";
+        assert_eq!(
+            lint_script(
+                Path::new("customer_userevent.js"),
+                contents,
+                &get_suffixes(&spec)
+            ),
+            vec![String::from(
+                "Missing @NScriptType tag for a userevent script"
+            )]
+        );
+        assert_eq!(
+            lint_script(Path::new("customer_ue.js"), contents, &get_suffixes(&spec)),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_get_callback_params() {
+        assert_eq!(
+            get_callback_params("define(['N/record'], function main(record) {});"),
+            Some(vec![String::from("record")])
+        );
+        assert_eq!(
+            get_callback_params("define(['N/search'], search => ({}));"),
+            Some(vec![String::from("search")])
+        );
+        assert_eq!(
+            get_callback_params("define([], () => ({}));"),
+            Some(Vec::new())
+        );
+        assert_eq!(get_callback_params("define([], factory);"), None);
+    }
+}
@@ -0,0 +1,60 @@
+use structopt::StructOpt;
+
+use super::assets::netsuite_types::{API, MODULES, RECORD_TYPES, TYPES};
+use super::escape_json;
+
+/// Options for listing the values accepted by the options of the `new` command.
+#[derive(StructOpt, Debug)]
+pub struct ListOpt {
+    /// Registry to list
+    #[structopt(possible_values = &["modules", "types", "apis", "records"])]
+    registry: String,
+
+    /// Print the values as a JSON array
+    #[structopt(long = "json")]
+    json: bool,
+}
+
+/// Prints the contents of an asset registry, one value per line or as a JSON array.
+pub fn run(opt: &ListOpt) {
+    let values = get_values(&opt.registry);
+    if opt.json {
+        print!("{}", format_json(values));
+    } else {
+        for value in values {
+            println!("{}", value);
+        }
+    }
+}
+
+/// Gets the values of a registry, as accepted by the matching option of the `new` command.
+fn get_values(registry: &str) -> &'static [&'static str] {
+    match registry {
+        "modules" => &MODULES,
+        "types" => &TYPES,
+        "records" => &RECORD_TYPES,
+        _ => &API,
+    }
+}
+
+/// Formats values as a JSON array of strings.
+fn format_json(values: &[&str]) -> String {
+    let entries: Vec<String> = values
+        .iter()
+        .map(|value| format!("\"{}\"", escape_json(value)))
+        .collect();
+    format!("[{}]\n", entries.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_json() {
+        assert_eq!(
+            format_json(get_values("apis")),
+            "[\"2.1\", \"2\", \"2.x\", \"2.0\"]\n"
+        );
+    }
+}
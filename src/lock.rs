@@ -0,0 +1,428 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::config::find_project_config;
+use super::merge::{self, Merge};
+use super::{
+    read_to_string, write_atomically, write_atomically_with, Fnv1a, Notice, SuiteScriptError,
+};
+
+/// Name of the lock manifest of a project, which records the files written by the generator.
+pub const LOCK_FILE: &str = "suitescript.lock";
+
+/// Directory of the generated contents of the files recorded in a lock manifest, relative to the
+/// manifest.
+pub const GENERATED_DIR: &str = ".suitescript/generated";
+
+/// Comment written at the top of a lock manifest.
+const LOCK_HEADER: &str = "# Files written by suitescript, with the hashes of their generated contents. The generated\n# contents are kept in .suitescript/generated, for merging edits when the files are regenerated.\n# Do not edit.\n\n";
+
+/// The lock manifest of a project, `suitescript.lock`, next to its project config or in the
+/// directory the generator is run in.
+///
+/// The manifest records each file written by the generator with a hash of its generated contents,
+/// and the generated contents are kept in `.suitescript/generated`, so that the edits made to a
+/// file since it was generated can be merged with its regenerated contents, and so that
+/// `suitescript clean` can remove the files.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Lock {
+    /// Directory of the manifest, which the paths of the files are relative to
+    #[serde(skip)]
+    pub dir: PathBuf,
+    /// Directory the generator is run in, which the paths given to the generator are relative to
+    #[serde(skip)]
+    current_dir: PathBuf,
+    /// Files written by the generator, sorted by path
+    #[serde(default, rename = "file")]
+    pub files: Vec<LockedFile>,
+}
+
+/// A file recorded in a lock manifest.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LockedFile {
+    /// Path of the file, relative to the directory of the manifest, with `/` separators
+    pub path: String,
+    /// 64-bit FNV-1a hash of the generated contents of the file, in hexadecimal
+    pub hash: String,
+}
+
+impl Lock {
+    /// Gets the path of the lock manifest of the project a directory is in, next to its project
+    /// config, if any, or in the directory itself.
+    pub fn find(current_dir: &Path) -> PathBuf {
+        find_project_config(current_dir)
+            .and_then(|config| config.parent().map(Path::to_path_buf))
+            .unwrap_or_else(|| current_dir.to_path_buf())
+            .join(LOCK_FILE)
+    }
+
+    /// Opens the lock manifest of the project a directory is in.
+    ///
+    /// Returns `None` if the project has no manifest, unless `create` is set, in which case the
+    /// manifest is created when it is saved.
+    ///
+    /// # Errors
+    /// Returns an IO error if the manifest cannot be read, and a validation error if it is invalid
+    pub fn open(current_dir: &Path, create: bool) -> Result<Option<Lock>, SuiteScriptError> {
+        let path = Lock::find(current_dir);
+        let dir = path.parent().unwrap_or(current_dir).to_path_buf();
+        let mut lock = if path.is_file() {
+            toml::from_str::<Lock>(&read_to_string(&path)?).map_err(|error| {
+                SuiteScriptError::Validation(format!(
+                    "Invalid lock manifest {}: {}",
+                    path.display(),
+                    error.message()
+                ))
+            })?
+        } else if create {
+            Lock::default()
+        } else {
+            return Ok(None);
+        };
+        lock.dir = dir;
+        lock.current_dir = current_dir.to_path_buf();
+        Ok(Some(lock))
+    }
+
+    /// Gets the path of the manifest.
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(LOCK_FILE)
+    }
+
+    /// Gets the path of a recorded file, relative to the directory the generator is run in if it
+    /// is in it.
+    pub fn resolve(&self, file: &LockedFile) -> PathBuf {
+        let path = self.dir.join(&file.path);
+        match path.strip_prefix(&self.current_dir) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => path,
+        }
+    }
+
+    /// Gets the path of a file given to the generator as recorded in the manifest, relative to the
+    /// directory of the manifest if it is in it.
+    pub fn get_key(&self, path: &Path) -> String {
+        get_key(&self.dir, &self.current_dir, path)
+    }
+
+    /// Gets the path of the generated contents of a file.
+    pub fn get_generated_path(&self, key: &str) -> PathBuf {
+        let hash = Fnv1a::default().update(key.as_bytes()).finish();
+        self.dir.join(GENERATED_DIR).join(format!("{:016x}", hash))
+    }
+
+    /// Checks whether a recorded file was edited since it was generated.
+    pub fn is_edited(&self, path: &Path) -> bool {
+        self.read_generated(path).is_some_and(|generated| {
+            std::fs::read_to_string(path).is_ok_and(|contents| contents != generated)
+        })
+    }
+
+    /// Reads the generated contents of a file given to the generator, if it is recorded.
+    pub fn read_generated(&self, path: &Path) -> Option<String> {
+        let key = self.get_key(path);
+        self.files
+            .iter()
+            .find(|file| file.path == key)
+            .and_then(|_| std::fs::read_to_string(self.get_generated_path(&key)).ok())
+    }
+
+    /// Writes the generated contents of a file given to the generator, returning its record.
+    ///
+    /// # Errors
+    /// Returns an IO error if the contents cannot be written
+    pub fn write_generated(
+        &self,
+        path: &Path,
+        contents: &str,
+    ) -> Result<LockedFile, SuiteScriptError> {
+        self.write_generated_with(path, |out| {
+            out.write_all(contents.as_bytes()).map_err(|source| {
+                SuiteScriptError::io(format!("Failed to write {}", path.display()), source)
+            })
+        })
+    }
+
+    /// Writes the generated contents of a file given to the generator, as written by `write` into
+    /// a writer, returning its record.
+    ///
+    /// # Errors
+    /// Returns the error of `write`, and an IO error if the contents cannot be written
+    pub fn write_generated_with<F>(
+        &self,
+        path: &Path,
+        write: F,
+    ) -> Result<LockedFile, SuiteScriptError>
+    where
+        F: FnOnce(&mut dyn Write) -> Result<(), SuiteScriptError>,
+    {
+        let key = self.get_key(path);
+        let generated = self.get_generated_path(&key);
+        create_dir(&generated)?;
+        let mut hash = Fnv1a::default();
+        write_atomically_with(&generated, |out| {
+            let mut hashed = HashingWriter {
+                inner: out,
+                hash: &mut hash,
+            };
+            write(&mut hashed)
+        })?;
+        Ok(LockedFile {
+            path: key,
+            hash: format!("{:016x}", hash.finish()),
+        })
+    }
+
+    /// Records files written by the generator, replacing their previous records.
+    pub fn record(&mut self, files: impl IntoIterator<Item = LockedFile>) {
+        for file in files {
+            self.files.retain(|recorded| recorded.path != file.path);
+            self.files.push(file);
+        }
+        self.files.sort_by(|left, right| left.path.cmp(&right.path));
+    }
+
+    /// Removes the record of a file and its generated contents.
+    ///
+    /// # Errors
+    /// Returns an IO error if the generated contents exist and cannot be removed
+    pub fn forget(&mut self, file: &LockedFile) -> Result<(), SuiteScriptError> {
+        let generated = self.get_generated_path(&file.path);
+        if generated.is_file() {
+            std::fs::remove_file(&generated).map_err(|source| {
+                SuiteScriptError::io(format!("Failed to remove {}", generated.display()), source)
+            })?;
+        }
+        self.files.retain(|recorded| recorded.path != file.path);
+        Ok(())
+    }
+
+    /// Writes the manifest.
+    ///
+    /// # Errors
+    /// Returns an IO error if the manifest cannot be written
+    pub fn save(&self) -> Result<(), SuiteScriptError> {
+        let contents = toml::to_string(self).map_err(|error| {
+            SuiteScriptError::Validation(format!(
+                "Failed to write lock manifest {}: {}",
+                self.path().display(),
+                error
+            ))
+        })?;
+        write_atomically(&self.path(), &format!("{}{}", LOCK_HEADER, contents))
+    }
+
+    /// Merges the edits made to a recorded file since it was generated with its regenerated
+    /// contents, as described by [`merge::merge`].
+    ///
+    /// Returns `None` if the file is not recorded, does not exist, or was not edited, in which case
+    /// the regenerated contents can be written as they are, and otherwise the merged contents and a
+    /// notice about the merge.
+    ///
+    /// # Errors
+    /// Returns a validation error if the file has conflict markers of a previous merge, unless
+    /// `force` is set
+    pub fn merge(
+        &self,
+        path: &Path,
+        regenerated: &str,
+        force: bool,
+    ) -> Result<Option<(String, Notice)>, SuiteScriptError> {
+        let generated = match self.read_generated(path) {
+            Some(generated) => generated,
+            None => return Ok(None),
+        };
+        let edited = match std::fs::read_to_string(path) {
+            Ok(edited) if edited != generated => edited,
+            _ => return Ok(None),
+        };
+        if merge::has_conflicts(&edited) && !force {
+            return Err(SuiteScriptError::Validation(format!(
+                "{} has conflict markers of a previous regeneration. Resolve them, or pass --force to merge anyway",
+                path.display()
+            )));
+        }
+
+        let Merge {
+            contents,
+            conflicts,
+        } = merge::merge(&generated, &edited, regenerated);
+        let notice = match conflicts {
+            0 => Notice::Note(format!(
+                "merged the edits to {} with its regenerated contents",
+                path.display()
+            )),
+            1 => Notice::Warning(format!(
+                "1 conflict merging the edits to {} with its regenerated contents, marked with <<<<<<< edited",
+                path.display()
+            )),
+            conflicts => Notice::Warning(format!(
+                "{} conflicts merging the edits to {} with its regenerated contents, marked with <<<<<<< edited",
+                conflicts,
+                path.display()
+            )),
+        };
+        Ok(Some((contents, notice)))
+    }
+}
+
+/// A writer that hashes the contents written through it.
+struct HashingWriter<'a, W: Write> {
+    inner: W,
+    hash: &'a mut Fnv1a,
+}
+
+impl<W: Write> Write for HashingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        *self.hash = self.hash.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A writer that writes the contents written through it into two writers, such as a file and its
+/// generated contents in a lock manifest.
+pub struct TeeWriter<A: Write, B: Write> {
+    pub first: A,
+    pub second: B,
+}
+
+impl<A: Write, B: Write> Write for TeeWriter<A, B> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.first.write_all(buf)?;
+        self.second.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.first.flush()?;
+        self.second.flush()
+    }
+}
+
+/// Hashes the contents of a file, as recorded in a lock manifest.
+pub fn hash_contents(contents: &[u8]) -> String {
+    format!("{:016x}", Fnv1a::default().update(contents).finish())
+}
+
+/// Gets the path of a file given to the generator relative to a directory if it is in it, with `/`
+/// separators, as recorded in a lock manifest or a batch cache in that directory.
+pub fn get_key(dir: &Path, current_dir: &Path, path: &Path) -> String {
+    let path = normalize(&current_dir.join(path));
+    let path = path.strip_prefix(dir).unwrap_or(&path);
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Removes the `.` and `..` components of a path, without reading the file system.
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Creates the parent directory of a file if it is missing.
+fn create_dir(path: &Path) -> Result<(), SuiteScriptError> {
+    match path.parent() {
+        Some(parent) => std::fs::create_dir_all(parent).map_err(|source| {
+            SuiteScriptError::io(
+                format!("Failed to create directory {}", parent.display()),
+                source,
+            )
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_round_trip() {
+        let dir = std::env::temp_dir().join(format!("suitescript-lock-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(Lock::open(&dir, false).unwrap().is_none());
+
+        let mut lock = Lock::open(&dir, true).unwrap().unwrap();
+        let path = Path::new("src/customer_ue.js");
+        let file = lock
+            .write_generated(path, "define([], () => {});\n")
+            .unwrap();
+        lock.record(vec![file.clone()]);
+        lock.save().unwrap();
+
+        let lock = Lock::open(&dir, false).unwrap().unwrap();
+        let generated = lock.read_generated(path);
+        let manifest = std::fs::read_to_string(lock.path()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            file,
+            LockedFile {
+                path: String::from("src/customer_ue.js"),
+                hash: hash_contents(b"define([], () => {});\n"),
+            }
+        );
+        assert_eq!(lock.files, vec![file]);
+        assert_eq!(generated.as_deref(), Some("define([], () => {});\n"));
+        assert!(manifest.starts_with(LOCK_HEADER));
+        assert!(manifest.contains("[[file]]\npath = \"src/customer_ue.js\"\n"));
+    }
+
+    #[test]
+    fn test_merge_edits() {
+        let dir = std::env::temp_dir().join(format!("suitescript-merge-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut lock = Lock::open(&dir, true).unwrap().unwrap();
+        let path = dir.join("customer_ue.js");
+        let generated = "define([\n  'N/record',\n], (record) => {\n  // TODO\n});\n";
+        std::fs::write(&path, generated).unwrap();
+        let file = lock.write_generated(&path, generated).unwrap();
+        lock.record(vec![file]);
+
+        let regenerated = generated.replace("  'N/record',\n", "  'N/record',\n  'N/search',\n");
+        let unedited = lock.merge(&path, &regenerated, false).unwrap();
+        std::fs::write(&path, generated.replace("  // TODO", "  log.debug('x');")).unwrap();
+        let merged = lock.merge(&path, &regenerated, false).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(unedited, None);
+        let (contents, notice) = merged.unwrap();
+        assert_eq!(
+            contents,
+            "define([\n  'N/record',\n  'N/search',\n], (record) => {\n  log.debug('x');\n});\n"
+        );
+        assert!(matches!(notice, Notice::Note(_)));
+    }
+
+    #[test]
+    fn test_get_key() {
+        let lock = Lock {
+            dir: PathBuf::from("/project"),
+            current_dir: PathBuf::from("/project/src"),
+            files: Vec::new(),
+        };
+        assert_eq!(
+            lock.get_key(Path::new("customer_ue.js")),
+            "src/customer_ue.js"
+        );
+        assert_eq!(
+            lock.resolve(&LockedFile {
+                path: String::from("src/customer_ue.js"),
+                hash: String::new(),
+            }),
+            PathBuf::from("customer_ue.js")
+        );
+    }
+}
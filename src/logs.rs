@@ -0,0 +1,285 @@
+use serde::Deserialize;
+use std::io;
+use structopt::StructOpt;
+
+use super::auth::{load_profile, validate_profile};
+use super::oauth::{get_authorization, Credentials};
+use super::upload::{get_credentials, get_env_setting, load_configs};
+use super::SuiteScriptError;
+
+/// Options for querying the execution logs of a script deployed to an account.
+#[derive(StructOpt, Debug)]
+pub struct LogsOpt {
+    /// Script ID of the script record, such as `customscript_customer_ue`
+    #[structopt(validator = validate_script_id)]
+    scriptid: String,
+
+    /// ID of the account to query, such as `1234567_SB1`, instead of the account of the profile
+    /// or the environment
+    #[structopt(long = "account")]
+    account: Option<String>,
+
+    /// Environment whose account is queried, set in a config as `env.<name>.account`
+    #[structopt(short, long = "env", default_value = "default")]
+    env: String,
+
+    /// Profile stored with `suitescript auth add` whose account and credentials sign the query,
+    /// instead of the account of the environment and the credentials in environment variables
+    #[structopt(long = "profile", validator = validate_profile)]
+    profile: Option<String>,
+
+    /// Types of the log entries to show, separated by commas, instead of every type
+    #[structopt(long = "type", use_delimiter = true, possible_values = &TYPES, case_insensitive = true)]
+    types: Vec<String>,
+
+    /// Maximum number of log entries to show, the most recent first
+    #[structopt(short = "n", long = "limit", default_value = "50", validator = validate_limit)]
+    limit: usize,
+
+    /// Show the keys, tokens, and secrets of the credentials in errors instead of redacting them,
+    /// only to debug queries locally
+    #[structopt(long = "show-secrets")]
+    show_secrets: bool,
+}
+
+/// Types of the entries of the execution logs, as written by `N/log`.
+const TYPES: [&str; 5] = ["debug", "audit", "error", "emergency", "system"];
+
+/// Largest number of rows that SuiteQL returns in a page.
+const MAX_LIMIT: usize = 1000;
+
+/// Page of the results of a SuiteQL query.
+#[derive(Debug, Deserialize)]
+struct Page {
+    items: Vec<LogEntry>,
+}
+
+/// Entry of the execution logs of a script, as selected by [`get_query`].
+#[derive(Debug, Default, Deserialize, PartialEq)]
+struct LogEntry {
+    #[serde(default)]
+    logged: String,
+    #[serde(default, rename = "type")]
+    kind: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    detail: String,
+}
+
+/// Prints the most recent entries of the execution logs of a script, queried with SuiteQL
+/// through the SuiteTalk REST web services of an account.
+///
+/// The account and the credentials are read from the keyring with `--profile`, and otherwise the
+/// account is read from the environment and the credentials from the same environment variables
+/// as `upload`. `--account` takes precedence over both. The keys, tokens, and secrets are redacted
+/// in errors unless `--show-secrets` is given.
+///
+/// # Errors
+/// Returns a validation error if the account or the credentials are missing or the response
+/// cannot be read, and an IO error if the keyring cannot be read or the query fails
+pub fn run(opt: &LogsOpt) -> Result<(), SuiteScriptError> {
+    let (account, credentials) = match &opt.profile {
+        Some(profile) => {
+            let profile = load_profile(profile)?;
+            (profile.account, profile.credentials)
+        }
+        None => (String::new(), get_credentials()?),
+    };
+    let account = match &opt.account {
+        Some(account) => account.clone(),
+        None if !account.is_empty() => account,
+        None => get_env_setting(&load_configs()?, &opt.env, "account")?,
+    };
+
+    let url = get_query_url(&account, opt.limit);
+    let body = serde_json::json!({ "q": get_query(&opt.scriptid, &opt.types) }).to_string();
+    let response = post(&url, &account, &credentials, &body).map_err(|source| {
+        let source = if opt.show_secrets {
+            source
+        } else {
+            io::Error::new(source.kind(), credentials.redact(&source.to_string()))
+        };
+        SuiteScriptError::io(
+            format!("Failed to query the execution logs of {}", opt.scriptid),
+            source,
+        )
+    })?;
+    let page: Page = serde_json::from_str(&response).map_err(|error| {
+        SuiteScriptError::Validation(format!("Failed to read the execution logs: {}", error))
+    })?;
+    if page.items.is_empty() {
+        println!("No execution logs found for {}", opt.scriptid);
+    } else {
+        print!("{}", format_entries(&page.items));
+    }
+    Ok(())
+}
+
+/// Validates the script ID of a script record, which starts with `customscript`.
+fn validate_script_id(scriptid: String) -> Result<(), String> {
+    let valid = scriptid.to_lowercase().starts_with("customscript")
+        && scriptid
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(format!(
+            "Invalid script ID {}, expected customscript followed by letters, digits, and `_`",
+            scriptid
+        ));
+    }
+    Ok(())
+}
+
+/// Validates the number of log entries to show.
+fn validate_limit(limit: String) -> Result<(), String> {
+    match limit.parse::<usize>() {
+        Ok(limit) if (1..=MAX_LIMIT).contains(&limit) => Ok(()),
+        _ => Err(format!(
+            "Invalid limit {}, expected a number from 1 to {}",
+            limit, MAX_LIMIT
+        )),
+    }
+}
+
+/// Gets the URL of the SuiteQL endpoint of an account, whose host is the account ID in lowercase
+/// with `-` instead of `_`, such as `1234567-sb1`.
+fn get_query_url(account: &str, limit: usize) -> String {
+    format!(
+        "https://{}.suitetalk.api.netsuite.com/services/rest/query/v1/suiteql?limit={}",
+        account.to_lowercase().replace('_', "-"),
+        limit
+    )
+}
+
+/// Gets the SuiteQL query of the most recent log entries of a script, of the given types or of
+/// every type. The script ID and the types are validated by the options, and are quoted as
+/// literals.
+fn get_query(scriptid: &str, types: &[String]) -> String {
+    let literal = |value: &str| format!("'{}'", value.replace('\'', "''"));
+    let mut query = format!(
+        "SELECT TO_CHAR(scriptnote.date, 'YYYY-MM-DD HH24:MI:SS') AS logged, \
+         scriptnote.type AS type, scriptnote.title AS title, scriptnote.detail AS detail \
+         FROM scriptnote INNER JOIN script ON script.id = scriptnote.scripttype \
+         WHERE LOWER(script.scriptid) = {}",
+        literal(&scriptid.to_lowercase())
+    );
+    if !types.is_empty() {
+        let types: Vec<String> = types
+            .iter()
+            .map(|kind| literal(&kind.to_uppercase()))
+            .collect();
+        query.push_str(&format!(" AND scriptnote.type IN ({})", types.join(", ")));
+    }
+    query.push_str(" ORDER BY scriptnote.date DESC");
+    query
+}
+
+/// Formats log entries one per line, with the date, the type, the title, and the detail, whose
+/// whitespace is collapsed so that each entry fits on its line.
+fn format_entries(entries: &[LogEntry]) -> String {
+    let width = entries
+        .iter()
+        .map(|entry| entry.kind.len())
+        .max()
+        .unwrap_or_default();
+    let mut output = String::new();
+    for entry in entries {
+        let mut message = entry.title.split_whitespace().collect::<Vec<_>>().join(" ");
+        let detail = entry
+            .detail
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !detail.is_empty() {
+            if !message.is_empty() {
+                message.push_str(": ");
+            }
+            message.push_str(&detail);
+        }
+        output.push_str(&format!(
+            "{}  {:width$}  {}\n",
+            entry.logged,
+            entry.kind,
+            message,
+            width = width
+        ));
+    }
+    output
+}
+
+/// Posts a SuiteQL query to an account, returning the body of the response.
+fn post(url: &str, account: &str, credentials: &Credentials, body: &str) -> io::Result<String> {
+    let authorization = get_authorization("POST", url, account, credentials)?;
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .build()
+        .into();
+    let mut response = agent
+        .post(url)
+        .header("Authorization", &authorization)
+        .header("Content-Type", "application/json")
+        .header("Prefer", "transient")
+        .send(body)
+        .map_err(io::Error::other)?;
+    let status = response.status();
+    let response = response
+        .body_mut()
+        .read_to_string()
+        .map_err(io::Error::other)?;
+    if !status.is_success() {
+        return Err(io::Error::other(format!(
+            "SuiteQL responded with HTTP {}: {}",
+            status.as_u16(),
+            response
+        )));
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_query_url() {
+        assert_eq!(
+            get_query_url("1234567_SB1", 50),
+            "https://1234567-sb1.suitetalk.api.netsuite.com/services/rest/query/v1/suiteql?limit=50"
+        );
+    }
+
+    #[test]
+    fn test_get_query() {
+        let query = get_query("CUSTOMSCRIPT_customer_ue", &[]);
+        assert!(
+            query.contains("WHERE LOWER(script.scriptid) = 'customscript_customer_ue' ORDER BY")
+        );
+        let query = get_query(
+            "customscript_customer_ue",
+            &[String::from("error"), String::from("o'audit")],
+        );
+        assert!(query.contains(" AND scriptnote.type IN ('ERROR', 'O''AUDIT') ORDER BY"));
+        assert!(validate_script_id(String::from("customscript_customer_ue")).is_ok());
+        assert!(validate_script_id(String::from("customscript' OR 1=1")).is_err());
+        assert!(validate_script_id(String::from("customdeploy_customer_ue")).is_err());
+        assert!(validate_limit(String::from("1000")).is_ok());
+        assert!(validate_limit(String::from("0")).is_err());
+    }
+
+    #[test]
+    fn test_format_entries() {
+        let page: Page = serde_json::from_str(
+            r#"{"links": [], "count": 2, "hasMore": false, "items": [
+                {"links": [], "logged": "2026-10-14 09:30:00", "type": "ERROR", "title": "afterSubmit", "detail": "Record\n  not found"},
+                {"links": [], "logged": "2026-10-14 09:29:59", "type": "DEBUG", "title": "beforeLoad"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            format_entries(&page.items),
+            "2026-10-14 09:30:00  ERROR  afterSubmit: Record not found\n\
+             2026-10-14 09:29:59  DEBUG  beforeLoad\n"
+        );
+    }
+}
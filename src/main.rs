@@ -1,415 +1,702 @@
-use std::fs::File;
 use std::io::prelude::*;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
+use structopt::clap::{AppSettings, ArgMatches, Error};
 use structopt::StructOpt;
-mod assets;
-use assets::netsuite_types::{API, MODULES, TYPES};
+use suitescript_generator::config::{self, find_project_config, user_config_path, Config};
+use suitescript_generator::lock::{Lock, LockedFile};
+use suitescript_generator::{
+    audit, auth, backup_file, batch, check_dropped_keep_regions, clean, completions, copyright,
+    deploy, describe, describe_backup, fix, format_json_result, format_plan, formatter,
+    get_companion_files, importer, lint, list, logs, mocks, parse_module_list, prepare,
+    read_kept_script, render, render_header, render_to, sdfproject, stats, templates, tsproject,
+    upload, validate_modules, validate_parent_dir, wizard, write_companion_file, write_script_file,
+    Notice, OutputFile, ScriptSpec, SuiteScriptError,
+};
 
 #[derive(StructOpt, Debug)]
 #[structopt(
     name = "suitescript",
-    about = "CLI to create SuiteScript files and generate boilerplate"
+    about = "CLI to create SuiteScript files and generate boilerplate",
+    setting = AppSettings::ArgRequiredElseHelp
 )]
 struct Opt {
-    /// Name of the file to be generated
-    #[structopt(short, long = "filename", parse(from_os_str), validator = validate_file_name)]
-    file_name: PathBuf,
+    /// Disable colors and interactive prompts, for stable line-oriented output in screen readers,
+    /// dumb terminals, and CI logs
+    #[structopt(long = "plain", global = true)]
+    plain: bool,
 
-    /// Type of `SuiteScript` to be generated
-    #[structopt(short, long = "scripttype", default_value = "", validator = validate_script_type)]
-    script_type: String,
-
-    /// Version of the `SuiteScript` API to use
-    #[structopt(short, long = "apiversion", default_value = "2.1", validator = validate_api_version)]
-    api_version: String,
-
-    /// `SuiteScript` modules to import
-    #[structopt(short, long = "modules", default_value = "", validator = validate_modules)]
-    modules: Vec<String>,
-
-    /// Path to a file containing your company's copyright message
-    #[structopt(short, long = "copyright", parse(from_os_str), default_value = "", validator = validate_copyright_file)]
-    copyright: PathBuf,
-}
-
-/// Entry point for the CLI.
-///
-/// Initializes the application. If input validation is successful, creates the file and
-/// populates it according to the given inputs.
-fn main() {
-    let config = Opt::from_args();
-    let mut file = create_file(&config.file_name);
-
-    let contents = format!(
-        "{}/**\n{} * @NApiVersion {}\n */\n\ndefine([\n{}\n}});",
-        get_copyright(&config.copyright),
-        get_script_type(config.script_type.as_ref()),
-        get_api_version(config.api_version.as_ref()),
-        get_modules(&config.modules),
-    );
-
-    write_to_file(&mut file, contents.as_ref());
-}
-
-/// Gets the `SuiteScript` API version to be used.
-fn get_api_version(version: &str) -> String {
-    match version {
-        "2" => String::from("2.0"),
-        _ => version.to_owned(),
-    }
+    #[structopt(subcommand)]
+    command: Option<Command>,
 }
 
-/// Retrieves the contents of a specified copyright file.
-///
-/// Reads the specified file into memory. The contents are trimmed to remove any mistaken
-/// whitespaces or newlines in the file. The contents are then returned, formatted with one blank
-/// line after the final content line of the copyright message. Returns an empty string if no file
-/// is specified.
-///
-/// # Panics
-/// The function panics if the file cannot be read
-fn get_copyright(copyright: &Path) -> String {
-    if copyright.to_str().unwrap() == "" {
-        return String::from("");
-    }
-
-    let contents = std::fs::read_to_string(copyright)
-        .expect("Failed to read file")
-        .trim()
-        .to_string();
-    format!("{}\n\n", contents)
+#[derive(StructOpt, Debug)]
+#[allow(clippy::large_enum_variant)]
+enum Command {
+    /// Creates a script with the boilerplate of its type
+    New(ScriptSpec),
+    /// Checks that the scripts in a directory share the API version, copyright, and naming convention
+    Audit(audit::AuditOpt),
+    /// Stores and removes the account credentials of upload profiles in the OS keyring
+    Auth(auth::AuthOpt),
+    /// Generates every script listed in a YAML or JSON manifest or a CSV file, writing none if any is
+    /// invalid
+    Batch(batch::BatchOpt),
+    /// Removes the files recorded in the lock manifest, after asking for confirmation
+    Clean(clean::CleanOpt),
+    /// Prints shell completions, including the supported modules and script types
+    Completions(completions::CompletionsOpt),
+    /// Gets, sets, and lists the options of the user config
+    Config(config::ConfigOpt),
+    /// Describes what a module offers and the script types it is available in
+    Describe(describe::DescribeOpt),
+    /// Inserts missing API versions, fixes the casing of header tags and modules, and prepends
+    /// missing copyright notices in existing scripts
+    Fix(fix::FixOpt),
+    /// Normalizes the define dependency arrays and header tags of existing scripts
+    Format(formatter::FormatOpt),
+    /// Prints a spec describing the type, modules, and API version of every script in a directory
+    Import(importer::ImportOpt),
+    /// Writes the directories, manifest, and configs of a SuiteCloud account customization project
+    Init(sdfproject::InitOpt),
+    /// Writes the tsconfig.json and package.json of a project of TypeScript scripts
+    InitTs(tsproject::InitTsOpt),
+    /// Checks existing scripts for malformed header tags, unknown modules, and define callbacks
+    /// whose arguments do not match the modules
+    Lint(lint::LintOpt),
+    /// Lists the supported modules, script types, API versions, or standard record types
+    List(list::ListOpt),
+    /// Prints the most recent execution logs of a script in an account, queried with SuiteQL
+    Logs(logs::LogsOpt),
+    /// Writes Jest mocks of the N modules imported by scripts
+    Mock(mocks::MockOpt),
+    /// Counts the scripts in a directory by script type, API version, and module
+    Stats(stats::StatsOpt),
+    /// Lists the templates that scripts can be generated from
+    Templates(templates::TemplatesOpt),
+    /// Replaces the copyright notice of existing scripts
+    UpdateCopyright(copyright::UpdateCopyrightOpt),
+    /// Uploads files to the File Cabinet through a RESTlet, with token based authentication
+    Upload(upload::UploadOpt),
 }
 
-/// Converts a given script type name to its supported `NetSuite` name.
-///
-/// Converts the name to lowercase to support mangled inputs. Matches the name to the casing
-/// supported by `NetSuite`. If no match, an empty string is returned.
-fn map_script_to_name(stype: &str) -> &str {
-    match stype.to_lowercase().as_ref() {
-        "mapreduce" => "MapReduce",
-        "userevent" => "UserEvet",
-        "scheduled" => "Scheduled",
-        "client" => "Client",
-        "suitelet" => "Suitelet",
-        "restlet" => "RESTlet",
-        "portlet" => "Portlet",
-        _ => "",
-    }
-}
+/// Exit code when the scripts audited do not follow the conventions.
+const EXIT_FINDINGS: i32 = 1;
+/// Exit code when the arguments or the options of a config are invalid.
+const EXIT_USAGE: i32 = 2;
+/// Exit code when a file, a directory, or the terminal cannot be read or written.
+const EXIT_IO: i32 = 3;
+/// Exit code when a template file cannot be read or rendered.
+const EXIT_TEMPLATE: i32 = 4;
 
-/// Converts a given script type to its supported `NetSuite` name.
+/// Entry point for the CLI.
 ///
-/// If the script name is valid, returns a string with the NScriptType tag and the script name.
-/// Otherwise, returns an empty string.
-fn get_script_type(script_type: &str) -> String {
-    let script_name = map_script_to_name(script_type);
-    match script_name {
-        "MapReduce" | "UserEvent" | "Scheduled" | "Client" => {
-            format!(" * @NScriptType {}Script\n", script_name)
+/// Runs the CLI and exits with the code of the kind of error that stopped it, if any, so that
+/// shell scripts can tell invalid arguments apart from files that cannot be written. With `--json`,
+/// the error is printed as JSON.
+fn main() {
+    if let Err(error) = run() {
+        if wants_json() {
+            eprint!("{}", error.to_json());
+        } else {
+            eprintln!("error: {}", error);
         }
-        "" => String::from(""),
-        _ => format!(" * @NScriptType {}\n", script_type),
+        std::process::exit(match error {
+            SuiteScriptError::Validation(_) => EXIT_USAGE,
+            SuiteScriptError::Io { .. } => EXIT_IO,
+            SuiteScriptError::Template { .. } => EXIT_TEMPLATE,
+        });
     }
 }
 
-/// Maps a given module name to the valid `NetSuite` name.
+/// Initializes the application and runs the given subcommand.
 ///
-/// Converts the module name to lowercase to support mangled inputs. Matches the name to a list of
-/// special cases, or returns the lowercase name if no case applies.
-fn map_module_to_name(module: &str) -> String {
-    let lower_case = module.to_lowercase();
-    match lower_case.as_str() {
-        "certificatecontrol" => "certificateControl".into(),
-        "currentrecord" => "currentRecord".into(),
-        "keycontrol" => "keyControl".into(),
-        "recordcontext" => "recordContext".into(),
-        "suiteappinfo" => "suiteAppInfo".into(),
-        "serverwidget" => "serverWidget".into(),
-        _ => lower_case,
+/// Without arguments, prompts for the options of a new script in a terminal, and prints the help
+/// message otherwise. Invalid arguments are reported by clap, which exits with the usage code.
+fn run() -> Result<(), SuiteScriptError> {
+    if std::env::args_os().len() == 1 && std::io::stdin().is_terminal() {
+        let spec = ScriptSpec {
+            interactive: true,
+            ..ScriptSpec::default()
+        };
+        return new(spec, &ArgMatches::default());
+    }
+
+    let mut app = Opt::clap();
+    if wants_plain() {
+        app = app.global_setting(AppSettings::ColorNever);
+    }
+    let matches = app.get_matches_safe().unwrap_or_else(|error| {
+        if !error.use_stderr() {
+            error.exit();
+        }
+        if wants_json() {
+            let message = describe_clap_error(&error);
+            eprint!("{}", SuiteScriptError::Validation(message).to_json());
+        } else {
+            eprintln!("{}", error.message);
+        }
+        std::process::exit(EXIT_USAGE);
+    });
+    let opt = Opt::from_clap(&matches);
+    let plain = opt.plain;
+    match opt.command {
+        Some(Command::New(spec)) => new(spec, matches.subcommand_matches("new").unwrap()),
+        Some(Command::Audit(opt)) => {
+            if !audit::run(&opt)? {
+                std::process::exit(EXIT_FINDINGS);
+            }
+            Ok(())
+        }
+        Some(Command::Auth(opt)) => auth::run(&opt, plain),
+        Some(Command::Batch(opt)) => batch(&opt),
+        Some(Command::Clean(opt)) => clean::run(&opt, plain),
+        Some(Command::Completions(opt)) => {
+            completions::run(&opt, Opt::clap());
+            Ok(())
+        }
+        Some(Command::Config(opt)) => {
+            if !config::run(&opt)? {
+                std::process::exit(EXIT_FINDINGS);
+            }
+            Ok(())
+        }
+        Some(Command::Describe(opt)) => {
+            describe::run(&opt);
+            Ok(())
+        }
+        Some(Command::Fix(opt)) => fix::run(&opt),
+        Some(Command::Format(opt)) => formatter::run(&opt),
+        Some(Command::Import(opt)) => importer::run(&opt),
+        Some(Command::Init(opt)) => sdfproject::run(&opt),
+        Some(Command::InitTs(opt)) => tsproject::run(&opt),
+        Some(Command::Lint(opt)) => {
+            if !lint::run(&opt)? {
+                std::process::exit(EXIT_FINDINGS);
+            }
+            Ok(())
+        }
+        Some(Command::List(opt)) => {
+            list::run(&opt);
+            Ok(())
+        }
+        Some(Command::Logs(opt)) => logs::run(&opt),
+        Some(Command::Mock(opt)) => mocks::run(&opt),
+        Some(Command::Stats(opt)) => stats::run(&opt),
+        Some(Command::Templates(opt)) => templates::run(&opt),
+        Some(Command::UpdateCopyright(opt)) => copyright::run(&opt),
+        Some(Command::Upload(opt)) => upload::run(&opt),
+        None => unreachable!("Arguments are required"),
     }
 }
 
-/// Converts a given module name to its supported `NetSuite` name.
-///
-/// Maps over a vector of module names, applying `map_module_to_name` to each name.
-fn get_module_names(modules: &[String]) -> Vec<String> {
-    modules
-        .iter()
-        .map(|name| map_module_to_name(name))
-        .collect()
-}
-
-/// Formats a list of `NetSuite` module names into the correct import string.
-///
-/// Joins modules with a comma, newline, and prefix of `N/`. Indentation is 2 spaces.
-fn format_imports(modules: &[String]) -> String {
-    modules.join("',\n  'N/")
+/// Checks whether the results are requested as JSON, with `--json` or `--print-plan`, including when
+/// the arguments cannot be parsed.
+fn wants_json() -> bool {
+    std::env::args_os()
+        .take_while(|arg| arg != "--")
+        .any(|arg| arg == "--json" || arg == "--print-plan")
 }
 
-/// Formats a list of `NetSuite` module names into an argument list.
-///
-/// Removes any `/` in module names. Joins modules with a comma and space.
-fn format_args(modules: &[String]) -> String {
-    let cleaned: Vec<String> = modules.iter().map(|name| name.replace('/', "")).collect();
-    cleaned.join(", ")
+/// Checks whether colors and interactive prompts are disabled with `--plain`, including when the
+/// arguments cannot be parsed.
+fn wants_plain() -> bool {
+    std::env::args_os()
+        .take_while(|arg| arg != "--")
+        .any(|arg| arg == "--plain")
 }
 
-/// Writes the given `SuiteScript` import modules to the file.
+/// Runs the `new` command.
 ///
-/// Returns a string with the formatted imports and args and the symbols around them if modules
-/// were passed in. Otherwise, returns a string with the symbols for an AMD module with no imports.
-fn get_modules(modules: &[String]) -> String {
-    if modules == vec![String::from("")] {
-        return String::from("], () => {\n");
-    }
-
-    let mods = get_module_names(modules);
-    format!(
-        "  'N/{}',\n], ({}) => {{\n",
-        format_imports(&mods),
-        format_args(&mods)
-    )
-}
+/// Applies the configs to the options that were not given as arguments, prompts for the options
+/// of an interactive script, and places a bare file name in the File Cabinet of the `SuiteCloud`
+/// project it is run in. If input validation is successful, creates the file and populates it
+/// according to the given inputs.
+fn new(mut spec: ScriptSpec, matches: &ArgMatches) -> Result<(), SuiteScriptError> {
+    let cwd = current_dir()?;
+    apply_configs(&mut spec, matches, &cwd)?;
+    if spec.interactive && matches.is_present("plain") {
+        return Err(SuiteScriptError::Validation(String::from(
+            "--interactive prompts for the options, which --plain disables",
+        )));
+    }
+    if spec.interactive {
+        let stdin = std::io::stdin();
+        wizard::prompt(&mut spec, &mut stdin.lock(), &mut std::io::stdout())
+            .map_err(|source| SuiteScriptError::io("Failed to prompt for the options", source))?;
+    }
+    spec.modules = resolve_modules(spec.modules)?;
+    let placed = sdfproject::resolve_file_name(&mut spec, &cwd);
+    let (config, mut notices) = prepare(&spec)?;
+    notices.splice(0..0, placed);
+    if !config.json {
+        for notice in &notices {
+            eprintln!("{}", notice);
+        }
+    }
 
-/// Creates a file with a given name.
-fn create_file(file_name: &Path) -> File {
-    File::create(file_name).unwrap()
-}
+    if config.header_only {
+        print!("{}", render_header(&config)?);
+        return Ok(());
+    }
 
-/// Writes given contents to a given file.
-fn write_to_file(file: &mut File, contents: &str) {
-    file.write_all(contents.as_bytes()).unwrap();
-}
+    let script = get_script_path(&config)?.to_path_buf();
+    if config.stdout {
+        return print_script(&config, &script);
+    }
 
-/// Checks if a file has an extension.
-///
-/// Retrieves the file extension from a given path, if available. Otherwise, returns a message that
-/// the path does not have an extension.
-///
-/// # Panics
-///
-/// Panics if the path extension is not valid unicode.
-fn validate_file(path: &Path) -> &str {
-    path.extension()
-        .map_or("File name missing extension", |ext| ext.to_str().unwrap())
-}
+    if !config.create_dirs {
+        validate_parent_dir(&script)?;
+    }
 
-/// Validates a given file name for a copyright file.
-///
-/// A copyright file is required to be a text file. It is assumed that the contents of the file
-/// contain a JSDoc style doc comment with a copyright message.
-fn validate_copyright_file(name: String) -> Result<(), String> {
-    // TODO: Check if file exists
-    if name.is_empty() {
+    let companions = get_companion_files(&config)?;
+    if config.print_plan {
+        let rendered = OutputFile {
+            path: script.clone(),
+            contents: render(&config)?,
+        };
+        print!("{}", format_plan(&config, &rendered, &companions));
         return Ok(());
     }
-
-    let path = Path::new(&name);
-    let ext = validate_file(path);
-    if ext != "txt" {
-        return Err(String::from(
-            "Invalid file type: copyright file must be a text file.",
-        ));
+    if config.dry_run {
+        println!("==> {} <==", script.display());
+        print_script(&config, &script)?;
+        println!();
+        for file in &companions {
+            println!("==> {} <==\n{}", file.path.display(), file.contents);
+        }
+        eprintln!("note: dry run, no files were written");
+        return Ok(());
     }
 
+    if config.deploy {
+        deploy::find_script_file_cabinet(&script)?;
+    }
+    let mut lock = Lock::open(&cwd, config.lock)?;
+    let (written, locked) = write_files(&config, &script, &companions, lock.as_ref())?;
+    if let Some(lock) = lock.as_mut() {
+        lock.record(locked);
+        lock.save()?;
+    }
+    if !config.json {
+        for notice in &written {
+            eprintln!("{}", notice);
+        }
+        for file in &companions {
+            eprintln!("note: created {}", file.path.display());
+        }
+    }
+    notices.extend(written);
+    if config.json {
+        print!(
+            "{}",
+            format_json_result(&config, &script, &companions, &notices)
+        );
+    }
+    if config.deploy {
+        let code = deploy::run(&config, &script)?;
+        if code != 0 {
+            std::process::exit(code);
+        }
+    }
     Ok(())
 }
 
-/// Validates a given file name for a `SuiteScript` file.
+/// A script of a batch, validated and ready to be written.
+struct PlannedScript {
+    /// Label of the script in errors, such as `row 2`
+    label: String,
+    config: ScriptSpec,
+    /// Path to write the script to
+    script: PathBuf,
+    /// Paths of the files generated alongside the script, which are generated again when they are
+    /// written so that a large batch does not hold their contents
+    companions: Vec<PathBuf>,
+    notices: Vec<Notice>,
+    /// Hash of what the script is generated from, recorded in the batch cache
+    hash: String,
+    /// Whether the script is skipped, as it is unchanged since the previous batch
+    cached: bool,
+}
+
+/// Runs the `batch` command.
 ///
-/// The file name is checked for its extension and existing parent directories if applicable.
-/// SuiteScript files must have a `.js` extension.
-fn validate_file_name(name: String) -> Result<(), String> {
-    let path = Path::new(&name);
-    let ext = validate_file(path);
-    if ext != "js" {
-        return Err(String::from(
-            "Invalid file type: SuiteScript file must be a JavaScript file.",
-        ));
-    }
-
-    if name.contains('/') || name.contains('\\') {
-        if let Some(parent) = path.parent() {
-            if !parent.is_dir() {
-                return Err(String::from("Parent directory does not exist"));
+/// Every script of the manifest or CSV file is validated and rendered before any file is written,
+/// so that a list with invalid scripts writes nothing and reports all of them at once. Scripts are
+/// rendered and written on several threads, and their warnings, errors, and the summary of the
+/// files created are printed in the order of the list. Scripts that would be generated the same way
+/// as by the previous batch are skipped, unless `--no-cache` is given.
+fn batch(opt: &batch::BatchOpt) -> Result<(), SuiteScriptError> {
+    let (source, entries) = batch::load_entries(opt)?;
+    let jobs = opt.get_jobs();
+    let cwd = current_dir()?;
+    let mut cache = batch::Cache::open(&cwd);
+    let fresh = |script: &Path, hash: &str| !opt.no_cache && cache.is_fresh(script, hash);
+    let plans = batch::map_parallel(&entries, jobs, |(label, entry)| {
+        let entry = entry.clone().map_err(SuiteScriptError::Validation)?;
+        plan_batch_entry(label, &entry, &cwd, &fresh)
+    });
+
+    let mut planned: Vec<PlannedScript> = Vec::new();
+    let mut errors = Vec::new();
+    for ((label, _), plan) in entries.iter().zip(plans) {
+        let plan = plan.and_then(|plan| {
+            let previous = planned.iter().find(|other| other.script == plan.script);
+            match previous {
+                Some(previous) => Err(SuiteScriptError::Validation(format!(
+                    "{} is also created by {}",
+                    plan.script.display(),
+                    previous.label
+                ))),
+                None => Ok(plan),
             }
+        });
+        match plan {
+            Ok(plan) => {
+                for notice in &plan.notices {
+                    eprintln!("{} ({})", notice, plan.script.display());
+                }
+                planned.push(plan);
+            }
+            Err(error) => errors.push(error.with_context(label)),
         }
     }
-
+    if !errors.is_empty() {
+        for error in &errors {
+            eprintln!("error: {}", error);
+        }
+        return Err(SuiteScriptError::Validation(format!(
+            "{} of {} scripts in {} are invalid, no files were written",
+            errors.len(),
+            entries.len(),
+            source.display()
+        )));
+    }
+
+    let (skipped, planned): (Vec<PlannedScript>, Vec<PlannedScript>) =
+        planned.into_iter().partition(|plan| plan.cached);
+    let mut lock = Lock::open(&cwd, planned.iter().any(|plan| plan.config.lock))?;
+    let written = batch::map_parallel(&planned, jobs, |plan| {
+        get_companion_files(&plan.config).and_then(|companions| {
+            write_files(&plan.config, &plan.script, &companions, lock.as_ref())
+        })
+    });
+    let mut failed = Vec::new();
+    let mut locked = Vec::new();
+    for (plan, result) in planned.iter().zip(written) {
+        match result {
+            Ok((notices, files)) => {
+                for notice in notices {
+                    eprintln!("{}", notice);
+                }
+                locked.extend(files);
+                let mut paths = vec![plan.script.clone()];
+                paths.extend(plan.companions.iter().cloned());
+                cache.record(&plan.script, plan.hash.clone(), &paths);
+            }
+            Err(error) => failed.push(error.with_context(&plan.label)),
+        }
+    }
+    if let Some(lock) = lock.as_mut() {
+        lock.record(locked);
+        lock.save()?;
+    }
+    if !planned.is_empty() {
+        cache.save()?;
+    }
+    if !failed.is_empty() {
+        let first = failed.remove(0);
+        for error in &failed {
+            eprintln!("error: {}", error);
+        }
+        return Err(first);
+    }
+
+    let noun = if planned.len() == 1 {
+        "script"
+    } else {
+        "scripts"
+    };
+    if !planned.is_empty() || skipped.is_empty() {
+        println!(
+            "Created {} {} from {}:",
+            planned.len(),
+            noun,
+            source.display()
+        );
+    }
+    for plan in &planned {
+        println!("  {}", plan.script.display());
+        for path in &plan.companions {
+            println!("    {}", path.display());
+        }
+    }
+    match skipped.len() {
+        0 => {}
+        1 => println!("Skipped 1 unchanged script, pass --no-cache to regenerate it"),
+        count => println!(
+            "Skipped {} unchanged scripts, pass --no-cache to regenerate them",
+            count
+        ),
+    }
     Ok(())
 }
 
-/// Validates a given `SuiteScript` script type against the list of supported script types.
+/// Resolves the options of a script listed in a manifest and renders it, without writing it, so
+/// that a script whose template cannot be rendered is reported before any file is written.
 ///
-/// Converts the given script name to lowercase to support mangled inputs. Checks the lowercase
-/// name against the list of supported script types in `assets/`.
-fn validate_script_type(name: String) -> Result<(), String> {
-    if name.is_empty() {
-        return Ok(());
-    }
-
-    let lower_case = name.to_lowercase();
-    if TYPES.contains(&&lower_case[..]) {
-        return Ok(());
-    }
-
-    Err(String::from("Invalid script type"))
+/// The options are parsed as the arguments of the `new` command, and the configs are applied to the
+/// options that are not set in the manifest. As with `new`, a bare file name is placed in the File
+/// Cabinet of the `SuiteCloud` project the batch is run in. A script that is `fresh`, as it would be
+/// generated the same way as by the previous batch, is not rendered and is planned to be skipped.
+fn plan_batch_entry(
+    label: &str,
+    entry: &[(String, config::Value)],
+    current_dir: &Path,
+    fresh: &dyn Fn(&Path, &str) -> bool,
+) -> Result<PlannedScript, SuiteScriptError> {
+    let matches = ScriptSpec::clap()
+        .get_matches_from_safe(batch::get_args(entry))
+        .map_err(|error| SuiteScriptError::Validation(describe_clap_error(&error)))?;
+    let mut spec = ScriptSpec::from_clap(&matches);
+    let unsupported = [
+        ("interactive", spec.interactive),
+        ("header-only", spec.header_only),
+        ("stdout", spec.stdout),
+        ("dry-run", spec.dry_run),
+        ("json", spec.json),
+        ("print-plan", spec.print_plan),
+    ];
+    if let Some((name, _)) = unsupported.iter().find(|(_, set)| *set) {
+        return Err(SuiteScriptError::Validation(format!(
+            "`{}` is not supported in a manifest",
+            name
+        )));
+    }
+
+    apply_configs(&mut spec, &matches, current_dir)?;
+    let placed = sdfproject::resolve_file_name(&mut spec, current_dir);
+    let (config, mut notices) = prepare(&spec)?;
+    notices.splice(0..0, placed);
+    let script = get_script_path(&config)?.to_path_buf();
+    let hash = batch::hash_entry(&config)?;
+    if fresh(&script, &hash) {
+        return Ok(PlannedScript {
+            label: label.to_owned(),
+            config,
+            script,
+            companions: Vec::new(),
+            notices,
+            hash,
+            cached: true,
+        });
+    }
+    let previous = read_kept_script(&script);
+    let dropped = render_to(&mut std::io::sink(), &config, previous.as_deref())?;
+    check_dropped_keep_regions(&script, &dropped, config.force)?;
+    if !config.create_dirs {
+        validate_parent_dir(&script)?;
+    }
+    let companions = get_companion_files(&config)?
+        .into_iter()
+        .map(|file| file.path)
+        .collect();
+    Ok(PlannedScript {
+        label: label.to_owned(),
+        config,
+        script,
+        companions,
+        notices,
+        hash,
+        cached: false,
+    })
 }
 
-/// Validates a given `SuiteScript` API version against the list of supported versions.
-fn validate_api_version(api: String) -> Result<(), String> {
-    if API.contains(&&api[..]) {
-        return Ok(());
+/// Applies the defaults of the user config and then the project config of the current directory, if
+/// any, to the options that were not given as arguments.
+fn apply_configs(
+    spec: &mut ScriptSpec,
+    matches: &ArgMatches,
+    current_dir: &Path,
+) -> Result<(), SuiteScriptError> {
+    let user_config = user_config_path().filter(|path| path.is_file());
+    for path in user_config
+        .iter()
+        .chain(find_project_config(current_dir).iter())
+    {
+        Config::load(path)?.apply(spec, &|arg| matches.occurrences_of(arg) > 0)?;
     }
+    Ok(())
+}
 
-    Err(String::from("Invalid API version"))
+/// Gets the current directory, which project configs and `SuiteCloud` projects are found from.
+fn current_dir() -> Result<PathBuf, SuiteScriptError> {
+    std::env::current_dir()
+        .map_err(|source| SuiteScriptError::io("Failed to read the current directory", source))
 }
 
-/// Validates a given `NetSuite` module name against the list of supported modules.
+/// Gets the path of a script whose options were resolved.
 ///
-/// Converts the given module to lowercase to support mangled inputs. Checks the lowercase name
-/// against the list of supported modules in `assets/`.
-fn validate_modules(name: String) -> Result<(), String> {
-    if name.is_empty() {
-        return Ok(());
+/// # Errors
+/// Returns a validation error if no file name is given, or if the path does not end in a file name,
+/// such as `..`
+fn get_script_path(config: &ScriptSpec) -> Result<&Path, SuiteScriptError> {
+    let path = config.file_name.as_deref().ok_or_else(|| {
+        SuiteScriptError::Validation(String::from(
+            "--filename is required unless --header-only is set",
+        ))
+    })?;
+    match path.file_name() {
+        Some(_) => Ok(path),
+        None => Err(SuiteScriptError::Validation(format!(
+            "Invalid file name {}: path does not end in a file name",
+            path.display()
+        ))),
     }
+}
 
-    let lower_case = name.to_lowercase();
-    if !MODULES.contains(&&lower_case[..]) {
-        return Err(format!("Invalid module name {}", name));
+/// Prints a script whose options were resolved, keeping the regions marked to be kept in the
+/// existing file, if any, and warning about the keep regions it would drop.
+///
+/// A pipe closed before the whole script is read, such as by `head`, is not an error.
+fn print_script(config: &ScriptSpec, path: &Path) -> Result<(), SuiteScriptError> {
+    let previous = read_kept_script(path);
+    let stdout = std::io::stdout();
+    let mut out = std::io::BufWriter::new(stdout.lock());
+    let printed = render_to(&mut out, config, previous.as_deref()).and_then(|dropped| {
+        out.flush()
+            .map_err(|source| SuiteScriptError::io("Failed to write script", source))?;
+        Ok(dropped)
+    });
+    let dropped = match printed {
+        Err(SuiteScriptError::Io { source, .. })
+            if source.kind() == std::io::ErrorKind::BrokenPipe =>
+        {
+            return Ok(())
+        }
+        printed => printed?,
+    };
+    for notice in check_dropped_keep_regions(path, &dropped, true)? {
+        eprintln!("{}", notice);
     }
-
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_valid_mod() {
-        assert_eq!(validate_modules(String::from("record")), Ok(()));
+/// Writes a script, rendering it straight into its file, and the files generated alongside it,
+/// backing up the files they overwrite if `--backup` is set and merging the edits made to them
+/// since they were generated if the project has a lock manifest.
+///
+/// Returns notes about the backups and merges, warnings about the keep regions dropped with
+/// `--force`, and the records of the files to add to the lock manifest.
+fn write_files(
+    config: &ScriptSpec,
+    script: &Path,
+    companions: &[OutputFile],
+    lock: Option<&Lock>,
+) -> Result<(Vec<Notice>, Vec<LockedFile>), SuiteScriptError> {
+    let (mut notices, locked) = write_script_file(config, script, lock)?;
+    let mut locked: Vec<LockedFile> = locked.into_iter().collect();
+    for file in companions {
+        if config.backup && file.path.is_file() {
+            let backup = backup_file(&file.path)?;
+            notices.push(describe_backup(&file.path, &backup));
+        }
+        let (merged, recorded) = write_companion_file(file, lock, config.force)?;
+        notices.extend(merged);
+        locked.extend(recorded);
     }
+    Ok((notices, locked))
+}
 
-    #[test]
-    fn test_invalid_mod() {
-        assert_eq!(
-            validate_modules(String::from("reecord")),
-            Err(String::from("Invalid module name reecord"))
-        );
-    }
+/// Describes an error parsing the arguments on one line, without the usage message.
+fn describe_clap_error(error: &Error) -> String {
+    let message = error.message.lines().next().unwrap_or_default();
+    message.trim_start_matches("error: ").to_owned()
+}
 
-    #[test]
-    fn test_valid_api() {
-        assert_eq!(validate_api_version(String::from("2")), Ok(()));
+/// Resolves the modules to import, reading them from stdin in place of a `-` entry.
+///
+/// Modules read from stdin are validated the same way as modules passed as arguments, with a
+/// validation error if any of them is not supported.
+fn resolve_modules(modules: Vec<String>) -> Result<Vec<String>, SuiteScriptError> {
+    if !modules.iter().any(|name| name == "-") {
+        return Ok(modules);
     }
 
-    #[test]
-    fn test_invalid_api() {
-        assert_eq!(
-            validate_api_version(String::from("1")),
-            Err(String::from("Invalid API version"))
-        );
-    }
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|source| SuiteScriptError::io("Failed to read modules from stdin", source))?;
 
-    #[test]
-    fn test_valid_script_type() {
-        assert_eq!(validate_script_type(String::from("mapreduce")), Ok(()));
-    }
+    let resolved: Vec<String> = modules
+        .into_iter()
+        .flat_map(|name| match name.as_str() {
+            "-" => parse_module_list(&input),
+            _ => vec![name],
+        })
+        .filter(|name| !name.is_empty())
+        .collect();
 
-    #[test]
-    fn test_invalid_script_type() {
-        assert_eq!(
-            validate_script_type(String::from("rest")),
-            Err(String::from("Invalid script type"))
-        );
+    for name in &resolved {
+        validate_modules(name.clone())?;
     }
 
-    #[test]
-    fn test_valid_file() {
-        assert_eq!(validate_file(Path::new("test.js")), "js");
-    }
-
-    #[test]
-    fn test_invalid_file() {
-        assert_eq!(
-            validate_file(Path::new("test")),
-            "File name missing extension"
-        );
+    if resolved.is_empty() {
+        return Ok(vec![String::from("")]);
     }
+    Ok(resolved)
+}
 
-    #[test]
-    fn test_valid_copyright() {
-        assert_eq!(
-            validate_copyright_file(String::from("copyright.txt")),
-            Ok(())
-        );
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
-    fn test_invalid_copyright() {
+    fn test_get_script_path() {
+        let spec = |file_name: Option<&str>| ScriptSpec {
+            file_name: file_name.map(PathBuf::from),
+            ..ScriptSpec::default()
+        };
         assert_eq!(
-            validate_copyright_file(String::from("copyright")),
-            Err(String::from(
-                "Invalid file type: copyright file must be a text file."
-            ))
+            get_script_path(&spec(Some("src/customer_ue.js"))).unwrap(),
+            Path::new("src/customer_ue.js")
         );
+        for (file_name, message) in [
+            (None, "--filename is required unless --header-only is set"),
+            (
+                Some("src/.."),
+                "Invalid file name src/..: path does not end in a file name",
+            ),
+        ] {
+            let error = get_script_path(&spec(file_name)).unwrap_err();
+            assert_eq!(error.kind(), "validation");
+            assert_eq!(error.to_string(), message);
+        }
     }
 
     #[test]
-    fn test_valid_script_file() {
-        assert_eq!(validate_file_name(String::from("test.js")), Ok(()));
-    }
-
-    #[test]
-    fn test_invalid_script_file() {
+    fn test_plan_batch_entry_in_sdf_project() {
+        let dir = std::env::temp_dir().join(format!("suitescript-batch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src/FileCabinet/SuiteScripts")).unwrap();
+        std::fs::write(dir.join("src/manifest.xml"), "").unwrap();
+        let entry = vec![
+            (
+                String::from("filename"),
+                config::Value::String(String::from("customer_ue.js")),
+            ),
+            (String::from("create-dirs"), config::Value::Bool(true)),
+        ];
+
+        let plan = plan_batch_entry("script 1", &entry, &dir, &|_, _| false);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let plan = plan.unwrap();
         assert_eq!(
-            validate_file_name(String::from("test")),
-            Err(String::from(
-                "Invalid file type: SuiteScript file must be a JavaScript file."
-            ))
+            plan.script,
+            PathBuf::from("src/FileCabinet/SuiteScripts/customer_ue.js")
         );
-    }
-
-    #[test]
-    fn test_valid_script_parent_dir() {
-        assert_eq!(validate_file_name(String::from("src/test.js")), Ok(()));
-    }
-
-    #[test]
-    fn test_invalid_script_parent_dir() {
         assert_eq!(
-            validate_file_name(String::from("nonexistent/test.js")),
-            Err(String::from("Parent directory does not exist"))
+            plan.notices[0].to_string(),
+            "note: placed customer_ue.js in the File Cabinet of the SuiteCloud project, as src/FileCabinet/SuiteScripts/customer_ue.js"
         );
     }
-
-    #[test]
-    fn test_format_imports() {
-        assert_eq!(
-            format_imports(&vec!["record".into(), "search".into()]),
-            String::from("record',\n  'N/search")
-        )
-    }
-
-    #[test]
-    fn test_format_args() {
-        assert_eq!(
-            format_args(&vec!["record".into(), "search".into(), "ui/dialog".into()]),
-            String::from("record, search, uidialog")
-        )
-    }
-
-    #[test]
-    fn test_get_mod_names() {
-        assert_eq!(
-            get_module_names(&vec![String::from("rEcOrD"), String::from("RECORDcontext")]),
-            vec![String::from("record"), String::from("recordContext")]
-        )
-    }
-
-    #[test]
-    fn test_map_script_name() {
-        assert_eq!(map_script_to_name("mApReDuCe"), "MapReduce")
-    }
 }
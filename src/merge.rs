@@ -0,0 +1,215 @@
+/// Opening line of the edits of a conflict.
+const CONFLICT_START: &str = "<<<<<<< edited";
+
+/// Line between the edits and the regenerated lines of a conflict.
+const CONFLICT_SEPARATOR: &str = "=======";
+
+/// Closing line of the regenerated lines of a conflict.
+const CONFLICT_END: &str = ">>>>>>> regenerated";
+
+/// The result of a three-way merge of the edits to a generated file with its regenerated contents.
+#[derive(Debug, PartialEq)]
+pub struct Merge {
+    /// Merged contents, with conflict markers around the lines changed on both sides
+    pub contents: String,
+    /// Number of conflicts
+    pub conflicts: usize,
+}
+
+/// Merges the edits made to a generated file with its regenerated contents.
+///
+/// `base` is the contents the file was generated with, `edited` is the file as it was edited, and
+/// `regenerated` is the new contents. Lines changed on one side only take that side. Lines changed
+/// the same way on both sides are taken once. Lines changed differently on both sides are written
+/// between conflict markers, as git does, with the edited lines first:
+///
+/// ```text
+/// <<<<<<< edited
+/// ...
+/// =======
+/// ...
+/// >>>>>>> regenerated
+/// ```
+pub fn merge(base: &str, edited: &str, regenerated: &str) -> Merge {
+    let base_lines: Vec<&str> = base.lines().collect();
+    let edited_lines: Vec<&str> = edited.lines().collect();
+    let regenerated_lines: Vec<&str> = regenerated.lines().collect();
+    let edited_matches = match_lines(&base_lines, &edited_lines);
+    let regenerated_matches = match_lines(&base_lines, &regenerated_lines);
+
+    let mut output: Vec<&str> = Vec::new();
+    let mut conflicts = 0;
+    let (mut o, mut a, mut b) = (0, 0, 0);
+    loop {
+        while o < base_lines.len()
+            && edited_matches[o] == Some(a)
+            && regenerated_matches[o] == Some(b)
+        {
+            output.push(base_lines[o]);
+            o += 1;
+            a += 1;
+            b += 1;
+        }
+
+        let next = (o..base_lines.len()).find_map(|index| {
+            match (edited_matches[index], regenerated_matches[index]) {
+                (Some(edited), Some(regenerated)) => Some((index, edited, regenerated)),
+                _ => None,
+            }
+        });
+        let (next_o, next_a, next_b) = next.unwrap_or((
+            base_lines.len(),
+            edited_lines.len(),
+            regenerated_lines.len(),
+        ));
+        let chunk_base = &base_lines[o..next_o];
+        let chunk_edited = &edited_lines[a..next_a];
+        let chunk_regenerated = &regenerated_lines[b..next_b];
+        if chunk_edited == chunk_base || chunk_edited == chunk_regenerated {
+            output.extend(chunk_regenerated);
+        } else if chunk_regenerated == chunk_base {
+            output.extend(chunk_edited);
+        } else {
+            conflicts += 1;
+            output.push(CONFLICT_START);
+            output.extend(chunk_edited);
+            output.push(CONFLICT_SEPARATOR);
+            output.extend(chunk_regenerated);
+            output.push(CONFLICT_END);
+        }
+
+        if next.is_none() {
+            break;
+        }
+        o = next_o;
+        a = next_a;
+        b = next_b;
+    }
+
+    let mut contents = output.join("\n");
+    if regenerated.ends_with('\n') {
+        contents.push('\n');
+    }
+    Merge {
+        contents,
+        conflicts,
+    }
+}
+
+/// Checks whether a file has conflict markers written by [`merge`] that were not resolved.
+pub fn has_conflicts(contents: &str) -> bool {
+    contents
+        .lines()
+        .any(|line| line == CONFLICT_START || line == CONFLICT_END)
+}
+
+/// Matches the lines of `base` with the lines of `other` along their longest common subsequence.
+///
+/// Returns the index of the matching line of `other` for each line of `base`, if any. The common
+/// prefix and suffix are matched first, so that only the changed middle is compared line by line.
+fn match_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; base.len()];
+    let prefix = base
+        .iter()
+        .zip(other)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix = base[prefix..]
+        .iter()
+        .rev()
+        .zip(other[prefix..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+    for (index, line) in matches.iter_mut().enumerate().take(prefix) {
+        *line = Some(index);
+    }
+    for offset in 1..=suffix {
+        matches[base.len() - offset] = Some(other.len() - offset);
+    }
+
+    let middle_base = &base[prefix..base.len() - suffix];
+    let middle_other = &other[prefix..other.len() - suffix];
+    let columns = middle_other.len() + 1;
+    let mut lengths = vec![0_u32; (middle_base.len() + 1) * columns];
+    for i in (0..middle_base.len()).rev() {
+        for j in (0..middle_other.len()).rev() {
+            lengths[i * columns + j] = if middle_base[i] == middle_other[j] {
+                lengths[(i + 1) * columns + j + 1] + 1
+            } else {
+                lengths[(i + 1) * columns + j].max(lengths[i * columns + j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    while i < middle_base.len() && j < middle_other.len() {
+        if middle_base[i] == middle_other[j] {
+            matches[prefix + i] = Some(prefix + j);
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * columns + j] >= lengths[i * columns + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "define([\n  'N/record',\n], (record) => {\n  const beforeLoad = (scriptContext) => {\n    // TODO\n  };\n\n  return { beforeLoad };\n});\n";
+
+    #[test]
+    fn test_merge_one_side() {
+        let edited = BASE.replace("    // TODO", "    log.debug('edited');");
+        let regenerated = BASE.replace("  'N/record',\n", "  'N/record',\n  'N/search',\n");
+        assert_eq!(
+            merge(BASE, &edited, &regenerated),
+            Merge {
+                contents: edited.replace("  'N/record',\n", "  'N/record',\n  'N/search',\n"),
+                conflicts: 0,
+            }
+        );
+        assert_eq!(merge(BASE, BASE, &regenerated).contents, regenerated);
+        assert_eq!(merge(BASE, &edited, BASE).contents, edited);
+    }
+
+    #[test]
+    fn test_merge_conflict() {
+        let edited = BASE.replace("  'N/record',\n", "  'N/query',\n");
+        let regenerated = BASE.replace("  'N/record',\n", "  'N/search',\n");
+        let merged = merge(BASE, &edited, &regenerated);
+        assert_eq!(merged.conflicts, 1);
+        assert_eq!(
+            merged.contents,
+            BASE.replace(
+                "  'N/record',\n",
+                "<<<<<<< edited\n  'N/query',\n=======\n  'N/search',\n>>>>>>> regenerated\n"
+            )
+        );
+        assert!(has_conflicts(&merged.contents));
+        assert!(!has_conflicts(BASE));
+    }
+
+    #[test]
+    fn test_merge_same_change() {
+        let changed = BASE.replace("    // TODO", "    log.debug('edited');");
+        assert_eq!(
+            merge(BASE, &changed, &changed),
+            Merge {
+                contents: changed.clone(),
+                conflicts: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_match_lines() {
+        assert_eq!(
+            match_lines(&["a", "b", "c", "d"], &["a", "c", "x", "d"]),
+            vec![Some(0), None, Some(1), Some(3)]
+        );
+    }
+}
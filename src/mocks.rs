@@ -0,0 +1,181 @@
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::assets::module_info::MODULE_INFO;
+use super::formatter::get_dependencies;
+use super::{read_to_string, write_output_file, OutputFile, SuiteScriptError};
+
+/// Options for generating Jest mocks of the modules imported by scripts.
+#[derive(StructOpt, Debug)]
+pub struct MockOpt {
+    /// Scripts whose modules are mocked
+    #[structopt(parse(from_os_str), required = true)]
+    scripts: Vec<PathBuf>,
+
+    /// Directory the mocks are written to, mapped to the `N` modules by the `moduleNameMapper` of
+    /// the Jest config
+    #[structopt(long = "out-dir", parse(from_os_str), default_value = "__mocks__")]
+    out_dir: PathBuf,
+}
+
+/// A member of a mocked module.
+#[derive(Debug, PartialEq)]
+enum Member {
+    /// A function, mocked with `jest.fn()`.
+    Function,
+    /// An enum or a namespace of constants, such as `record.Type`, mocked with an empty object.
+    Enum,
+    /// An object returned by the module, such as `Record`, or a namespace of functions.
+    Object(Vec<(String, Member)>),
+}
+
+/// Writes a mock of each `N` module imported by the scripts.
+///
+/// Mocks that already exist are left unchanged, so that the return values set up in them are
+/// never overwritten.
+///
+/// # Errors
+/// Returns an error if a script cannot be read, has no `define` dependency array, or if a mock
+/// cannot be written
+pub fn run(opt: &MockOpt) -> Result<(), SuiteScriptError> {
+    let mut modules: Vec<String> = Vec::new();
+    for script in &opt.scripts {
+        let contents = read_to_string(script)?;
+        let dependencies = get_dependencies(&contents).ok_or_else(|| {
+            SuiteScriptError::Validation(format!(
+                "{} has no define call with a dependency array",
+                script.display()
+            ))
+        })?;
+        for module in dependencies
+            .iter()
+            .filter_map(|dependency| dependency.strip_prefix("N/"))
+        {
+            if !modules.iter().any(|name| name == module) {
+                modules.push(module.to_owned());
+            }
+        }
+    }
+
+    for module in &modules {
+        let file = get_file(&opt.out_dir, module);
+        if file.path.exists() {
+            println!("Skipped {}, which already exists", file.path.display());
+            continue;
+        }
+        write_output_file(&file)?;
+        println!("Created {}", file.path.display());
+    }
+    Ok(())
+}
+
+/// Gets the mock of a module, named like the module is imported, such as `N/ui/serverWidget.js`.
+fn get_file(out_dir: &Path, module: &str) -> OutputFile {
+    OutputFile {
+        path: out_dir.join("N").join(format!("{}.js", module)),
+        contents: get_mock(module),
+    }
+}
+
+/// Writes the contents of the mock of a module.
+///
+/// The mock is a CommonJS module exporting the common members of the module listed in `assets/`.
+/// The objects the module returns, such as `Record`, are exported too, so that tests can return
+/// them from the mocked functions, such as with `record.load.mockReturnValue(record.Record)`.
+fn get_mock(module: &str) -> String {
+    let info = MODULE_INFO
+        .iter()
+        .find(|info| info.name == module.to_lowercase());
+    let mut members = Vec::new();
+    for member in info.map(|info| info.members).unwrap_or_default() {
+        let path: Vec<&str> = member.split('.').collect();
+        // Members of the module are named after it, such as `record.load`, unlike its objects
+        let path = match path.split_first() {
+            Some((first, rest)) if first.starts_with(char::is_lowercase) => rest,
+            _ => &path[..],
+        };
+        add_member(&mut members, path);
+    }
+
+    format!(
+        "/**\n * Mock of N/{} for Jest, whose functions are jest.fn() mocks returning undefined until\n * a test sets their return values.\n */\nmodule.exports = {};\n",
+        module,
+        format_object(&members, 0)
+    )
+}
+
+/// Adds a member to the members of a module or of one of its objects, such as `Record.save`.
+fn add_member(members: &mut Vec<(String, Member)>, path: &[&str]) {
+    let (name, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    let position = members.iter().position(|(member, _)| member == name);
+    if rest.is_empty() {
+        if position.is_none() {
+            let member = if name.starts_with(char::is_uppercase) {
+                Member::Enum
+            } else {
+                Member::Function
+            };
+            members.push(((*name).to_owned(), member));
+        }
+        return;
+    }
+
+    let index = position.unwrap_or_else(|| {
+        members.push(((*name).to_owned(), Member::Object(Vec::new())));
+        members.len() - 1
+    });
+    if !matches!(members[index].1, Member::Object(_)) {
+        members[index].1 = Member::Object(Vec::new());
+    }
+    if let Member::Object(children) = &mut members[index].1 {
+        add_member(children, rest);
+    }
+}
+
+/// Formats the members of a mock as an object literal indented by a level of 2 spaces.
+fn format_object(members: &[(String, Member)], level: usize) -> String {
+    if members.is_empty() {
+        return String::from("{}");
+    }
+    let indent = "  ".repeat(level + 1);
+    let lines: String = members
+        .iter()
+        .map(|(name, member)| {
+            let value = match member {
+                Member::Function => String::from("jest.fn()"),
+                Member::Enum => String::from("{}"),
+                Member::Object(children) => format_object(children, level + 1),
+            };
+            format!("{}{}: {},\n", indent, name, value)
+        })
+        .collect();
+    format!("{{\n{}{}}}", lines, "  ".repeat(level))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_mock() {
+        assert_eq!(
+            get_mock("record"),
+            "/**\n * Mock of N/record for Jest, whose functions are jest.fn() mocks returning undefined until\n * a test sets their return values.\n */\nmodule.exports = {\n  create: jest.fn(),\n  load: jest.fn(),\n  copy: jest.fn(),\n  transform: jest.fn(),\n  submitFields: jest.fn(),\n  delete: jest.fn(),\n  Record: {\n    save: jest.fn(),\n  },\n};\n"
+        );
+        assert!(get_mock("xml").contains(
+            "  Parser: {\n    fromString: jest.fn(),\n  },\n  XPath: {\n    select: jest.fn(),\n  },\n  validate: jest.fn(),\n"
+        ));
+        assert!(get_mock("cache").contains("  Scope: {},\n"));
+    }
+
+    #[test]
+    fn test_get_file() {
+        assert_eq!(
+            get_file(Path::new("__mocks__"), "ui/serverWidget").path,
+            PathBuf::from("__mocks__/N/ui/serverWidget.js")
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+use std::fmt;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Text shown in place of the keys, tokens, and secrets of credentials.
+const REDACTED: &str = "[redacted]";
+
+/// Token based authentication credentials of an integration and a user of an account.
+///
+/// The keys, tokens, and secrets are redacted when the credentials are debug formatted.
+#[derive(Clone, PartialEq)]
+pub struct Credentials {
+    /// Consumer key of the integration record
+    pub consumer_key: String,
+    /// Consumer secret of the integration record
+    pub consumer_secret: String,
+    /// ID of the access token of the user
+    pub token_id: String,
+    /// Secret of the access token of the user
+    pub token_secret: String,
+}
+
+impl Credentials {
+    /// Replaces the keys, tokens, and secrets of the credentials in a text, such as an error
+    /// echoing a request, with `[redacted]`, as they are or percent encoded.
+    pub fn redact(&self, text: &str) -> String {
+        let mut values: Vec<String> = self
+            .values()
+            .iter()
+            .filter(|value| !value.is_empty())
+            .flat_map(|value| vec![(*value).to_owned(), percent_encode(value)])
+            .collect();
+        // Longer values are replaced first, so that a value within another is not left behind
+        values.sort_by_key(|value| std::cmp::Reverse(value.len()));
+        values
+            .iter()
+            .fold(text.to_owned(), |text, value| text.replace(value, REDACTED))
+    }
+
+    /// Gets the keys, tokens, and secrets of the credentials.
+    fn values(&self) -> [&str; 4] {
+        [
+            &self.consumer_key,
+            &self.consumer_secret,
+            &self.token_id,
+            &self.token_secret,
+        ]
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("consumer_key", &REDACTED)
+            .field("consumer_secret", &REDACTED)
+            .field("token_id", &REDACTED)
+            .field("token_secret", &REDACTED)
+            .finish()
+    }
+}
+
+/// Percent encodes a value as required by OAuth 1.0, leaving only unreserved characters as they
+/// are.
+pub fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Gets the `Authorization` header of a request signed with token based authentication.
+///
+/// The request is signed with OAuth 1.0 and HMAC-SHA256, over the method, the URL without its
+/// query, and the parameters of its query and of the header. The realm is the account ID, such as
+/// `1234567_SB1`. The nonce is 16 random bytes from the random number generator of the OS.
+///
+/// # Errors
+/// Returns an IO error if the random number generator of the OS is unavailable
+pub fn get_authorization(
+    method: &str,
+    url: &str,
+    account: &str,
+    credentials: &Credentials,
+) -> io::Result<String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).map_err(io::Error::other)?;
+    let nonce: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    Ok(sign(
+        method,
+        url,
+        account,
+        credentials,
+        &timestamp.as_secs().to_string(),
+        &nonce,
+    ))
+}
+
+/// Signs a request with a given timestamp and nonce.
+fn sign(
+    method: &str,
+    url: &str,
+    account: &str,
+    credentials: &Credentials,
+    timestamp: &str,
+    nonce: &str,
+) -> String {
+    let oauth = [
+        ("oauth_consumer_key", credentials.consumer_key.as_str()),
+        ("oauth_nonce", nonce),
+        ("oauth_signature_method", "HMAC-SHA256"),
+        ("oauth_timestamp", timestamp),
+        ("oauth_token", credentials.token_id.as_str()),
+        ("oauth_version", "1.0"),
+    ];
+    let (base_url, query) = url.split_once('?').unwrap_or((url, ""));
+    let mut params: Vec<(String, String)> = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+            (percent_encode(name), percent_encode(value))
+        })
+        .chain(
+            oauth
+                .iter()
+                .map(|(name, value)| ((*name).to_owned(), percent_encode(value))),
+        )
+        .collect();
+    params.sort();
+    let params: Vec<String> = params
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+
+    let base = format!(
+        "{}&{}&{}",
+        method.to_uppercase(),
+        percent_encode(base_url),
+        percent_encode(&params.join("&"))
+    );
+    let key = format!(
+        "{}&{}",
+        percent_encode(&credentials.consumer_secret),
+        percent_encode(&credentials.token_secret)
+    );
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(base.as_bytes());
+    let signature = STANDARD.encode(mac.finalize().into_bytes());
+
+    let fields: Vec<String> = std::iter::once(("realm", account))
+        .chain(oauth.iter().copied())
+        .chain(std::iter::once(("oauth_signature", signature.as_str())))
+        .map(|(name, value)| format!("{}=\"{}\"", name, percent_encode(value)))
+        .collect();
+    format!("OAuth {}", fields.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        let credentials = Credentials {
+            consumer_key: String::from("consumer"),
+            consumer_secret: String::from("consumer secret"),
+            token_id: String::from("token"),
+            token_secret: String::from("token secret"),
+        };
+        let header = sign(
+            "post",
+            "https://1234567.restlets.api.netsuite.com/app/site/hosting/restlet.nl?script=10&deploy=1",
+            "1234567",
+            &credentials,
+            "1700000000",
+            "abc",
+        );
+        assert_eq!(
+            header,
+            "OAuth realm=\"1234567\", oauth_consumer_key=\"consumer\", oauth_nonce=\"abc\", oauth_signature_method=\"HMAC-SHA256\", oauth_timestamp=\"1700000000\", oauth_token=\"token\", oauth_version=\"1.0\", oauth_signature=\"tlcG3B%2FOCjXBNo4rgaIMagXw6%2F4bQp0Gf46iRhXBd%2Fg%3D\""
+        );
+    }
+
+    #[test]
+    fn test_redact() {
+        let credentials = Credentials {
+            consumer_key: String::from("ck"),
+            consumer_secret: String::from("consumer secret"),
+            token_id: String::from("ti"),
+            token_secret: String::from("ts"),
+        };
+        assert_eq!(
+            credentials.redact("oauth_token=\"ti\", key consumer%20secret, ck"),
+            "oauth_token=\"[redacted]\", key [redacted], [redacted]"
+        );
+        assert_eq!(
+            format!("{:?}", credentials),
+            "Credentials { consumer_key: \"[redacted]\", consumer_secret: \"[redacted]\", token_id: \"[redacted]\", token_secret: \"[redacted]\" }"
+        );
+    }
+}
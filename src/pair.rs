@@ -0,0 +1,301 @@
+use std::path::{Path, PathBuf};
+
+use super::typescript::is_typescript;
+use super::{
+    check_dropped_keep_regions, get_file_name, map_script_to_name, prepare, preserve_keep_regions,
+    read_kept_script, render, render_header, OutputFile, ScriptSpec, SuiteScriptError, KEEP_END,
+    KEEP_START,
+};
+
+/// Suffixes of the scripts that can be paired, removed from the names of the files paired with
+/// them.
+const SUFFIXES: [&str; 2] = ["_sl", "_ue"];
+
+/// Gets the name of a paired script without its extension and its `_sl` or `_ue` suffix, such as
+/// `customer` for `customer_ue.js`.
+pub fn get_base_name(script: &Path) -> String {
+    let stem = script.file_stem().unwrap_or_default().to_string_lossy();
+    let base = SUFFIXES
+        .iter()
+        .find_map(|suffix| {
+            let end = stem.len().checked_sub(suffix.len())?;
+            let matches = stem.is_char_boundary(end) && stem[end..].eq_ignore_ascii_case(suffix);
+            Some(&stem[..end]).filter(|_| matches)
+        })
+        .unwrap_or(&stem);
+    base.to_owned()
+}
+
+/// Gets the path of the client script paired with a Suitelet or a user event.
+///
+/// The client script is named after the script, without its `_sl` or `_ue` suffix if it has one,
+/// with a `_cs` suffix and the same extension, so that `mysuitelet.js` is paired with
+/// `mysuitelet_cs.js` and `customer_ue.ts` with `customer_cs.ts`.
+pub fn get_client_path(script: &Path) -> PathBuf {
+    script.with_file_name(format!(
+        "{}_cs.{}",
+        get_base_name(script),
+        get_extension(script)
+    ))
+}
+
+/// Gets the path of the constants module shared by a user event and its client script, such as
+/// `customer_constants.js` for `customer_ue.js`.
+pub fn get_constants_path(script: &Path) -> PathBuf {
+    script.with_file_name(format!(
+        "{}_constants.{}",
+        get_base_name(script),
+        get_extension(script)
+    ))
+}
+
+/// Gets the extension of a paired script, `js` if it has none.
+fn get_extension(script: &Path) -> String {
+    script.extension().map_or_else(
+        || String::from("js"),
+        |extension| extension.to_string_lossy().into_owned(),
+    )
+}
+
+/// Gets the dependency importing a module from the scripts next to it, such as
+/// `./customer_constants` for `customer_constants.js`.
+pub fn get_dependency(module: &Path) -> String {
+    format!(
+        "./{}",
+        module.file_stem().unwrap_or_default().to_string_lossy()
+    )
+}
+
+/// Gets the client script paired with a Suitelet or a user event whose options were resolved.
+///
+/// The client script has the same API version, copyright, author, and module scope as the
+/// script, imports `N/currentRecord`, and refers to the script in a `@see` tag. The client script
+/// of a user event also imports their constants module. Code in the keep regions of an existing
+/// client script is kept.
+///
+/// # Errors
+/// Returns an IO or template error if the client script cannot be rendered
+pub fn get_client_file(config: &ScriptSpec) -> Result<OutputFile, SuiteScriptError> {
+    let script = config
+        .file_name
+        .as_deref()
+        .expect("File name is required to pair a script");
+    let path = get_client_path(script);
+    let (see, dependencies) = match map_script_to_name(&config.script_type) {
+        "UserEvent" => {
+            let constants = get_constants_path(script);
+            (
+                vec![
+                    format!(
+                        "./{} User event script of the record",
+                        get_file_name(script)
+                    ),
+                    format!(
+                        "./{} Field IDs shared with the user event script",
+                        get_file_name(&constants)
+                    ),
+                ],
+                vec![get_dependency(&constants)],
+            )
+        }
+        _ => (
+            vec![format!(
+                "./{} Suitelet whose form this script is attached to",
+                get_file_name(script)
+            )],
+            Vec::new(),
+        ),
+    };
+    let spec = ScriptSpec {
+        file_name: Some(path.clone()),
+        script_type: String::from("client"),
+        api_version: config.api_version.clone(),
+        modules: vec![String::from("currentrecord")],
+        copyright: config.copyright.clone(),
+        copyright_text: config.copyright_text.clone(),
+        templates_dir: config.templates_dir.clone(),
+        no_header: config.no_header,
+        ts_check: config.ts_check,
+        lang: config.lang.clone(),
+        scope: config.scope.clone(),
+        author: config.author.clone(),
+        company: config.company.clone(),
+        provenance: config.provenance,
+        reproducible: config.reproducible,
+        see,
+        dependencies,
+        ..ScriptSpec::default()
+    };
+    let (spec, _) = prepare(&spec)?;
+    keep_existing(path, render(&spec)?, config.force)
+}
+
+/// Gets the constants module shared by a user event whose options were resolved and its client
+/// script.
+///
+/// The module exports the record type of the user event and an object of field IDs to fill in,
+/// inside a keep region so that the IDs survive regeneration. A `TypeScript` module exports them
+/// as constants.
+///
+/// # Errors
+/// Returns an IO error if the copyright file cannot be read
+pub fn get_constants_file(config: &ScriptSpec) -> Result<OutputFile, SuiteScriptError> {
+    let script = config
+        .file_name
+        .as_deref()
+        .expect("File name is required to pair a script");
+    let path = get_constants_path(script);
+    let spec = ScriptSpec {
+        file_name: Some(path.clone()),
+        script_type: String::from("library"),
+        see: vec![
+            format!(
+                "./{} User event script of the record",
+                get_file_name(script)
+            ),
+            format!(
+                "./{} Client script of the record",
+                get_file_name(&get_client_path(script))
+            ),
+        ],
+        description: None,
+        amd_config: None,
+        ..config.clone()
+    };
+    let header = if spec.no_header {
+        String::new()
+    } else {
+        render_header(&spec)?
+    };
+    let ts_check = if spec.ts_check {
+        "// @ts-check\n\n"
+    } else {
+        ""
+    };
+    let record = config.record.as_deref().unwrap_or_default();
+    if is_typescript(config) {
+        let contents = format!(
+            "{}{}>\n/** Type of the record customized by the scripts. */\nexport const RECORD_TYPE = '{}';\n\n/** IDs of the fields used by the scripts, such as `STATUS: 'custentity_status'`. */\nexport const FIELDS = {{\n  // TODO: Add the IDs of the fields\n}};\n{}\n",
+            header, KEEP_START, record, KEEP_END
+        );
+        return keep_existing(path, contents, config.force);
+    }
+    let contents = format!(
+        "{}{}define([], () => {{\n  {}>\n  /** Type of the record customized by the scripts. */\n  const RECORD_TYPE = '{}';\n\n  /** IDs of the fields used by the scripts, such as `STATUS: 'custentity_status'`. */\n  const FIELDS = {{\n    // TODO: Add the IDs of the fields\n  }};\n\n  return {{ RECORD_TYPE, FIELDS }};\n  {}\n}});",
+        ts_check, header, KEEP_START, record, KEEP_END
+    );
+    keep_existing(path, contents, config.force)
+}
+
+/// Keeps the code in the keep regions of the file at a path, if it exists, in its new contents.
+///
+/// # Errors
+/// Returns a validation error if keep regions of the file are no longer generated, unless `force`
+/// is set
+fn keep_existing(
+    path: PathBuf,
+    contents: String,
+    force: bool,
+) -> Result<OutputFile, SuiteScriptError> {
+    let contents = match read_kept_script(&path) {
+        Some(previous) => {
+            let (contents, dropped) = preserve_keep_regions(&contents, &previous);
+            check_dropped_keep_regions(&path, &dropped, force)?;
+            contents
+        }
+        None => contents,
+    };
+    Ok(OutputFile { path, contents })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_client_path() {
+        assert_eq!(
+            get_client_path(Path::new("src/mysuitelet.js")),
+            PathBuf::from("src/mysuitelet_cs.js")
+        );
+        assert_eq!(
+            get_client_path(Path::new("customer_SL.js")),
+            PathBuf::from("customer_cs.js")
+        );
+        assert_eq!(
+            get_constants_path(Path::new("src/customer_ue.js")),
+            PathBuf::from("src/customer_constants.js")
+        );
+        assert_eq!(
+            get_dependency(Path::new("src/customer_constants.js")),
+            "./customer_constants"
+        );
+    }
+
+    #[test]
+    fn test_get_client_file() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("mysuitelet.js")),
+            script_type: String::from("suitelet"),
+            pair: true,
+            ..ScriptSpec::default()
+        };
+        let (config, _) = prepare(&spec).unwrap();
+        let client = get_client_file(&config).unwrap();
+        assert_eq!(client.path, PathBuf::from("mysuitelet_cs.js"));
+        assert!(client.contents.starts_with(
+            "/**\n * @NScriptType ClientScript\n * @NApiVersion 2.1\n * @see ./mysuitelet.js Suitelet whose form this script is attached to\n */\n\ndefine([\n  'N/currentRecord',\n"
+        ));
+    }
+
+    #[test]
+    fn test_user_event_pair() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_ue.js")),
+            script_type: String::from("userevent"),
+            modules: vec![String::from("record")],
+            pair: true,
+            record: Some(String::from("customer")),
+            ..ScriptSpec::default()
+        };
+        let (config, notices) = prepare(&spec).unwrap();
+        assert!(notices.is_empty());
+        assert!(render(&config).unwrap().contains(
+            " * @see ./customer_constants.js Field IDs shared with the client script\n */\n\ndefine([\n  'N/record',\n  './customer_constants',\n], (record, customerConstants) => {\n"
+        ));
+
+        let client = get_client_file(&config).unwrap();
+        assert_eq!(client.path, PathBuf::from("customer_cs.js"));
+        assert!(client.contents.contains(
+            "  'N/currentRecord',\n  './customer_constants',\n], (currentRecord, customerConstants) => {\n"
+        ));
+
+        let constants = get_constants_file(&config).unwrap();
+        assert_eq!(constants.path, PathBuf::from("customer_constants.js"));
+        assert!(constants.contents.starts_with(
+            "/**\n * @NApiVersion 2.1\n * @see ./customer_ue.js User event script of the record\n * @see ./customer_cs.js Client script of the record\n */\n\ndefine([], () => {\n"
+        ));
+        assert!(constants
+            .contents
+            .contains("  const RECORD_TYPE = 'customer';\n"));
+    }
+
+    #[test]
+    fn test_typescript_pair() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_ue.ts")),
+            pair: true,
+            ..ScriptSpec::default()
+        };
+        let (config, _) = prepare(&spec).unwrap();
+        assert_eq!(
+            get_client_file(&config).unwrap().path,
+            PathBuf::from("customer_cs.ts")
+        );
+        let constants = get_constants_file(&config).unwrap();
+        assert_eq!(constants.path, PathBuf::from("customer_constants.ts"));
+        assert!(constants.contents.contains(
+            "\n\n// <suitescript:keep>\n/** Type of the record customized by the scripts. */\nexport const RECORD_TYPE = 'customer';\n"
+        ));
+    }
+}
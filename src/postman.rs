@@ -0,0 +1,163 @@
+use std::path::{Path, PathBuf};
+
+use super::{escape_json, OutputFile};
+
+/// HTTP methods described by the collection, each with an example payload if it sends a body.
+const METHODS: [(&str, Option<&str>); 4] = [
+    ("GET", None),
+    ("POST", Some("{\n  \"id\": \"\"\n}")),
+    ("PUT", Some("{\n  \"id\": \"\"\n}")),
+    ("DELETE", None),
+];
+
+/// Collection variables, with their default values, used by the requests of the collection.
+const VARIABLES: [(&str, &str); 8] = [
+    ("account", ""),
+    ("realm", ""),
+    ("scriptId", ""),
+    ("deployId", "1"),
+    ("consumerKey", ""),
+    ("consumerSecret", ""),
+    ("tokenId", ""),
+    ("tokenSecret", ""),
+];
+
+/// Gets a Postman collection for a RESTlet next to the script.
+///
+/// The collection is named `<script>.postman_collection.json` and can also be imported into
+/// Insomnia. It has a request for each of the given methods handled by the RESTlet.
+pub fn get_file(script: &Path, methods: &[&str]) -> OutputFile {
+    OutputFile {
+        path: get_collection_path(script),
+        contents: get_collection(script, methods),
+    }
+}
+
+/// Gets the path of the collection for a script.
+fn get_collection_path(script: &Path) -> PathBuf {
+    script.with_extension("postman_collection.json")
+}
+
+/// Writes a request of the collection for a method.
+///
+/// Requests use the standard `NetSuite` RESTlet URL and token based authentication, with the
+/// account, script, deployment, and credentials taken from the collection variables.
+fn get_request(name: &str, method: &str, payload: Option<&str>) -> String {
+    let auth: Vec<String> = [
+        ("consumerKey", "{{consumerKey}}"),
+        ("consumerSecret", "{{consumerSecret}}"),
+        ("token", "{{tokenId}}"),
+        ("tokenSecret", "{{tokenSecret}}"),
+        ("realm", "{{realm}}"),
+        ("signatureMethod", "HMAC-SHA256"),
+    ]
+    .iter()
+    .map(|(key, value)| {
+        format!(
+            "            {{ \"key\": \"{}\", \"value\": \"{}\" }}",
+            key, value
+        )
+    })
+    .collect();
+
+    let body = payload.map_or_else(String::new, |payload| {
+        format!(
+            ",\n        \"body\": {{ \"mode\": \"raw\", \"raw\": \"{}\" }}",
+            escape_json(payload)
+        )
+    });
+
+    format!(
+        "    {{
+      \"name\": \"{method} {name}\",
+      \"request\": {{
+        \"method\": \"{method}\",
+        \"auth\": {{
+          \"type\": \"oauth1\",
+          \"oauth1\": [
+{auth}
+          ]
+        }},
+        \"header\": [{{ \"key\": \"Content-Type\", \"value\": \"application/json\" }}],
+        \"url\": {{
+          \"raw\": \"https://{{{{account}}}}.restlets.api.netsuite.com/app/site/hosting/restlet.nl?script={{{{scriptId}}}}&deploy={{{{deployId}}}}\",
+          \"protocol\": \"https\",
+          \"host\": [\"{{{{account}}}}\", \"restlets\", \"api\", \"netsuite\", \"com\"],
+          \"path\": [\"app\", \"site\", \"hosting\", \"restlet.nl\"],
+          \"query\": [
+            {{ \"key\": \"script\", \"value\": \"{{{{scriptId}}}}\" }},
+            {{ \"key\": \"deploy\", \"value\": \"{{{{deployId}}}}\" }}
+          ]
+        }}{body}
+      }}
+    }}",
+        method = method,
+        name = name,
+        auth = auth.join(",\n"),
+        body = body,
+    )
+}
+
+/// Writes the contents of the collection for the given methods of a script.
+fn get_collection(script: &Path, methods: &[&str]) -> String {
+    let name = escape_json(&script.file_stem().unwrap_or_default().to_string_lossy());
+    let requests: Vec<String> = METHODS
+        .iter()
+        .filter(|(method, _)| methods.iter().any(|name| name.eq_ignore_ascii_case(method)))
+        .map(|(method, payload)| get_request(&name, method, *payload))
+        .collect();
+    let variables: Vec<String> = VARIABLES
+        .iter()
+        .map(|(key, value)| format!("    {{ \"key\": \"{}\", \"value\": \"{}\" }}", key, value))
+        .collect();
+
+    format!(
+        "{{
+  \"info\": {{
+    \"name\": \"{}\",
+    \"schema\": \"https://schema.getpostman.com/json/collection/v2.1.0/collection.json\"
+  }},
+  \"item\": [
+{}
+  ],
+  \"variable\": [
+{}
+  ]
+}}
+",
+        name,
+        requests.join(",\n"),
+        variables.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_path() {
+        assert_eq!(
+            get_collection_path(Path::new("src/customer_rl.js")),
+            PathBuf::from("src/customer_rl.postman_collection.json")
+        );
+    }
+
+    #[test]
+    fn test_collection_requests() {
+        let collection = get_collection(
+            Path::new("customer_rl.js"),
+            &["get", "post", "put", "delete"],
+        );
+        assert!(collection.contains("\"name\": \"POST customer_rl\""));
+        assert!(collection.contains("\"raw\": \"https://{{account}}.restlets.api.netsuite.com/app/site/hosting/restlet.nl?script={{scriptId}}&deploy={{deployId}}\""));
+        assert_eq!(collection.matches("\"mode\": \"raw\"").count(), 2);
+    }
+
+    #[test]
+    fn test_collection_selected_methods() {
+        let collection = get_collection(Path::new("customer_rl.js"), &["get"]);
+        assert!(collection.contains("\"name\": \"GET customer_rl\""));
+        assert!(!collection.contains("\"name\": \"POST customer_rl\""));
+    }
+}
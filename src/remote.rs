@@ -0,0 +1,154 @@
+use sha2::{Digest, Sha256};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use super::{write_atomically, SuiteScriptError};
+
+/// Scheme of the URLs that templates and copyright files can be fetched from.
+const SCHEME: &str = "https://";
+
+/// Fragment of a URL giving the SHA-256 checksum of its contents, such as `#sha256=<hex>`.
+const CHECKSUM: &str = "#sha256=";
+
+/// Largest file fetched, which templates and copyright messages are far smaller than.
+const MAX_SIZE: u64 = 1024 * 1024;
+
+/// Checks whether the path of a template or a copyright file is an HTTPS URL.
+pub fn is_url(path: &Path) -> bool {
+    path.to_str().is_some_and(|path| path.starts_with(SCHEME))
+}
+
+/// Gets the path of a URL without its checksum, whose extension is the extension of the file.
+pub fn strip_checksum(url: &str) -> &str {
+    url.split_once(CHECKSUM).map_or(url, |(url, _)| url)
+}
+
+/// Fetches a template or a copyright file from an HTTPS URL, returning the path of its copy in
+/// the cache.
+///
+/// The URL ends with the SHA-256 checksum of the file, such as
+/// `https://example.com/layout.hbs#sha256=<hex>`, which the fetched file must match. Files are
+/// cached by checksum in `~/.cache/suitescript`, so that a cached file is used without fetching it
+/// again, and a changed file needs a new checksum.
+///
+/// # Errors
+/// Returns a validation error if the URL has no checksum or the file does not match it, and an IO
+/// error if the file cannot be fetched or cached
+pub fn fetch(url: &str) -> Result<PathBuf, SuiteScriptError> {
+    let checksum = url
+        .split_once(CHECKSUM)
+        .map(|(_, checksum)| checksum)
+        .filter(|checksum| is_checksum(checksum))
+        .ok_or_else(|| {
+            SuiteScriptError::Validation(format!(
+                "URL {} has no checksum, add #sha256=<checksum> with the SHA-256 checksum of the file",
+                url
+            ))
+        })?
+        .to_lowercase();
+    let dir = cache_dir().ok_or_else(|| {
+        SuiteScriptError::io(
+            "Failed to find the cache directory",
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "XDG_CACHE_HOME, HOME, and LOCALAPPDATA are not set",
+            ),
+        )
+    })?;
+    let path = dir.join(&checksum);
+    if let Ok(contents) = std::fs::read_to_string(&path) {
+        if get_checksum(&contents) == checksum {
+            return Ok(path);
+        }
+    }
+
+    let contents = download(strip_checksum(url))
+        .map_err(|source| SuiteScriptError::io(format!("Failed to fetch {}", url), source))?;
+    let actual = get_checksum(&contents);
+    if actual != checksum {
+        return Err(SuiteScriptError::Validation(format!(
+            "Checksum of {} is {}, expected {}",
+            strip_checksum(url),
+            actual,
+            checksum
+        )));
+    }
+    std::fs::create_dir_all(&dir).map_err(|source| {
+        SuiteScriptError::io(format!("Failed to create {}", dir.display()), source)
+    })?;
+    write_atomically(&path, &contents)?;
+    Ok(path)
+}
+
+/// Downloads the contents of a URL as text.
+fn download(url: &str) -> io::Result<String> {
+    let mut response = ureq::get(url).call().map_err(io::Error::other)?;
+    let mut contents = String::new();
+    response
+        .body_mut()
+        .as_reader()
+        .take(MAX_SIZE)
+        .read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+/// Checks whether a value is a SHA-256 checksum, as 64 hexadecimal digits.
+fn is_checksum(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Gets the SHA-256 checksum of contents, as lowercase hexadecimal digits.
+fn get_checksum(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Gets the directory of the cached templates and copyright files, such as
+/// `~/.cache/suitescript`.
+///
+/// Uses `XDG_CACHE_HOME` if set, `~/.cache` otherwise, and `%LOCALAPPDATA%` on systems without a
+/// home directory.
+fn cache_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .map(|dir| dir.join("suitescript"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_url() {
+        assert!(is_url(Path::new("https://example.com/layout.hbs")));
+        assert!(!is_url(Path::new("http://example.com/layout.hbs")));
+        assert!(!is_url(Path::new("templates/layout.hbs")));
+        assert_eq!(
+            strip_checksum("https://example.com/copyright.txt#sha256=abc"),
+            "https://example.com/copyright.txt"
+        );
+    }
+
+    #[test]
+    fn test_get_checksum() {
+        assert_eq!(
+            get_checksum("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert!(is_checksum(&get_checksum("abc")));
+        assert!(!is_checksum("abc"));
+    }
+
+    #[test]
+    fn test_fetch_without_checksum() {
+        assert_eq!(
+            fetch("https://example.com/layout.hbs").unwrap_err().to_string(),
+            "URL https://example.com/layout.hbs has no checksum, add #sha256=<checksum> with the SHA-256 checksum of the file"
+        );
+    }
+}
@@ -0,0 +1,353 @@
+use std::path::{Component, Path, PathBuf};
+
+use super::sdfproject::{escape_xml, OBJECTS_DIR};
+use super::{get_portlet_type, map_script_to_name, OutputFile, ScriptSpec};
+
+/// Prefix of the IDs of script records.
+pub const SCRIPT_ID_PREFIX: &str = "customscript_";
+
+/// Maximum length of the IDs of script records and deployments, including their prefix.
+pub const MAX_ID_LENGTH: usize = 40;
+
+/// Elements of the script records of each script type, such as `usereventscript`.
+const RECORD_TYPES: [(&str, &str); 11] = [
+    ("BundleInstallation", "bundleinstallationscript"),
+    ("Client", "clientscript"),
+    ("MapReduce", "mapreducescript"),
+    ("MassUpdate", "massupdatescript"),
+    ("Portlet", "portlet"),
+    ("RESTlet", "restlet"),
+    ("Scheduled", "scheduledscript"),
+    ("SDFInstallation", "sdfinstallationscript"),
+    ("Suitelet", "suitelet"),
+    ("UserEvent", "usereventscript"),
+    ("WorkflowAction", "workflowactionscript"),
+];
+
+/// Gets the element of the script record of a script type, such as `usereventscript`.
+///
+/// Returns `None` for script types without a script record, such as libraries.
+pub fn get_record_type(script_type: &str) -> Option<&'static str> {
+    let name = map_script_to_name(script_type);
+    RECORD_TYPES
+        .iter()
+        .find(|(script_type, _)| *script_type == name)
+        .map(|(_, record_type)| *record_type)
+}
+
+/// Gets the SDF object of the script record of a script, unless it already exists.
+///
+/// The object is written to the `Objects` directory of the project the script is in, found next
+/// to the `FileCabinet` directory containing it, or to `src/Objects` if the script is outside a
+/// project.
+pub fn get_file(script: &Path, config: &ScriptSpec) -> Option<OutputFile> {
+    let script_id = get_script_id(config);
+    let (objects_dir, script_file) = match find_file_cabinet(script) {
+        Some((file_cabinet, script_file)) => (file_cabinet.with_file_name("Objects"), script_file),
+        None => (
+            PathBuf::from(OBJECTS_DIR),
+            format!("/SuiteScripts/{}", get_js_file_name(script)),
+        ),
+    };
+    let path = objects_dir.join(format!("{}.xml", script_id));
+    if path.exists() {
+        return None;
+    }
+
+    Some(OutputFile {
+        path,
+        contents: get_object(config, &script_id, &script_file),
+    })
+}
+
+/// Gets the ID of the script record of a script, given with `--script-id` or derived from the file
+/// name.
+///
+/// A derived ID is `customscript_` followed by the file name in lowercase, with characters other
+/// than letters, digits, and `_` replaced with `_`, and cut to the maximum length of an ID.
+pub fn get_script_id(config: &ScriptSpec) -> String {
+    if let Some(script_id) = &config.script_id {
+        return script_id.clone();
+    }
+
+    let stem: String = config
+        .file_name
+        .as_deref()
+        .and_then(Path::file_stem)
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' | '_' => c,
+            _ => '_',
+        })
+        .take(MAX_ID_LENGTH - SCRIPT_ID_PREFIX.len())
+        .collect();
+    format!("{}{}", SCRIPT_ID_PREFIX, stem.trim_end_matches('_'))
+}
+
+/// Gets the ID of the deployment of a script to a record type, the name of its script record
+/// followed by the record type, such as `customdeploy_customer_ue_salesorder`.
+pub fn get_deployment_id(script_id: &str, record: &str) -> String {
+    let name = script_id
+        .strip_prefix(SCRIPT_ID_PREFIX)
+        .unwrap_or(script_id);
+    format!("customdeploy_{}_{}", name, record.to_lowercase())
+}
+
+/// Gets the name of the file deployed for a script, with the `.js` extension a `TypeScript` script
+/// is transpiled to.
+fn get_js_file_name(script: &Path) -> String {
+    format!(
+        "{}.js",
+        script.file_stem().unwrap_or_default().to_string_lossy()
+    )
+}
+
+/// Finds the `FileCabinet` directory containing a script.
+///
+/// Returns the directory and the path of the script in the File Cabinet, such as
+/// `/SuiteScripts/customer_ue.js`, or `None` if the script is not in a `FileCabinet` directory.
+/// The directory is relative to the current directory if the script is and the directory is in
+/// the current directory.
+fn find_file_cabinet(script: &Path) -> Option<(PathBuf, String)> {
+    let current_dir = std::env::current_dir().ok()?;
+    let absolute = if script.is_absolute() {
+        script.to_path_buf()
+    } else {
+        current_dir.join(script)
+    };
+    let file_cabinet = absolute
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.file_name().is_some_and(|name| name == "FileCabinet"))?;
+    let relative = absolute.strip_prefix(file_cabinet).ok()?;
+    let mut segments: Vec<String> = relative
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(segment) => Some(segment.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+    segments.push(get_js_file_name(script));
+    let dir = Some(file_cabinet)
+        .filter(|_| script.is_relative())
+        .and_then(|dir| dir.strip_prefix(&current_dir).ok())
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(file_cabinet);
+    Some((dir.to_path_buf(), format!("/{}", segments.join("/"))))
+}
+
+/// Writes the XML of the script record of a script, with its ID, name, file, and description, and
+/// a deployment for each record type it is deployed to.
+fn get_object(config: &ScriptSpec, script_id: &str, script_file: &str) -> String {
+    let record_type = get_record_type(&config.script_type).unwrap_or_default();
+    let name = config
+        .file_name
+        .as_deref()
+        .and_then(Path::file_stem)
+        .unwrap_or_default()
+        .to_string_lossy();
+    let mut fields = vec![
+        format!("  <name>{}</name>", escape_xml(&name)),
+        format!("  <scriptfile>[{}]</scriptfile>", escape_xml(script_file)),
+    ];
+    if let Some(description) = &config.description {
+        fields.push(format!(
+            "  <description>{}</description>",
+            escape_xml(description)
+        ));
+    }
+    fields.push(String::from("  <isinactive>F</isinactive>"));
+    fields.push(String::from("  <notifyadmins>F</notifyadmins>"));
+    fields.push(String::from("  <notifyemails></notifyemails>"));
+    fields.push(String::from("  <notifyowner>T</notifyowner>"));
+    if let Some(portlet_type) = get_portlet_type(config) {
+        fields.push(format!(
+            "  <portlettype>{}</portlettype>",
+            portlet_type.to_uppercase()
+        ));
+    }
+    if !config.deployments.is_empty() {
+        let deployments: Vec<String> = config
+            .deployments
+            .iter()
+            .map(|record| get_deployment(config, script_id, record))
+            .collect();
+        fields.push(format!(
+            "  <scriptdeployments>\n{}\n  </scriptdeployments>",
+            deployments.join("\n")
+        ));
+    }
+
+    format!(
+        "<{} scriptid=\"{}\">\n{}\n</{}>\n",
+        record_type,
+        script_id,
+        fields.join("\n"),
+        record_type
+    )
+}
+
+/// Writes the XML of the deployment of a script to a record type.
+///
+/// The deployment is named after the script and the record type, such as
+/// `customdeploy_customer_ue_salesorder`, and is available to every role and department unless
+/// an audience is given.
+fn get_deployment(config: &ScriptSpec, script_id: &str, record: &str) -> String {
+    let mut fields = Vec::new();
+    if config.audience.is_empty() {
+        fields.push(String::from("<allroles>T</allroles>"));
+    } else {
+        let roles: Vec<String> = config
+            .audience
+            .iter()
+            .map(|role| format_reference(role, "customrole"))
+            .collect();
+        fields.push(String::from("<allroles>F</allroles>"));
+        fields.push(format!("<audslctrole>{}</audslctrole>", roles.join("|")));
+    }
+    if !config.departments.is_empty() {
+        fields.push(format!(
+            "<auddepartment>{}</auddepartment>",
+            config.departments.join("|")
+        ));
+    }
+    fields.push(String::from("<isdeployed>T</isdeployed>"));
+    fields.push(format!(
+        "<loglevel>{}</loglevel>",
+        get_or_default(&config.log_level, "debug")
+    ));
+    fields.push(format!(
+        "<recordtype>{}</recordtype>",
+        format_reference(record, "customrecord")
+    ));
+    fields.push(format!(
+        "<status>{}</status>",
+        get_or_default(&config.deploy_status, "testing")
+    ));
+
+    let fields: String = fields
+        .iter()
+        .map(|field| format!("      {}\n", field))
+        .collect();
+    format!(
+        "    <scriptdeployment scriptid=\"{}\">\n{}    </scriptdeployment>",
+        get_deployment_id(script_id, record),
+        fields
+    )
+}
+
+/// Formats a record type or a role, as a reference such as `[customrecord_invoice]` if it is a
+/// custom object with the given prefix, and as an uppercase ID such as `SALESORDER` otherwise.
+fn format_reference(id: &str, custom_prefix: &str) -> String {
+    let lower_case = id.to_lowercase();
+    if lower_case.starts_with(custom_prefix) {
+        return format!("[{}]", lower_case);
+    }
+    id.to_uppercase()
+}
+
+/// Gets an option in uppercase, or a default if it is empty.
+fn get_or_default(value: &str, default: &str) -> String {
+    if value.is_empty() {
+        return default.to_uppercase();
+    }
+    value.to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_script_id() {
+        let script_id = |file_name: &str, script_id: Option<&str>| {
+            get_script_id(&ScriptSpec {
+                file_name: Some(PathBuf::from(file_name)),
+                script_id: script_id.map(String::from),
+                ..ScriptSpec::default()
+            })
+        };
+        assert_eq!(
+            script_id("src/Customer-UE.js", None),
+            "customscript_customer_ue"
+        );
+        assert_eq!(
+            script_id("customer_credit_limit_approval_ue.js", None),
+            "customscript_customer_credit_limit_appro"
+        );
+        assert_eq!(
+            script_id("customer_ue.js", Some("customscript_credit_ue")),
+            "customscript_credit_ue"
+        );
+        assert_eq!(
+            get_deployment_id("customscript_credit_ue", "SalesOrder"),
+            "customdeploy_credit_ue_salesorder"
+        );
+    }
+
+    #[test]
+    fn test_find_file_cabinet() {
+        assert_eq!(
+            find_file_cabinet(Path::new(
+                "/project/src/FileCabinet/SuiteScripts/orders/orders_rl.ts"
+            )),
+            Some((
+                PathBuf::from("/project/src/FileCabinet"),
+                String::from("/SuiteScripts/orders/orders_rl.js")
+            ))
+        );
+        assert_eq!(find_file_cabinet(Path::new("/project/orders_rl.js")), None);
+    }
+
+    #[test]
+    fn test_get_object() {
+        let config = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_ue.js")),
+            script_type: String::from("userevent"),
+            description: Some(String::from("Sets the defaults of customers")),
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            get_object(
+                &config,
+                "customscript_customer_ue",
+                "/SuiteScripts/customer_ue.js"
+            ),
+            "<usereventscript scriptid=\"customscript_customer_ue\">\n  <name>customer_ue</name>\n  <scriptfile>[/SuiteScripts/customer_ue.js]</scriptfile>\n  <description>Sets the defaults of customers</description>\n  <isinactive>F</isinactive>\n  <notifyadmins>F</notifyadmins>\n  <notifyemails></notifyemails>\n  <notifyowner>T</notifyowner>\n</usereventscript>\n"
+        );
+    }
+
+    #[test]
+    fn test_get_deployment() {
+        let config = ScriptSpec {
+            deployments: vec![
+                String::from("salesorder"),
+                String::from("customrecord_order"),
+            ],
+            deploy_status: String::from("released"),
+            audience: vec![
+                String::from("administrator"),
+                String::from("customrole_sales"),
+            ],
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            get_deployment(&config, "customscript_order_ue", "customrecord_order"),
+            "    <scriptdeployment scriptid=\"customdeploy_order_ue_customrecord_order\">\n      <allroles>F</allroles>\n      <audslctrole>ADMINISTRATOR|[customrole_sales]</audslctrole>\n      <isdeployed>T</isdeployed>\n      <loglevel>DEBUG</loglevel>\n      <recordtype>[customrecord_order]</recordtype>\n      <status>RELEASED</status>\n    </scriptdeployment>"
+        );
+
+        let config = ScriptSpec {
+            departments: vec![String::from("3"), String::from("7")],
+            ..ScriptSpec::default()
+        };
+        assert_eq!(
+            get_deployment(&config, "customscript_order_ue", "salesorder"),
+            "    <scriptdeployment scriptid=\"customdeploy_order_ue_salesorder\">\n      <allroles>T</allroles>\n      <auddepartment>3|7</auddepartment>\n      <isdeployed>T</isdeployed>\n      <loglevel>DEBUG</loglevel>\n      <recordtype>SALESORDER</recordtype>\n      <status>TESTING</status>\n    </scriptdeployment>"
+        );
+    }
+}
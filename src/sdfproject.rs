@@ -0,0 +1,255 @@
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::{escape_json, write_output_file, Notice, OutputFile, ScriptSpec, SuiteScriptError};
+
+/// Options for setting up a `SuiteCloud` account customization project.
+#[derive(StructOpt, Debug)]
+pub struct InitOpt {
+    /// Directory of the project, the current directory if omitted
+    #[structopt(parse(from_os_str), default_value = ".")]
+    dir: PathBuf,
+
+    /// Name of the project in its manifest, the name of the directory if omitted
+    #[structopt(long)]
+    name: Option<String>,
+
+    /// ID of the account authentication used by the `suitecloud` CLI, written in `project.json`
+    #[structopt(long = "auth-id")]
+    auth_id: Option<String>,
+}
+
+/// Directory of the scripts of a project, relative to the project.
+pub const SCRIPTS_DIR: &str = "src/FileCabinet/SuiteScripts";
+
+/// Directory of the custom objects of a project, such as script records, relative to the project.
+pub const OBJECTS_DIR: &str = "src/Objects";
+
+/// Writes the directories, manifest, deploy file, and configs of an account customization project.
+///
+/// Files that already exist are left unchanged, so that the command can be run in an existing
+/// project to add the missing files.
+///
+/// # Errors
+/// Returns an IO error if a directory or a file cannot be written
+pub fn run(opt: &InitOpt) -> Result<(), SuiteScriptError> {
+    for dir in &[SCRIPTS_DIR, OBJECTS_DIR] {
+        let path = opt.dir.join(dir);
+        std::fs::create_dir_all(&path).map_err(|source| {
+            SuiteScriptError::io(
+                format!("Failed to create directory {}", path.display()),
+                source,
+            )
+        })?;
+    }
+
+    for file in get_files(opt) {
+        if file.path.exists() {
+            println!("Skipped {}, which already exists", file.path.display());
+            continue;
+        }
+        write_output_file(&file)?;
+        println!("Created {}", file.path.display());
+    }
+    Ok(())
+}
+
+/// Gets the files of an account customization project.
+fn get_files(opt: &InitOpt) -> Vec<OutputFile> {
+    let name = opt
+        .name
+        .clone()
+        .unwrap_or_else(|| get_project_name(&opt.dir));
+    vec![
+        OutputFile {
+            path: opt.dir.join("src/manifest.xml"),
+            contents: get_manifest(&name),
+        },
+        OutputFile {
+            path: opt.dir.join("src/deploy.xml"),
+            contents: String::from(DEPLOY),
+        },
+        OutputFile {
+            path: opt.dir.join("project.json"),
+            contents: get_project(opt.auth_id.as_deref()),
+        },
+        OutputFile {
+            path: opt.dir.join("suitecloud.config.js"),
+            contents: String::from(SUITECLOUD_CONFIG),
+        },
+    ]
+}
+
+/// Deploy file of a project, deploying its account configuration, scripts, objects, and
+/// translations.
+const DEPLOY: &str = "<deploy>
+  <configuration>
+    <path>~/AccountConfiguration/*</path>
+  </configuration>
+  <files>
+    <path>~/FileCabinet/SuiteScripts/*</path>
+  </files>
+  <objects>
+    <path>~/Objects/*</path>
+  </objects>
+  <translationimports>
+    <path>~/Translations/*</path>
+  </translationimports>
+</deploy>
+";
+
+/// Config of the `suitecloud` CLI, which finds the project in `src`.
+const SUITECLOUD_CONFIG: &str = "module.exports = {
+  defaultProjectFolder: 'src',
+  commands: {},
+};
+";
+
+/// Gets the manifest of an account customization project.
+fn get_manifest(name: &str) -> String {
+    format!(
+        "<manifest projecttype=\"ACCOUNTCUSTOMIZATION\">
+  <projectname>{}</projectname>
+  <frameworkversion>1.0</frameworkversion>
+</manifest>
+",
+        escape_xml(name)
+    )
+}
+
+/// Gets the `project.json` of a project, which holds the authentication used to deploy it.
+fn get_project(auth_id: Option<&str>) -> String {
+    match auth_id {
+        Some(auth_id) => format!(
+            "{{\n  \"defaultAuthId\": \"{}\"\n}}\n",
+            escape_json(auth_id)
+        ),
+        None => String::from("{}\n"),
+    }
+}
+
+/// Gets the name of a project from the name of its directory.
+fn get_project_name(dir: &Path) -> String {
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    dir.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| String::from("SuiteScripts"))
+}
+
+/// Finds the `FileCabinet` directory of the `SuiteCloud` project containing a directory.
+///
+/// A project is a directory with a `project.json` or a `src/manifest.xml`, whose files are in
+/// `src/FileCabinet`, or with a `manifest.xml` of a project without a `src` directory, whose files
+/// are in `FileCabinet`.
+pub fn find_file_cabinet(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors().find_map(|project| {
+        if project.join("project.json").is_file() || project.join("src/manifest.xml").is_file() {
+            return Some(project.join("src/FileCabinet"));
+        }
+        if project.join("manifest.xml").is_file() {
+            return Some(project.join("FileCabinet"));
+        }
+        None
+    })
+}
+
+/// Places a script in the `SuiteScripts` directory of the `SuiteCloud` project containing the
+/// current directory.
+///
+/// A bare file name, such as `customer_ue.js`, is resolved into `SuiteScripts` unless the current
+/// directory is already in the File Cabinet, so that `./customer_ue.js` creates the script in the
+/// current directory. Other scripts are left where they are, with a warning if they are outside
+/// the File Cabinet. Returns a notice for each change made or problem found.
+pub fn resolve_file_name(spec: &mut ScriptSpec, current_dir: &Path) -> Vec<Notice> {
+    let (file_name, file_cabinet) = match (&spec.file_name, find_file_cabinet(current_dir)) {
+        (Some(file_name), Some(file_cabinet)) => (file_name.clone(), file_cabinet),
+        _ => return Vec::new(),
+    };
+
+    let bare = file_name
+        .parent()
+        .is_some_and(|parent| parent.as_os_str().is_empty());
+    if bare && !current_dir.starts_with(&file_cabinet) {
+        let path = file_cabinet.join("SuiteScripts").join(&file_name);
+        let path = path
+            .strip_prefix(current_dir)
+            .map_or_else(|_| path.clone(), Path::to_path_buf);
+        let notice = Notice::Note(format!(
+            "placed {} in the File Cabinet of the SuiteCloud project, as {}",
+            file_name.display(),
+            path.display()
+        ));
+        spec.file_name = Some(path);
+        return vec![notice];
+    }
+
+    if !current_dir.join(&file_name).starts_with(&file_cabinet) {
+        return vec![Notice::Warning(format!(
+            "{} is outside the File Cabinet of the SuiteCloud project",
+            file_name.display()
+        ))];
+    }
+    Vec::new()
+}
+
+/// Escapes the characters of a string that are special in XML text and attributes.
+pub fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_manifest() {
+        assert_eq!(
+            get_manifest("Sales & Orders"),
+            "<manifest projecttype=\"ACCOUNTCUSTOMIZATION\">\n  <projectname>Sales &amp; Orders</projectname>\n  <frameworkversion>1.0</frameworkversion>\n</manifest>\n"
+        );
+    }
+
+    #[test]
+    fn test_resolve_file_name() {
+        let dir = std::env::temp_dir().join(format!("suitescript-sdf-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src/FileCabinet/SuiteScripts")).unwrap();
+        std::fs::write(dir.join("src/manifest.xml"), "").unwrap();
+        let resolve = |file_name: &str, current_dir: &Path| {
+            let mut spec = ScriptSpec {
+                file_name: Some(PathBuf::from(file_name)),
+                ..ScriptSpec::default()
+            };
+            let notices = resolve_file_name(&mut spec, current_dir);
+            (spec.file_name.unwrap(), notices.len())
+        };
+
+        let scripts = dir.join("src/FileCabinet/SuiteScripts");
+        let placed = resolve("customer_ue.js", &dir);
+        let kept = resolve("customer_ue.js", &scripts);
+        let outside = resolve("tools/build.js", &dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            placed,
+            (
+                PathBuf::from("src/FileCabinet/SuiteScripts/customer_ue.js"),
+                1
+            )
+        );
+        assert_eq!(kept, (PathBuf::from("customer_ue.js"), 0));
+        assert_eq!(outside, (PathBuf::from("tools/build.js"), 1));
+    }
+
+    #[test]
+    fn test_get_project() {
+        assert_eq!(get_project(None), "{}\n");
+        assert_eq!(
+            get_project(Some("sandbox")),
+            "{\n  \"defaultAuthId\": \"sandbox\"\n}\n"
+        );
+    }
+}
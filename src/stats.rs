@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+use super::audit::{escape_csv, find_scripts, get_tag};
+use super::formatter::get_dependencies;
+use super::{escape_json, get_api_version, read_to_string, SuiteScriptError};
+
+/// Label of the scripts without a value, such as libraries without an `@NScriptType` tag.
+const NONE: &str = "(none)";
+
+/// Options for summarizing the scripts of a project.
+#[derive(StructOpt, Debug)]
+pub struct StatsOpt {
+    /// Directory containing the scripts to summarize
+    #[structopt(parse(from_os_str))]
+    dir: PathBuf,
+
+    /// Format of the report
+    #[structopt(long = "format", default_value = "table", possible_values = &["table", "json", "csv"])]
+    format: String,
+}
+
+/// Names and the number of scripts with each.
+type Counts = Vec<(String, usize)>;
+
+/// Numbers of scripts of a project by script type, API version, and module.
+///
+/// Each count is a name and the number of scripts with it, sorted by decreasing number and then
+/// by name.
+#[derive(Debug, PartialEq)]
+struct Stats {
+    scripts: usize,
+    script_types: Counts,
+    api_versions: Counts,
+    modules: Counts,
+}
+
+/// Prints the numbers of scripts in a directory by script type, API version, and module.
+///
+/// # Errors
+/// Returns an error if the directory or a script cannot be read
+pub fn run(opt: &StatsOpt) -> Result<(), SuiteScriptError> {
+    let mut contents = Vec::new();
+    for script in find_scripts(&opt.dir)? {
+        contents.push(read_to_string(&script)?);
+    }
+    let stats = get_stats(&contents);
+
+    match opt.format.as_str() {
+        "json" => print!("{}", format_json(&stats)),
+        "csv" => print!("{}", format_csv(&stats)),
+        _ => print!("{}", format_table(&stats)),
+    }
+    Ok(())
+}
+
+/// Counts the script types, API versions, and modules of the contents of scripts.
+///
+/// A module is counted once per script importing it.
+fn get_stats(scripts: &[String]) -> Stats {
+    let mut script_types = BTreeMap::new();
+    let mut api_versions = BTreeMap::new();
+    let mut modules = BTreeMap::new();
+    for contents in scripts {
+        let script_type = get_tag(contents, "@NScriptType").unwrap_or(NONE);
+        *script_types.entry(script_type.to_owned()).or_insert(0) += 1;
+        let api_version =
+            get_tag(contents, "@NApiVersion").map_or_else(|| NONE.to_owned(), get_api_version);
+        *api_versions.entry(api_version).or_insert(0) += 1;
+
+        let mut dependencies = get_dependencies(contents).unwrap_or_default();
+        dependencies.sort();
+        dependencies.dedup();
+        for module in dependencies
+            .into_iter()
+            .filter(|dependency| dependency.starts_with("N/"))
+        {
+            *modules.entry(module).or_insert(0) += 1;
+        }
+    }
+
+    Stats {
+        scripts: scripts.len(),
+        script_types: sort_counts(script_types),
+        api_versions: sort_counts(api_versions),
+        modules: sort_counts(modules),
+    }
+}
+
+/// Sorts counts by decreasing number, and then by name.
+fn sort_counts(counts: BTreeMap<String, usize>) -> Counts {
+    let mut counts: Counts = counts.into_iter().collect();
+    counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+    counts
+}
+
+/// Gets the counts of a report with the name of their column and their key in JSON and CSV.
+fn get_sections(stats: &Stats) -> [(&str, &str, &Counts); 3] {
+    [
+        ("Script type", "scripttype", &stats.script_types),
+        ("API version", "apiversion", &stats.api_versions),
+        ("Module", "module", &stats.modules),
+    ]
+}
+
+/// Formats the stats as one table per count, after the number of scripts.
+fn format_table(stats: &Stats) -> String {
+    let mut table = format!("{} scripts\n", stats.scripts);
+    for (title, _, counts) in get_sections(stats) {
+        if counts.is_empty() {
+            continue;
+        }
+        let width = counts
+            .iter()
+            .map(|(name, _)| name.len())
+            .chain(std::iter::once(title.len()))
+            .max()
+            .unwrap_or_default();
+        table.push_str(&format!("\n{:width$}  Scripts\n", title, width = width));
+        for (name, count) in counts {
+            table.push_str(&format!("{:width$}  {}\n", name, count, width = width));
+        }
+    }
+    table
+}
+
+/// Formats the stats as a JSON object with the number of scripts and an object per count.
+fn format_json(stats: &Stats) -> String {
+    let mut fields = vec![format!("  \"scripts\": {}", stats.scripts)];
+    for (_, key, counts) in get_sections(stats) {
+        let entries: Vec<String> = counts
+            .iter()
+            .map(|(name, count)| format!("    \"{}\": {}", escape_json(name), count))
+            .collect();
+        if entries.is_empty() {
+            fields.push(format!("  \"{}s\": {{}}", key));
+        } else {
+            fields.push(format!("  \"{}s\": {{\n{}\n  }}", key, entries.join(",\n")));
+        }
+    }
+    format!("{{\n{}\n}}\n", fields.join(",\n"))
+}
+
+/// Formats the stats as CSV with a header row and a row per name of each count.
+fn format_csv(stats: &Stats) -> String {
+    let mut csv = String::from("category,name,scripts\n");
+    for (_, key, counts) in get_sections(stats) {
+        for (name, count) in counts {
+            csv.push_str(&format!("{},{},{}\n", key, escape_csv(name), count));
+        }
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> Stats {
+        get_stats(&[
+            String::from("/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.1\n */\ndefine(['N/record', 'N/search'], (record, search) => {});"),
+            String::from("/**\n * @NScriptType UserEventScript\n * @NApiVersion 2.x\n */\ndefine(['N/record', './lib'], (record, lib) => {});"),
+            String::from("/**\n * @NApiVersion 2.1\n */\ndefine([], () => {});"),
+        ])
+    }
+
+    #[test]
+    fn test_get_stats() {
+        assert_eq!(
+            stats(),
+            Stats {
+                scripts: 3,
+                script_types: vec![
+                    (String::from("UserEventScript"), 2),
+                    (String::from(NONE), 1)
+                ],
+                api_versions: vec![(String::from("2.1"), 2), (String::from("2.x"), 1)],
+                modules: vec![(String::from("N/record"), 2), (String::from("N/search"), 1)],
+            }
+        );
+    }
+
+    #[test]
+    fn test_format_table() {
+        assert_eq!(
+            format_table(&stats()),
+            "3 scripts\n\nScript type      Scripts\nUserEventScript  2\n(none)           1\n\nAPI version  Scripts\n2.1          2\n2.x          1\n\nModule    Scripts\nN/record  2\nN/search  1\n"
+        );
+    }
+
+    #[test]
+    fn test_format_json() {
+        assert_eq!(
+            format_json(&stats()),
+            "{\n  \"scripts\": 3,\n  \"scripttypes\": {\n    \"UserEventScript\": 2,\n    \"(none)\": 1\n  },\n  \"apiversions\": {\n    \"2.1\": 2,\n    \"2.x\": 1\n  },\n  \"modules\": {\n    \"N/record\": 2,\n    \"N/search\": 1\n  }\n}\n"
+        );
+        assert_eq!(
+            format_json(&get_stats(&[])),
+            "{\n  \"scripts\": 0,\n  \"scripttypes\": {},\n  \"apiversions\": {},\n  \"modules\": {}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_csv() {
+        assert_eq!(
+            format_csv(&stats()),
+            "category,name,scripts\nscripttype,UserEventScript,2\nscripttype,(none),1\napiversion,2.1,2\napiversion,2.x,1\nmodule,N/record,2\nmodule,N/search,1\n"
+        );
+    }
+}
@@ -0,0 +1,590 @@
+use handlebars::Template;
+use regex::Regex;
+use std::io;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::config::{load_spec, user_dir};
+use super::{
+    get_template_data, map_script_to_name, prepare, read_to_string, render_handlebars, typescript,
+    ScriptSpec, SuiteScriptError, DEFAULT_TEMPLATE, TEMPLATE_VARIABLES,
+};
+
+/// Extensions of template files, in the order they are preferred for a script type.
+const EXTENSIONS: [&str; 2] = ["hbs", "tpl"];
+
+/// Helpers of Handlebars, whose names are not variables of a template.
+const HELPERS: [&str; 16] = [
+    "if", "unless", "each", "with", "lookup", "log", "eq", "ne", "gt", "gte", "lt", "lte", "and",
+    "or", "not", "len",
+];
+
+/// Options for discovering the templates of scripts.
+#[derive(StructOpt, Debug)]
+pub struct TemplatesOpt {
+    #[structopt(subcommand)]
+    command: TemplatesCommand,
+}
+
+#[derive(StructOpt, Debug)]
+enum TemplatesCommand {
+    /// Lists the built-in templates and the templates of the templates directory, with the script
+    /// types they apply to and the variables they use
+    List {
+        /// Directory of templates, the `templates-dir` of the config or
+        /// `~/.config/suitescript/templates` if omitted
+        #[structopt(
+            long = "templates-dir",
+            parse(from_os_str),
+            env = "SUITESCRIPT_TEMPLATES_DIR"
+        )]
+        templates_dir: Option<PathBuf>,
+    },
+    /// Renders a template with the variables of a sample script to stdout, without writing files
+    Render {
+        /// Built-in template, such as `default`, template of the templates directory, such as
+        /// `userevent`, or path of a template file
+        name: String,
+
+        /// Path to a JSON object of variables replacing those of the sample script
+        #[structopt(long = "vars", parse(from_os_str))]
+        vars: Option<PathBuf>,
+
+        /// Directory of templates, the `templates-dir` of the config or
+        /// `~/.config/suitescript/templates` if omitted
+        #[structopt(
+            long = "templates-dir",
+            parse(from_os_str),
+            env = "SUITESCRIPT_TEMPLATES_DIR"
+        )]
+        templates_dir: Option<PathBuf>,
+    },
+}
+
+/// A template that scripts can be generated from.
+#[derive(Debug, PartialEq)]
+struct TemplateEntry {
+    /// Name of a built-in template, or path of a template file
+    name: String,
+    /// Description of the scripts the template applies to
+    applies_to: String,
+    /// Variables used by the template, in the order they first appear
+    variables: Vec<String>,
+}
+
+/// Runs a `templates` subcommand.
+///
+/// # Errors
+/// Returns an error if the config, the templates directory, or a template cannot be read
+pub fn run(opt: &TemplatesOpt) -> Result<(), SuiteScriptError> {
+    match &opt.command {
+        TemplatesCommand::List { templates_dir } => {
+            let mut entries = get_builtin_templates();
+            if let Some(dir) = get_templates_dir(templates_dir)?.filter(|dir| dir.is_dir()) {
+                entries.extend(get_installed_templates(&dir)?);
+            }
+            print!("{}", format_table(&entries));
+        }
+        TemplatesCommand::Render {
+            name,
+            vars,
+            templates_dir,
+        } => {
+            let (path, template) = find_template(name, get_templates_dir(templates_dir)?)?;
+            let mut data = get_sample_data(&path)?;
+            if let Some(vars) = vars {
+                let contents = read_to_string(vars)?;
+                let vars: serde_json::Map<String, serde_json::Value> =
+                    serde_json::from_str(&contents).map_err(|error| {
+                        SuiteScriptError::Validation(format!(
+                            "Invalid variables in {}, expected a JSON object: {}",
+                            vars.display(),
+                            error
+                        ))
+                    })?;
+                data.extend(vars);
+            }
+            print!(
+                "{}",
+                render_handlebars(&template, &serde_json::Value::Object(data), &path)?
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Gets the templates directory given, or the `templates-dir` of the config, or the templates
+/// directory of the user config.
+///
+/// # Errors
+/// Returns an error if a config cannot be read or is invalid
+fn get_templates_dir(dir: &Option<PathBuf>) -> Result<Option<PathBuf>, SuiteScriptError> {
+    match dir {
+        Some(dir) => Ok(Some(dir.clone())),
+        None => Ok(load_spec(Path::new("."))?
+            .templates_dir
+            .or_else(|| user_dir().map(|dir| dir.join("templates")))),
+    }
+}
+
+/// Finds a template by name, returning its path and contents.
+///
+/// The name is a built-in template, a template of the templates directory without its extension,
+/// such as `userevent` or `userevent.ts`, or the path of a template file.
+///
+/// # Errors
+/// Returns a template error if the template file cannot be read, and a validation error if there
+/// is no such template
+fn find_template(name: &str, dir: Option<PathBuf>) -> Result<(PathBuf, String), SuiteScriptError> {
+    match name {
+        super::TEMPLATE => return Ok((PathBuf::from(name), String::from(DEFAULT_TEMPLATE))),
+        "typescript" => return Ok((PathBuf::from(name), String::from(typescript::TEMPLATE))),
+        _ => {}
+    }
+
+    let installed = dir.and_then(|dir| {
+        EXTENSIONS
+            .iter()
+            .map(|extension| dir.join(format!("{}.{}", name, extension)))
+            .find(|path| path.is_file())
+    });
+    let path = match installed {
+        Some(path) => path,
+        None if Path::new(name).is_file() => PathBuf::from(name),
+        None => {
+            return Err(SuiteScriptError::Validation(format!(
+                "No template {}, see `suitescript templates list` for the templates",
+                name
+            )))
+        }
+    };
+    let template = std::fs::read_to_string(&path)
+        .map(|template| template.replace("\r\n", "\n"))
+        .map_err(|source| SuiteScriptError::Template {
+            path: path.clone(),
+            source,
+        })?;
+    check_template(&path, &template)?;
+    Ok((path, template))
+}
+
+/// Gets the variables of a sample script rendered with a template.
+///
+/// The sample is a script of the type the template is named after, or a user event script, that
+/// imports `N/record`, written in `TypeScript` for the `TypeScript` templates.
+///
+/// # Errors
+/// Returns an error if the options of the sample script cannot be resolved
+fn get_sample_data(
+    path: &Path,
+) -> Result<serde_json::Map<String, serde_json::Value>, SuiteScriptError> {
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    let stem = name.split('.').next().unwrap_or_default();
+    let script_type = match map_script_to_name(stem) {
+        "" | "CustomGL" => "userevent",
+        _ => stem,
+    };
+    let typescript = name == "typescript" || name.contains(".ts.");
+    let spec = ScriptSpec {
+        file_name: Some(PathBuf::from(if typescript {
+            "sample.ts"
+        } else {
+            "sample.js"
+        })),
+        script_type: script_type.to_owned(),
+        modules: vec![String::from("record")],
+        reproducible: true,
+        ..ScriptSpec::default()
+    };
+    let (config, _) = prepare(&spec)?;
+    match get_template_data(&config)? {
+        serde_json::Value::Object(data) => Ok(data),
+        _ => unreachable!("The data of templates is an object"),
+    }
+}
+
+/// Gets the built-in templates of JavaScript and `TypeScript` scripts.
+fn get_builtin_templates() -> Vec<TemplateEntry> {
+    vec![
+        TemplateEntry {
+            name: super::TEMPLATE.to_owned(),
+            applies_to: String::from("JavaScript scripts without a template"),
+            variables: get_template_variables(DEFAULT_TEMPLATE),
+        },
+        TemplateEntry {
+            name: String::from("typescript"),
+            applies_to: String::from("TypeScript scripts without a template"),
+            variables: get_template_variables(typescript::TEMPLATE),
+        },
+    ]
+}
+
+/// Gets the template files of a templates directory, sorted by name.
+///
+/// Templates named after a script type, such as `userevent.hbs`, apply to the scripts of that
+/// type, and other templates apply to the scripts given them with `--template`.
+///
+/// # Errors
+/// Returns an IO error if the directory or a template cannot be read
+fn get_installed_templates(dir: &Path) -> Result<Vec<TemplateEntry>, SuiteScriptError> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(|source| {
+        SuiteScriptError::io(format!("Failed to read {}", dir.display()), source)
+    })? {
+        let path = entry
+            .map_err(|source| {
+                SuiteScriptError::io(format!("Failed to read {}", dir.display()), source)
+            })?
+            .path();
+        let is_template = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| EXTENSIONS.contains(&extension));
+        if path.is_file() && is_template {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut entries = Vec::new();
+    for path in paths {
+        entries.push(TemplateEntry {
+            name: path.display().to_string(),
+            applies_to: get_applies_to(&path),
+            variables: get_template_variables(&read_to_string(&path)?),
+        });
+    }
+    Ok(entries)
+}
+
+/// Describes the scripts a template file applies to, from its name.
+fn get_applies_to(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    let (stem, lang) = match stem.strip_suffix(".ts") {
+        Some(stem) => (stem, "TypeScript"),
+        None => (stem, "JavaScript"),
+    };
+    match map_script_to_name(stem) {
+        "" => String::from("Scripts given it with --template"),
+        name if name.to_lowercase() == stem => format!("{} {} scripts", name, lang),
+        _ => String::from("Scripts given it with --template"),
+    }
+}
+
+/// Gets the variables used by a Handlebars template, in the order they first appear.
+///
+/// Variables are the names of the expressions and of the parameters of helpers, such as `header`
+/// in `{{#if header}}`, without the helpers, literals, `this`, `@` data variables such as
+/// `@first`, and the block parameters of `as |name|`. Comments and closing tags are skipped.
+pub fn get_template_variables(template: &str) -> Vec<String> {
+    let mut variables: Vec<String> = Vec::new();
+    for (_, name) in get_variable_uses(template) {
+        if !variables.iter().any(|variable| variable == name) {
+            variables.push(name.to_owned());
+        }
+    }
+    variables
+}
+
+/// Checks a template file for the problems that break the scripts generated from it.
+///
+/// Reports invalid Handlebars syntax, variables that are not rendered for scripts, templates with
+/// neither the `{{header}}` variable nor an `@NApiVersion` tag, which `NetSuite` requires, and
+/// `/*` comment blocks that are not closed. Returns the line and the description of each problem.
+pub fn lint_template(template: &str) -> Vec<(usize, String)> {
+    if let Err(error) = Template::compile(template) {
+        let line = error.pos().map_or(1, |(line, _)| line);
+        return vec![(line, format!("invalid Handlebars: {}", error.reason()))];
+    }
+
+    let mut problems = Vec::new();
+    if !template.contains("@NApiVersion")
+        && get_variable_uses(template)
+            .iter()
+            .all(|(_, name)| *name != "header")
+    {
+        problems.push((
+            1,
+            String::from("no {{header}} variable or @NApiVersion tag"),
+        ));
+    }
+    for (line, name) in get_variable_uses(template) {
+        if !TEMPLATE_VARIABLES.contains(&name) {
+            problems.push((line, format!("unknown variable `{}`", name)));
+        }
+    }
+    if let Some(line) = find_unclosed_comment(template) {
+        problems.push((line, String::from("comment block is not closed with */")));
+    }
+    problems.sort_by_key(|(line, _)| *line);
+    problems
+}
+
+/// Checks a template file with [`lint_template`] before scripts are generated from it.
+///
+/// # Errors
+/// Returns a template error naming the template file, with the line and the description of each
+/// problem
+pub fn check_template(path: &Path, template: &str) -> Result<(), SuiteScriptError> {
+    let problems: Vec<String> = lint_template(template)
+        .iter()
+        .map(|(line, problem)| format!("line {}: {}", line, problem))
+        .collect();
+    if problems.is_empty() {
+        return Ok(());
+    }
+    Err(SuiteScriptError::Template {
+        path: path.to_owned(),
+        source: io::Error::new(io::ErrorKind::InvalidData, problems.join("; ")),
+    })
+}
+
+/// Gets the variables used by the expressions of a template, with the line of each use.
+fn get_variable_uses(template: &str) -> Vec<(usize, &str)> {
+    let mut uses = Vec::new();
+    let mut block_params: Vec<&str> = Vec::new();
+    for (line, expression) in get_expressions(template) {
+        let expression = expression.trim_start_matches(['#', '^', '&']).trim();
+        if expression.starts_with(['!', '/', '>']) {
+            continue;
+        }
+        let (expression, params) = expression.split_once(" as |").unwrap_or((expression, ""));
+        block_params.extend(params.trim_end_matches('|').split_whitespace());
+
+        let mut words: Vec<&str> = expression.split_whitespace().collect();
+        if words.len() > 1 || words.first().is_some_and(|word| HELPERS.contains(word)) {
+            words.remove(0);
+        }
+        for word in words {
+            let value = word.split_once('=').map_or(word, |(_, value)| value);
+            let name = value.split(['.', '/']).next().unwrap_or_default();
+            let is_variable = name
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && !["this", "else", "true", "false", "null"].contains(&name)
+                && !block_params.contains(&name);
+            if is_variable {
+                uses.push((line, name));
+            }
+        }
+    }
+    uses
+}
+
+/// Gets the contents of the `{{...}}` expressions of a template, without their braces and the
+/// `~` markers that strip whitespace, with the line each starts on.
+fn get_expressions(template: &str) -> Vec<(usize, &str)> {
+    get_expression_regex()
+        .captures_iter(template)
+        .filter_map(|captures| Some((captures.get(0)?.start(), captures.get(1)?)))
+        .map(|(start, contents)| (get_line(template, start), contents.as_str().trim()))
+        .collect()
+}
+
+/// Gets the regular expression matching the `{{...}}` expressions of a template.
+fn get_expression_regex() -> Regex {
+    Regex::new(r"(?s)\{\{\{?~?(.*?)~?\}?\}\}").expect("The expression is valid")
+}
+
+/// Gets the line of a byte offset in a text, starting at 1.
+fn get_line(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
+}
+
+/// Finds the line of the first `/*` comment block of a template that is not closed by `*/`.
+///
+/// The expressions of the template are skipped, since their values, such as the header, are
+/// complete comments, and so are line comments.
+fn find_unclosed_comment(template: &str) -> Option<usize> {
+    let text = get_expression_regex().replace_all(template, |captures: &regex::Captures| {
+        captures[0].replace(|c: char| c != '\n', " ")
+    });
+    let mut offset = 0;
+    loop {
+        let rest = &text[offset..];
+        let line_comment = rest.find("//");
+        let block = rest.find("/*")?;
+        match line_comment {
+            Some(start) if start < block => {
+                offset += rest[start..]
+                    .find('\n')
+                    .map_or(rest.len(), |end| start + end);
+            }
+            _ => match rest[block + 2..].find("*/") {
+                Some(end) => offset += block + 2 + end + 2,
+                None => return Some(get_line(&text, offset + block)),
+            },
+        }
+    }
+}
+
+/// Formats the templates as a table with one row per template.
+fn format_table(entries: &[TemplateEntry]) -> String {
+    let width = |get: &dyn Fn(&TemplateEntry) -> usize, title: &str| {
+        entries
+            .iter()
+            .map(get)
+            .chain(std::iter::once(title.len()))
+            .max()
+            .unwrap_or_default()
+    };
+    let name_width = width(&|entry| entry.name.len(), "Template");
+    let applies_width = width(&|entry| entry.applies_to.len(), "Applies to");
+
+    let mut table = format!(
+        "{:name_width$}  {:applies_width$}  Variables\n",
+        "Template",
+        "Applies to",
+        name_width = name_width,
+        applies_width = applies_width
+    );
+    for entry in entries {
+        let row = format!(
+            "{:name_width$}  {:applies_width$}  {}",
+            entry.name,
+            entry.applies_to,
+            entry.variables.join(", "),
+            name_width = name_width,
+            applies_width = applies_width
+        );
+        table.push_str(row.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_template_variables() {
+        assert_eq!(
+            get_template_variables(DEFAULT_TEMPLATE),
+            vec!["tscheck", "header", "provenance", "define", "body"]
+        );
+        assert_eq!(
+            get_template_variables(
+                "{{! Layout }}{{#if header}}{{header}}{{else}}// {{ file }}{{/if}}\n{{~#each modules}}{{#unless @first}}, {{/unless}}{{this}}{{/each~}}\n{{{body}}}"
+            ),
+            vec!["header", "file", "modules", "body"]
+        );
+    }
+
+    #[test]
+    fn test_lint_template() {
+        assert!(lint_template(DEFAULT_TEMPLATE).is_empty());
+        assert!(lint_template(typescript::TEMPLATE).is_empty());
+        assert!(lint_template(
+            "{{header}}// {{#each modules as |module|}}{{module}} {{/each}}\n/* {{file}} */\n{{define}}{{body}}});"
+        )
+        .is_empty());
+        assert_eq!(
+            lint_template("{{header}}\n/**\n * {{company}}\n{{define}}{{body}}});"),
+            vec![
+                (2, String::from("comment block is not closed with */")),
+                (3, String::from("unknown variable `company`")),
+            ]
+        );
+        assert_eq!(
+            lint_template("{{define}}{{body}}});"),
+            vec![(
+                1,
+                String::from("no {{header}} variable or @NApiVersion tag")
+            )]
+        );
+        let problems = lint_template("{{header}}\n{{#if provenance}}{{define}}{{body}}});");
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].1.starts_with("invalid Handlebars: "));
+    }
+
+    #[test]
+    fn test_check_template() {
+        let error = check_template(Path::new("layout.hbs"), "{{header}}{{title}}").unwrap_err();
+        assert_eq!(error.kind(), "template");
+        assert_eq!(
+            error.to_string(),
+            "Failed to load template file layout.hbs: line 1: unknown variable `title`"
+        );
+    }
+
+    #[test]
+    fn test_find_template() {
+        let dir = std::env::temp_dir().join(format!(
+            "suitescript-render-templates-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("userevent.hbs"), "{{header}}// {{scripttype}}\r\n").unwrap();
+
+        let installed = find_template("userevent", Some(dir.clone()));
+        let missing = find_template("mapreduce", Some(dir.clone()));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            installed.unwrap(),
+            (
+                dir.join("userevent.hbs"),
+                String::from("{{header}}// {{scripttype}}\n")
+            )
+        );
+        assert_eq!(
+            missing.unwrap_err().to_string(),
+            "No template mapreduce, see `suitescript templates list` for the templates"
+        );
+        assert_eq!(
+            find_template("default", None).unwrap(),
+            (PathBuf::from("default"), String::from(DEFAULT_TEMPLATE))
+        );
+    }
+
+    #[test]
+    fn test_get_sample_data() {
+        let data = get_sample_data(Path::new("templates/mapreduce.hbs")).unwrap();
+        assert_eq!(data["scripttype"], "MapReduce");
+        assert_eq!(data["file"], "sample.js");
+        assert_eq!(data["modules"], serde_json::json!(["N/record"]));
+        assert!(data["define"]
+            .as_str()
+            .unwrap()
+            .starts_with("define([\n  'N/record',\n"));
+
+        let data = get_sample_data(Path::new("typescript")).unwrap();
+        assert_eq!(data["scripttype"], "UserEvent");
+        assert_eq!(data["file"], "sample.ts");
+    }
+
+    #[test]
+    fn test_get_applies_to() {
+        assert_eq!(
+            get_applies_to(Path::new("templates/userevent.hbs")),
+            "UserEvent JavaScript scripts"
+        );
+        assert_eq!(
+            get_applies_to(Path::new("templates/mapreduce.ts.tpl")),
+            "MapReduce TypeScript scripts"
+        );
+        assert_eq!(
+            get_applies_to(Path::new("templates/ue.hbs")),
+            "Scripts given it with --template"
+        );
+        assert_eq!(
+            get_applies_to(Path::new("templates/layout.hbs")),
+            "Scripts given it with --template"
+        );
+    }
+
+    #[test]
+    fn test_format_table() {
+        assert_eq!(
+            format_table(&get_builtin_templates()),
+            "Template    Applies to                             Variables\ndefault     JavaScript scripts without a template  tscheck, header, provenance, define, body\ntypescript  TypeScript scripts without a template  header, provenance, define, body\n"
+        );
+    }
+}
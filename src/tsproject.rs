@@ -0,0 +1,170 @@
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use super::{
+    get_api_version, validate_api_version, write_output_file, OutputFile, SuiteScriptError,
+};
+
+/// Options for setting up a `TypeScript` project of scripts.
+#[derive(StructOpt, Debug)]
+pub struct InitTsOpt {
+    /// Directory of the project, the current directory if omitted
+    #[structopt(parse(from_os_str), default_value = ".")]
+    dir: PathBuf,
+
+    /// Version of the `SuiteScript` API of the scripts, which sets the ECMAScript version they are
+    /// transpiled to
+    #[structopt(short, long = "apiversion", default_value = "2.1", validator = validate_api_version)]
+    api_version: String,
+
+    /// Directory of the `TypeScript` scripts, relative to the project
+    #[structopt(long = "src", default_value = "src")]
+    src: String,
+
+    /// Directory the transpiled scripts are written to, relative to the project
+    #[structopt(long = "out-dir", default_value = "dist")]
+    out_dir: String,
+}
+
+/// Version range of `TypeScript` installed by the project.
+const TYPESCRIPT_VERSION: &str = "^5.0.0";
+
+/// Version range of the `SuiteScript` type definitions installed by the project.
+const TYPES_VERSION: &str = "^2023.1.0";
+
+/// Writes the `tsconfig.json` and `package.json` of a `TypeScript` project.
+///
+/// Files that already exist are left unchanged, so that the command can be run in an existing
+/// project to add the missing file.
+///
+/// # Errors
+/// Returns an IO error if a file cannot be written
+pub fn run(opt: &InitTsOpt) -> Result<(), SuiteScriptError> {
+    for file in get_files(opt) {
+        if file.path.exists() {
+            println!("Skipped {}, which already exists", file.path.display());
+            continue;
+        }
+        write_output_file(&file)?;
+        println!("Created {}", file.path.display());
+    }
+    Ok(())
+}
+
+/// Gets the files of a `TypeScript` project.
+fn get_files(opt: &InitTsOpt) -> Vec<OutputFile> {
+    vec![
+        OutputFile {
+            path: opt.dir.join("tsconfig.json"),
+            contents: get_tsconfig(opt),
+        },
+        OutputFile {
+            path: opt.dir.join("package.json"),
+            contents: get_package(&get_package_name(&opt.dir)),
+        },
+    ]
+}
+
+/// Gets the `tsconfig.json` of a project.
+///
+/// Scripts are transpiled to AMD modules, as ES5 for `SuiteScript` 2.0 and ES2021 for 2.1, with
+/// their comments kept so that `NetSuite` can read the `JSDoc` tags. The `N` modules resolve to
+/// the type definitions of `@hitc/netsuite-types`.
+fn get_tsconfig(opt: &InitTsOpt) -> String {
+    let (target, lib) = match get_api_version(&opt.api_version).as_str() {
+        "2.0" => ("ES5", "\"ES5\", \"ES2015.Promise\""),
+        _ => ("ES2021", "\"ES2021\""),
+    };
+    format!(
+        "{{
+  \"compilerOptions\": {{
+    \"target\": \"{}\",
+    \"lib\": [{}],
+    \"module\": \"AMD\",
+    \"moduleResolution\": \"node\",
+    \"strict\": true,
+    \"removeComments\": false,
+    \"newLine\": \"lf\",
+    \"rootDir\": \"{}\",
+    \"outDir\": \"{}\",
+    \"baseUrl\": \".\",
+    \"paths\": {{
+      \"N\": [\"node_modules/@hitc/netsuite-types/N\"],
+      \"N/*\": [\"node_modules/@hitc/netsuite-types/N/*\"]
+    }}
+  }},
+  \"include\": [\"{}/**/*.ts\"]
+}}
+",
+        target, lib, opt.src, opt.out_dir, opt.src
+    )
+}
+
+/// Gets the `package.json` of a project, with the type packages and the scripts building it.
+fn get_package(name: &str) -> String {
+    format!(
+        "{{
+  \"name\": \"{}\",
+  \"version\": \"1.0.0\",
+  \"private\": true,
+  \"scripts\": {{
+    \"build\": \"tsc\",
+    \"watch\": \"tsc --watch\"
+  }},
+  \"devDependencies\": {{
+    \"@hitc/netsuite-types\": \"{}\",
+    \"typescript\": \"{}\"
+  }}
+}}
+",
+        name, TYPES_VERSION, TYPESCRIPT_VERSION
+    )
+}
+
+/// Gets the name of the package of a project from the name of its directory.
+///
+/// Package names are lowercase, so the name is lowercased and any character other than a letter,
+/// a digit, `-`, `_`, or `.` is replaced with `-`.
+fn get_package_name(dir: &Path) -> String {
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    let name: String = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '-',
+        })
+        .collect();
+    let name = name.trim_matches(|c| c == '-' || c == '.' || c == '_');
+    if name.is_empty() {
+        return String::from("suitescript-project");
+    }
+    name.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tsconfig_target() {
+        let opt = InitTsOpt::from_iter(&["init-ts", "-a", "2.0"]);
+        let tsconfig = get_tsconfig(&opt);
+        assert!(tsconfig
+            .contains("    \"target\": \"ES5\",\n    \"lib\": [\"ES5\", \"ES2015.Promise\"],\n"));
+        assert!(tsconfig.contains("  \"include\": [\"src/**/*.ts\"]\n"));
+
+        let opt = InitTsOpt::from_iter(&["init-ts", "--out-dir", "FileCabinet/SuiteScripts"]);
+        let tsconfig = get_tsconfig(&opt);
+        assert!(tsconfig.contains("    \"target\": \"ES2021\",\n"));
+        assert!(tsconfig.contains("    \"outDir\": \"FileCabinet/SuiteScripts\",\n"));
+    }
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(get_package_name(Path::new("/tmp/My Project")), "my-project");
+        assert_eq!(get_package_name(Path::new("/")), "suitescript-project");
+    }
+}
@@ -0,0 +1,240 @@
+use super::assets::entry_points::EntryPoint;
+use super::assets::recipes::Recipe;
+use super::{
+    annotate_costs, format_dependency_arg, format_doc, get_module_names, get_module_path,
+    map_script_to_name, wrap_keep_region, ScriptSpec, KEEP_END, KEEP_START,
+};
+
+/// Built-in template of `TypeScript` scripts, which import their modules and export their entry
+/// points instead of wrapping them in a module definition.
+pub const TEMPLATE: &str = "{{header}}{{provenance}}{{define}}{{body}}";
+
+/// Script types whose entry points are typed in the `EntryPoints` namespace of `N/types`.
+const NAMESPACES: [&str; 10] = [
+    "BundleInstallation",
+    "Client",
+    "MapReduce",
+    "MassUpdate",
+    "Portlet",
+    "RESTlet",
+    "Scheduled",
+    "Suitelet",
+    "UserEvent",
+    "WorkflowAction",
+];
+
+/// Script types whose entry points take parameters other than a context, such as the request
+/// parameters of a RESTlet, which are typed by the type of the function instead.
+const FUNCTION_TYPES: [&str; 2] = ["BundleInstallation", "RESTlet"];
+
+/// Checks whether a script is generated as `TypeScript`, with `--lang ts` or a `.ts` file name.
+pub fn is_typescript(config: &ScriptSpec) -> bool {
+    match config.lang.as_str() {
+        "" => config
+            .file_name
+            .as_deref()
+            .and_then(|path| path.extension())
+            .is_some_and(|extension| extension == "ts"),
+        lang => lang == "ts",
+    }
+}
+
+/// Gets the namespace of the types of the entry points of a script, such as `UserEvent`.
+///
+/// Returns `None` for scripts whose entry points have no types, such as libraries and
+/// SuiteCommerce extensions.
+fn get_namespace(config: &ScriptSpec) -> Option<&'static str> {
+    let name = map_script_to_name(&config.script_type);
+    if config.suitecommerce {
+        return None;
+    }
+    NAMESPACES
+        .iter()
+        .find(|namespace| **namespace == name)
+        .copied()
+}
+
+/// Writes the `import` statements of a script, in place of the opening of the module definition.
+///
+/// The `EntryPoints` types are imported first if any entry point is typed, as a type-only import
+/// removed when the script is transpiled, followed by the modules and the local dependencies.
+/// Each is imported as a namespace named like the arguments of a module definition, from the
+/// path of the module in the `@hitc/netsuite-types` definitions, such as `N/ui/serverWidget`.
+pub fn get_imports(config: &ScriptSpec, entry_points: &[&EntryPoint]) -> String {
+    let mut imports = Vec::new();
+    if get_namespace(config).is_some() && !entry_points.is_empty() {
+        imports.push(String::from("import type { EntryPoints } from 'N/types';"));
+    }
+    let modules = get_module_names(&config.modules);
+    for name in modules.iter().filter(|name| !name.is_empty()) {
+        imports.push(format!(
+            "import * as {} from 'N/{}';",
+            name.replace('/', ""),
+            get_module_path(name)
+        ));
+    }
+    for dependency in &config.dependencies {
+        imports.push(format!(
+            "import * as {} from '{}';",
+            format_dependency_arg(dependency),
+            dependency
+        ));
+    }
+
+    if imports.is_empty() {
+        return String::new();
+    }
+    format!("{}\n\n", imports.join("\n"))
+}
+
+/// Writes the body of a `TypeScript` script.
+///
+/// Like the body of a module definition, the body has the snippets of the recipes and the entry
+/// points, each in its keep region, but at the top level of the file, and the entry points are
+/// exported rather than returned.
+pub fn get_body(config: &ScriptSpec, recipes: &[&Recipe], entry_points: &[&EntryPoint]) -> String {
+    if recipes.is_empty() && entry_points.is_empty() {
+        return format!("{}>\n\n{}\n", KEEP_START, KEEP_END);
+    }
+
+    let namespace = get_namespace(config);
+    let mut parts: Vec<String> = recipes
+        .iter()
+        .map(|recipe| wrap_keep_region(recipe.name, &annotate_costs(recipe.snippet), "  "))
+        .collect();
+    parts.extend(
+        entry_points
+            .iter()
+            .map(|entry_point| format_entry_point(entry_point, namespace)),
+    );
+    if !entry_points.is_empty() {
+        let names: Vec<String> = entry_points
+            .iter()
+            .map(|entry_point| match entry_point.function {
+                Some(function) => format!("{} as {}", function, entry_point.name),
+                None => entry_point.name.to_owned(),
+            })
+            .collect();
+        parts.push(format!("  export {{ {} }};", names.join(", ")));
+    }
+
+    dedent(&format!("{}\n", parts.join("\n\n")))
+}
+
+/// Writes an entry point as a function typed with the types of the `EntryPoints` namespace.
+///
+/// The parameter is typed with the context of the entry point, such as
+/// `EntryPoints.UserEvent.beforeSubmitContext`. Entry points that take other parameters are typed
+/// with the type of the function, such as `EntryPoints.RESTlet.get`, which types the parameters.
+fn format_entry_point(entry_point: &EntryPoint, namespace: Option<&str>) -> String {
+    let name = entry_point.function.unwrap_or(entry_point.name);
+    let (function, param) = match namespace {
+        Some(namespace) if FUNCTION_TYPES.contains(&namespace) => {
+            // `delete` is a reserved word, so the type of the entry point is named `delete_`
+            let type_name = match entry_point.name {
+                "delete" => "delete_",
+                type_name => type_name,
+            };
+            (
+                format!("{}: EntryPoints.{}.{}", name, namespace, type_name),
+                entry_point.param.to_owned(),
+            )
+        }
+        Some(namespace) if !entry_point.param.is_empty() => (
+            name.to_owned(),
+            format!(
+                "{}: EntryPoints.{}.{}Context",
+                entry_point.param, namespace, entry_point.name
+            ),
+        ),
+        _ => (name.to_owned(), entry_point.param.to_owned()),
+    };
+    format!(
+        "{}  const {} = ({}) => {{\n{}\n  }};",
+        format_doc(entry_point.doc),
+        function,
+        param,
+        wrap_keep_region(name, &annotate_costs(entry_point.body), "    ")
+    )
+}
+
+/// Removes one level of indentation, two spaces, from each line of a text.
+fn dedent(text: &str) -> String {
+    text.split('\n')
+        .map(|line| line.strip_prefix("  ").unwrap_or(line))
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{prepare, render};
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_is_typescript() {
+        let spec = |file: &str, lang: &str| ScriptSpec {
+            file_name: Some(PathBuf::from(file)),
+            lang: lang.to_owned(),
+            ..ScriptSpec::default()
+        };
+        assert!(is_typescript(&spec("customer_ue.ts", "")));
+        assert!(is_typescript(&spec("customer_ue.ts", "ts")));
+        assert!(!is_typescript(&spec("customer_ue.js", "")));
+    }
+
+    #[test]
+    fn test_render_typescript() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_rl.ts")),
+            modules: vec![String::from("record")],
+            methods: vec![String::from("get"), String::from("delete")],
+            ..ScriptSpec::default()
+        };
+        let (config, _) = prepare(&spec).unwrap();
+        let script = render(&config).unwrap();
+        assert!(script.starts_with(
+            "/**\n * @NScriptType RESTlet\n * @NApiVersion 2.1\n */\n\nimport type { EntryPoints } from 'N/types';\nimport * as record from 'N/record';\n\n/**\n"
+        ));
+        assert!(script.contains(
+            "\nconst doGet: EntryPoints.RESTlet.get = (requestParams) => {\n  // <suitescript:keep doGet>\n"
+        ));
+        assert!(script
+            .contains("\nconst doDelete: EntryPoints.RESTlet.delete_ = (requestParams) => {\n"));
+        assert!(script.ends_with(
+            "\n  // </suitescript:keep>\n};\n\nexport { doGet as get, doDelete as delete };\n"
+        ));
+    }
+
+    #[test]
+    fn test_context_types() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("customer_sl.ts")),
+            modules: vec![String::from("ui/serverwidget"), String::from("record")],
+            ..ScriptSpec::default()
+        };
+        let (config, _) = prepare(&spec).unwrap();
+        let script = render(&config).unwrap();
+        assert!(script.contains(
+            "import type { EntryPoints } from 'N/types';\nimport * as uiserverwidget from 'N/ui/serverWidget';\nimport * as record from 'N/record';\n"
+        ));
+        assert!(script.contains(
+            "\nconst onRequest = (context: EntryPoints.Suitelet.onRequestContext) => {\n"
+        ));
+    }
+
+    #[test]
+    fn test_untyped_entry_points() {
+        let spec = ScriptSpec {
+            file_name: Some(PathBuf::from("utils.ts")),
+            script_type: String::from("library"),
+            ..ScriptSpec::default()
+        };
+        let (config, _) = prepare(&spec).unwrap();
+        assert_eq!(
+            render(&config).unwrap(),
+            "/**\n * @NApiVersion 2.1\n */\n\n/**\n * Example function exported to the scripts importing the library.\n */\nconst example = () => {\n  // <suitescript:keep example>\n  // TODO: Replace with the functions shared by scripts\n  // </suitescript:keep>\n};\n\nexport { example };\n"
+        );
+    }
+}
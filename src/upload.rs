@@ -0,0 +1,293 @@
+use std::io;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+use super::auth::{load_profile, validate_profile};
+use super::config::{find_project_config, user_config_path, Config};
+use super::oauth::{get_authorization, Credentials};
+use super::{escape_json, get_file_name, read_to_string, SuiteScriptError};
+
+/// Options for uploading files to the File Cabinet of an account.
+#[derive(StructOpt, Debug)]
+pub struct UploadOpt {
+    /// Files to upload
+    #[structopt(parse(from_os_str), required_unless = "print-restlet")]
+    files: Vec<PathBuf>,
+
+    /// Environment to upload to, whose account, RESTlet, and folder are set in a config as
+    /// `env.<name>.account`, `env.<name>.restlet`, and `env.<name>.folder`
+    #[structopt(short, long = "env", default_value = "default")]
+    env: String,
+
+    /// Profile stored with `suitescript auth add` whose account and credentials sign the uploads,
+    /// instead of the account of the environment and the credentials in environment variables
+    #[structopt(long = "profile", validator = validate_profile)]
+    profile: Option<String>,
+
+    /// Internal ID of the folder to upload to, instead of the folder of the environment
+    #[structopt(long = "folder")]
+    folder: Option<String>,
+
+    /// Print the RESTlet that receives the uploads, to deploy in the account once
+    #[structopt(long = "print-restlet")]
+    print_restlet: bool,
+
+    /// Show the keys, tokens, and secrets of the credentials in errors instead of redacting them,
+    /// only to debug uploads locally
+    #[structopt(long = "show-secrets")]
+    show_secrets: bool,
+}
+
+/// Environment variables holding the token based authentication credentials of the requests.
+const CREDENTIAL_VARS: [&str; 4] = [
+    "SUITESCRIPT_CONSUMER_KEY",
+    "SUITESCRIPT_CONSUMER_SECRET",
+    "SUITESCRIPT_TOKEN_ID",
+    "SUITESCRIPT_TOKEN_SECRET",
+];
+
+/// RESTlet that saves the files posted to it in the File Cabinet, replacing a file of the same
+/// name in the folder.
+const RESTLET: &str = "/**
+ * @NScriptType Restlet
+ * @NApiVersion 2.1
+ * @description Saves the files uploaded by `suitescript upload` in the File Cabinet
+ */
+
+define([
+  'N/file',
+], (file) => {
+  const TYPES = {
+    js: file.Type.JAVASCRIPT,
+    json: file.Type.JSON,
+    xml: file.Type.XMLDOC,
+  };
+
+  /**
+   * Saves a file in a folder, replacing the file of the same name.
+   * @param {{ name: string, folder: string, contents: string }} requestBody
+   */
+  const post = ({ name, folder, contents }) => {
+    const extension = name.split('.').pop().toLowerCase();
+    const fileType = TYPES[extension] || file.Type.PLAINTEXT;
+    const id = file.create({ name, fileType, contents, folder: Number(folder) }).save();
+    return { id };
+  };
+
+  return { post };
+});
+";
+
+/// Settings of the environment that files are uploaded to.
+#[derive(Debug, PartialEq)]
+struct Environment {
+    /// ID of the account, such as `1234567_SB1`
+    account: String,
+    /// External URL of the deployment of the upload RESTlet
+    restlet: String,
+    /// Internal ID of the folder the files are saved in
+    folder: String,
+}
+
+/// Uploads files to the File Cabinet through the upload RESTlet of an environment, signing the
+/// requests with token based authentication.
+///
+/// The account and the credentials are read from the keyring with `--profile`, and otherwise the
+/// credentials are read from the `SUITESCRIPT_CONSUMER_KEY`, `SUITESCRIPT_CONSUMER_SECRET`,
+/// `SUITESCRIPT_TOKEN_ID`, and `SUITESCRIPT_TOKEN_SECRET` environment variables. The keys, tokens,
+/// and secrets are redacted in the errors of the uploads, such as a response echoing the request,
+/// unless `--show-secrets` is given.
+///
+/// # Errors
+/// Returns a validation error if the environment, the profile, or the credentials are incomplete,
+/// and an IO error if the keyring or a file cannot be read or a file cannot be uploaded
+pub fn run(opt: &UploadOpt) -> Result<(), SuiteScriptError> {
+    if opt.print_restlet {
+        print!("{}", RESTLET);
+        return Ok(());
+    }
+
+    let (account, credentials) = match &opt.profile {
+        Some(profile) => {
+            let profile = load_profile(profile)?;
+            (Some(profile.account), profile.credentials)
+        }
+        None => (None, get_credentials()?),
+    };
+    let environment = load_environment(&opt.env, account, opt.folder.as_deref())?;
+    for path in &opt.files {
+        let name = get_file_name(path);
+        let body = format!(
+            "{{\"name\": \"{}\", \"folder\": \"{}\", \"contents\": \"{}\"}}",
+            escape_json(&name),
+            escape_json(&environment.folder),
+            escape_json(&read_to_string(path)?)
+        );
+        let response = post(&environment, &credentials, &body).map_err(|source| {
+            let source = if opt.show_secrets {
+                source
+            } else {
+                io::Error::new(source.kind(), credentials.redact(&source.to_string()))
+            };
+            SuiteScriptError::io(format!("Failed to upload {}", name), source)
+        })?;
+        match get_file_id(&response) {
+            Some(id) => println!(
+                "Uploaded {} to file {} in folder {}",
+                path.display(),
+                id,
+                environment.folder
+            ),
+            None => println!("Uploaded {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+/// Loads the settings of an environment from the user config and the project config, where the
+/// project config takes precedence, unless the account or the folder is given.
+fn load_environment(
+    name: &str,
+    account: Option<String>,
+    folder: Option<&str>,
+) -> Result<Environment, SuiteScriptError> {
+    let configs = load_configs()?;
+    let get = |setting: &str| get_env_setting(&configs, name, setting);
+    Ok(Environment {
+        account: match account {
+            Some(account) => account,
+            None => get("account")?,
+        },
+        restlet: get("restlet")?,
+        folder: match folder {
+            Some(folder) => folder.to_owned(),
+            None => get("folder")?,
+        },
+    })
+}
+
+/// Loads the user config and the project config of the current directory, in increasing order of
+/// precedence, skipping those that do not exist.
+///
+/// # Errors
+/// Returns an error if the current directory or a config cannot be read
+pub fn load_configs() -> Result<Vec<Config>, SuiteScriptError> {
+    let current_dir = std::env::current_dir()
+        .map_err(|source| SuiteScriptError::io("Failed to read the current directory", source))?;
+    let user_config = user_config_path().filter(|path| path.is_file());
+    user_config
+        .iter()
+        .chain(find_project_config(&current_dir).iter())
+        .map(|path| Config::load(path))
+        .collect()
+}
+
+/// Gets a setting of an environment, such as `account`, from the last config that sets it.
+///
+/// # Errors
+/// Returns a validation error if no config sets it
+pub fn get_env_setting(
+    configs: &[Config],
+    name: &str,
+    setting: &str,
+) -> Result<String, SuiteScriptError> {
+    configs
+        .iter()
+        .rev()
+        .find_map(|config| config.get_env(name, setting))
+        .map(str::to_owned)
+        .ok_or_else(|| {
+            SuiteScriptError::Validation(format!(
+                "Environment {} has no {}, set it with `suitescript config set env.{}.{} <value>`",
+                name, setting, name, setting
+            ))
+        })
+}
+
+/// Reads the token based authentication credentials from their environment variables.
+///
+/// # Errors
+/// Returns a validation error naming the variables that are not set
+pub fn get_credentials() -> Result<Credentials, SuiteScriptError> {
+    let values: Vec<Option<String>> = CREDENTIAL_VARS
+        .iter()
+        .map(|var| std::env::var(var).ok().filter(|value| !value.is_empty()))
+        .collect();
+    let missing: Vec<&str> = CREDENTIAL_VARS
+        .iter()
+        .zip(&values)
+        .filter(|(_, value)| value.is_none())
+        .map(|(var, _)| *var)
+        .collect();
+    if !missing.is_empty() {
+        return Err(SuiteScriptError::Validation(format!(
+            "Missing the credentials in {}, or a profile given with --profile",
+            missing.join(", ")
+        )));
+    }
+
+    let mut values = values.into_iter().flatten();
+    let mut next = || values.next().unwrap_or_default();
+    Ok(Credentials {
+        consumer_key: next(),
+        consumer_secret: next(),
+        token_id: next(),
+        token_secret: next(),
+    })
+}
+
+/// Posts a JSON body to the upload RESTlet of an environment, returning the body of the response.
+fn post(environment: &Environment, credentials: &Credentials, body: &str) -> io::Result<String> {
+    let authorization = get_authorization(
+        "POST",
+        &environment.restlet,
+        &environment.account,
+        credentials,
+    )?;
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .build()
+        .into();
+    let mut response = agent
+        .post(&environment.restlet)
+        .header("Authorization", &authorization)
+        .header("Content-Type", "application/json")
+        .send(body)
+        .map_err(io::Error::other)?;
+    let status = response.status();
+    let response = response
+        .body_mut()
+        .read_to_string()
+        .map_err(io::Error::other)?;
+    if !status.is_success() {
+        return Err(io::Error::other(format!(
+            "the RESTlet responded with HTTP {}: {}",
+            status.as_u16(),
+            response
+        )));
+    }
+    Ok(response)
+}
+
+/// Gets the internal ID of the file saved by the upload RESTlet from its response, such as
+/// `{"id":123}`.
+fn get_file_id(response: &str) -> Option<&str> {
+    let after = response.split("\"id\"").nth(1)?.trim_start();
+    let value = after.strip_prefix(':')?.trim_start();
+    let end = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    Some(&value[..end]).filter(|id| !id.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_file_id() {
+        assert_eq!(get_file_id("{\"id\":123}"), Some("123"));
+        assert_eq!(get_file_id("{ \"id\": 45 }"), Some("45"));
+        assert_eq!(get_file_id("{}"), None);
+    }
+}
@@ -0,0 +1,299 @@
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use super::assets::netsuite_types::MODULES;
+use super::{
+    get_module_names, validate_api_version, validate_file_name, validate_naming_convention,
+    validate_parent_dir, validate_script_type, ScriptSpec,
+};
+
+/// Prompts for the file name, script type, API version, and modules of a script.
+///
+/// Each answer is validated as it is entered, and asked again if it is invalid. An empty answer
+/// keeps the current value of the option, which is shown in brackets.
+///
+/// # Errors
+/// Returns an error if the input or output fails, or the input ends before every answer is given
+pub fn prompt(
+    spec: &mut ScriptSpec,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    let file_name = spec
+        .file_name
+        .as_ref()
+        .map(|path| path.display().to_string())
+        .unwrap_or_default();
+    let create_dirs = spec.create_dirs;
+    let convention = spec.clone();
+    let file_name = ask_with(input, output, "File name", &file_name, |name| {
+        validate_file_name(name.clone())?;
+        validate_naming_convention(&convention, Path::new(&name))?;
+        if create_dirs {
+            return Ok(());
+        }
+        validate_parent_dir(Path::new(&name))
+    })?;
+    spec.file_name = Some(PathBuf::from(file_name));
+
+    spec.script_type = ask(
+        input,
+        output,
+        "Script type, empty to infer it from the file name",
+        &spec.script_type,
+        |name| {
+            if name.is_empty() {
+                return Ok(());
+            }
+            validate_script_type(name)
+        },
+    )?;
+
+    spec.api_version = ask(
+        input,
+        output,
+        "API version",
+        &spec.api_version,
+        validate_api_version,
+    )?;
+
+    spec.modules = pick_modules(input, output, &spec.modules)?;
+    Ok(())
+}
+
+/// Asks for the modules to import, matching each search against the supported modules.
+///
+/// A search that names a module, or matches a single module, selects it. Otherwise the matching
+/// modules are listed, and any of them can be picked by number.
+fn pick_modules(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    current: &[String],
+) -> io::Result<Vec<String>> {
+    let default = get_module_names(current)
+        .into_iter()
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<String>>()
+        .join(" ");
+    'question: loop {
+        let answer = ask(
+            input,
+            output,
+            "Modules, searched by any part of their names",
+            &default,
+            |_| Ok(()),
+        )?;
+
+        let mut modules: Vec<String> = Vec::new();
+        for search in answer.split(|c: char| c == ',' || c.is_whitespace()) {
+            let search = search.trim_start_matches("N/");
+            if search.is_empty() {
+                continue;
+            }
+
+            let candidates = search_modules(search);
+            let picked = match candidates.as_slice() {
+                [] => {
+                    writeln!(output, "error: No module matches {}", search)?;
+                    continue 'question;
+                }
+                [module] => vec![*module],
+                _ if candidates[0] == search.to_lowercase() => vec![candidates[0]],
+                _ => pick_candidates(input, output, search, &candidates)?,
+            };
+            for module in picked {
+                if !modules.iter().any(|selected| selected == module) {
+                    modules.push(module.to_owned());
+                }
+            }
+        }
+        return Ok(modules);
+    }
+}
+
+/// Asks which of the modules matching a search to import, by their numbers in the list.
+fn pick_candidates(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    search: &str,
+    candidates: &[&'static str],
+) -> io::Result<Vec<&'static str>> {
+    writeln!(output, "Modules matching {}:", search)?;
+    for (number, module) in candidates.iter().enumerate() {
+        writeln!(output, "  {}) {}", number + 1, module)?;
+    }
+
+    let count = candidates.len();
+    let answer = ask_with(
+        input,
+        output,
+        "Pick by number, separated by spaces",
+        "1",
+        |answer| {
+            let valid = answer.split_whitespace().all(|number| {
+                number
+                    .parse::<usize>()
+                    .is_ok_and(|number| (1..=count).contains(&number))
+            });
+            if valid {
+                return Ok(());
+            }
+            Err(format!("Pick numbers from 1 to {}", count))
+        },
+    )?;
+    Ok(answer
+        .split_whitespace()
+        .map(|number| candidates[number.parse::<usize>().unwrap() - 1])
+        .collect())
+}
+
+/// Searches the supported modules, best matches first.
+///
+/// A module matches if the letters of the search appear in its name in order, ignoring case and
+/// slashes, so that `srvwgt` matches `ui/serverwidget`. Exact names come first, then names
+/// starting with the search, then names containing it. Names where the letters are scattered are
+/// only matched if no name contains the search, closest together first.
+fn search_modules(search: &str) -> Vec<&'static str> {
+    let search: Vec<char> = search
+        .to_lowercase()
+        .chars()
+        .filter(|c| *c != '/')
+        .collect();
+    let mut matches: Vec<(usize, &'static str)> = MODULES
+        .iter()
+        .filter_map(|module| Some((match_score(&search, module)?, *module)))
+        .collect();
+    matches.sort();
+    if matches.first().is_some_and(|(score, _)| *score <= 2) {
+        matches.retain(|(score, _)| *score <= 2);
+    }
+    matches.into_iter().map(|(_, module)| module).collect()
+}
+
+/// Scores how well a search matches a module name, lower being better.
+///
+/// Returns `None` if the letters of the search do not appear in the name in order.
+fn match_score(search: &[char], module: &str) -> Option<usize> {
+    let name: Vec<char> = module.chars().filter(|c| *c != '/').collect();
+    if search.is_empty() {
+        return None;
+    }
+    if name == search {
+        return Some(0);
+    }
+    if name.starts_with(search) {
+        return Some(1);
+    }
+    if name.windows(search.len()).any(|window| window == search) {
+        return Some(2);
+    }
+
+    let mut positions = Vec::with_capacity(search.len());
+    let mut next = 0;
+    for c in search {
+        let offset = name[next..].iter().position(|letter| letter == c)?;
+        positions.push(next + offset);
+        next += offset + 1;
+    }
+    let span = positions.last()? - positions.first()? + 1;
+    Some(3 + span - search.len())
+}
+
+/// Asks a question until the answer is valid, returning the answer.
+///
+/// An empty answer is replaced by the default before it is validated.
+///
+/// # Errors
+/// Returns an error if the input or output fails, or the input ends before the answer is given
+pub fn ask(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    default: &str,
+    validate: fn(String) -> Result<(), String>,
+) -> io::Result<String> {
+    ask_with(input, output, question, default, validate)
+}
+
+/// Asks a question until the answer is valid, with a validator that can capture its context.
+fn ask_with(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    default: &str,
+    validate: impl Fn(String) -> Result<(), String>,
+) -> io::Result<String> {
+    loop {
+        if default.trim().is_empty() {
+            write!(output, "{}: ", question)?;
+        } else {
+            write!(output, "{} [{}]: ", question, default.trim())?;
+        }
+        output.flush()?;
+
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Input ended before every question was answered",
+            ));
+        }
+        let answer = match answer.trim() {
+            "" => default.trim(),
+            answer => answer,
+        };
+        match validate(answer.to_owned()) {
+            Ok(()) => return Ok(answer.to_owned()),
+            Err(message) => writeln!(output, "error: {}", message)?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt() {
+        let mut spec = ScriptSpec::default();
+        let mut input = io::Cursor::new("customer.txt\ncustomer_ue.js\n\n\nrecord, N/srch\n");
+        let mut output = Vec::new();
+        prompt(&mut spec, &mut input, &mut output).unwrap();
+
+        assert_eq!(spec.file_name, Some(PathBuf::from("customer_ue.js")));
+        assert_eq!(spec.script_type, "");
+        assert_eq!(spec.api_version, "2.1");
+        assert_eq!(spec.modules, vec!["record", "search"]);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "File name: error: Invalid file type: SuiteScript file must be a JavaScript or TypeScript file.\nFile name: Script type, empty to infer it from the file name: API version [2.1]: Modules, searched by any part of their names: "
+        );
+    }
+
+    #[test]
+    fn test_pick_ambiguous_modules() {
+        let mut input = io::Cursor::new("xyzzy\nui\n1 3\n");
+        let mut output = Vec::new();
+        let modules = pick_modules(&mut input, &mut output, &[]).unwrap();
+        assert_eq!(modules, vec!["ui/dialog", "ui/serverwidget"]);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Modules, searched by any part of their names: error: No module matches xyzzy\nModules, searched by any part of their names: Modules matching ui:\n  1) ui/dialog\n  2) ui/message\n  3) ui/serverwidget\n  4) suiteappinfo\nPick by number, separated by spaces [1]: "
+        );
+    }
+
+    #[test]
+    fn test_search_modules() {
+        assert_eq!(search_modules("srvwgt"), vec!["ui/serverwidget"]);
+        assert!(search_modules("/").is_empty());
+        assert_eq!(search_modules("record")[0], "record");
+        assert_eq!(&search_modules("rec")[..2], &["record", "recordcontext"]);
+    }
+
+    #[test]
+    fn test_prompt_input_ended() {
+        let mut spec = ScriptSpec::default();
+        let mut input = io::Cursor::new("customer_ue.js\n");
+        assert!(prompt(&mut spec, &mut input, &mut Vec::new()).is_err());
+    }
+}